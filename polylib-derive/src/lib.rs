@@ -0,0 +1,304 @@
+//! Derive macros for `polylib` traits `Zero` and `One`.
+//!
+//! Enabled by feature `derive` of `polylib`, which re-exports them.
+//! Works for structs with named fields, tuple structs and unit structs.
+//! Each field should implement the derived trait itself.
+//!
+//! Example:
+//! ```
+//! # use polylib::{One, Zero};
+//! #[derive(Zero, One, PartialEq, Debug)]
+//! struct Meters(i32);
+//!
+//! assert_eq!(Meters::zero(), Meters(0));
+//! assert!(Meters(1).is_one());
+//! ```
+//!
+//! No external dependencies are used, so input is parsed by hand.
+
+use proc_macro::{Delimiter, TokenStream, TokenTree};
+
+/// Derives `polylib::Zero`: `zero()` is built from zeros of all fields,
+/// `is_zero()` is true when every field is zero.
+#[proc_macro_derive(Zero)]
+pub fn derive_zero(input: TokenStream) -> TokenStream {
+    derive(input, "Zero", "zero", "is_zero")
+}
+
+/// Derives `polylib::One`: `one()` is built from ones of all fields,
+/// `is_one()` is true when every field is one.
+#[proc_macro_derive(One)]
+pub fn derive_one(input: TokenStream) -> TokenStream {
+    derive(input, "One", "one", "is_one")
+}
+
+// parsed shape of struct fields
+enum Fields {
+    Named(Vec<(String, String)>),
+    Unnamed(Vec<String>),
+    Unit,
+}
+
+// parsed struct declaration
+struct Input {
+    name: String,
+    impl_generics: String,
+    ty_generics: String,
+    where_clause: Vec<String>,
+    fields: Fields,
+}
+
+fn derive(input: TokenStream, trait_name: &str, ctor: &str, check: &str) -> TokenStream {
+    let input = match parse(input) {
+        Ok(input) => input,
+        Err(msg) => return compile_error(&msg),
+    };
+    let path = format!("::polylib::{}", trait_name);
+
+    let mut bounds = input.where_clause.clone();
+    let (build, test) = match &input.fields {
+        Fields::Named(fields) => {
+            let mut build = Vec::new();
+            let mut test = Vec::new();
+            for (name, ty) in fields {
+                bounds.push(format!("{}: {}", ty, path));
+                build.push(format!("{}: <{} as {}>::{}()", name, ty, path, ctor));
+                test.push(format!("{}::{}(&self.{})", path, check, name));
+            }
+            (format!("{} {{ {} }}", input.name, build.join(", ")), test)
+        }
+        Fields::Unnamed(fields) => {
+            let mut build = Vec::new();
+            let mut test = Vec::new();
+            for (i, ty) in fields.iter().enumerate() {
+                bounds.push(format!("{}: {}", ty, path));
+                build.push(format!("<{} as {}>::{}()", ty, path, ctor));
+                test.push(format!("{}::{}(&self.{})", path, check, i));
+            }
+            (format!("{}({})", input.name, build.join(", ")), test)
+        }
+        Fields::Unit => (input.name.clone(), Vec::new()),
+    };
+    let test = if test.is_empty() {
+        "true".to_string()
+    } else {
+        test.join(" && ")
+    };
+    let where_clause = if bounds.is_empty() {
+        String::new()
+    } else {
+        format!("where {}", bounds.join(", "))
+    };
+
+    let code = format!(
+        "impl{impl_generics} {path} for {name}{ty_generics} {where_clause} {{
+            fn {ctor}() -> Self {{
+                {build}
+            }}
+
+            fn {check}(&self) -> bool {{
+                {test}
+            }}
+        }}",
+        impl_generics = input.impl_generics,
+        path = path,
+        name = input.name,
+        ty_generics = input.ty_generics,
+        where_clause = where_clause,
+        ctor = ctor,
+        build = build,
+        check = check,
+        test = test,
+    );
+    code.parse().expect("generated impl should be valid rust")
+}
+
+fn compile_error(msg: &str) -> TokenStream {
+    format!("compile_error!({:?});", msg)
+        .parse()
+        .expect("compile_error! should be valid rust")
+}
+
+fn is_punct(token: &TokenTree, ch: char) -> bool {
+    matches!(token, TokenTree::Punct(p) if p.as_char() == ch)
+}
+
+fn is_ident(token: &TokenTree, name: &str) -> bool {
+    matches!(token, TokenTree::Ident(i) if i.to_string() == name)
+}
+
+fn to_source(tokens: &[TokenTree]) -> String {
+    tokens
+        .iter()
+        .cloned()
+        .collect::<TokenStream>()
+        .to_string()
+}
+
+// splits tokens by commas, that are not inside of `<...>`
+fn split_top_level(tokens: &[TokenTree]) -> Vec<Vec<TokenTree>> {
+    let mut parts = Vec::new();
+    let mut current = Vec::new();
+    let mut depth = 0i32;
+    for (i, token) in tokens.iter().enumerate() {
+        if is_punct(token, '<') {
+            depth += 1;
+        } else if is_punct(token, '>') {
+            // `->` inside of fn types is not a closing bracket
+            let arrow = i > 0 && is_punct(&tokens[i - 1], '-');
+            if !arrow {
+                depth -= 1;
+            }
+        } else if is_punct(token, ',') && depth == 0 {
+            parts.push(std::mem::take(&mut current));
+            continue;
+        }
+        current.push(token.clone());
+    }
+    if !current.is_empty() {
+        parts.push(current);
+    }
+    parts
+}
+
+// removes leading `#[...]` attributes and visibility
+fn strip_attrs_and_vis(tokens: &[TokenTree]) -> &[TokenTree] {
+    let mut rest = tokens;
+    loop {
+        match rest {
+            [hash, TokenTree::Group(_), tail @ ..] if is_punct(hash, '#') => rest = tail,
+            [vis, TokenTree::Group(g), tail @ ..]
+                if is_ident(vis, "pub") && g.delimiter() == Delimiter::Parenthesis =>
+            {
+                rest = tail
+            }
+            [vis, tail @ ..] if is_ident(vis, "pub") => rest = tail,
+            _ => return rest,
+        }
+    }
+}
+
+fn parse(input: TokenStream) -> Result<Input, String> {
+    let tokens: Vec<TokenTree> = input.into_iter().collect();
+    let mut rest = strip_attrs_and_vis(&tokens);
+
+    match rest.first() {
+        Some(token) if is_ident(token, "struct") => rest = &rest[1..],
+        _ => return Err("Zero and One can be derived only for structs".to_string()),
+    }
+    let name = match rest.first() {
+        Some(TokenTree::Ident(ident)) => ident.to_string(),
+        _ => return Err("expected struct name".to_string()),
+    };
+    rest = &rest[1..];
+
+    let (impl_generics, ty_generics) = if rest.first().is_some_and(|t| is_punct(t, '<')) {
+        let mut depth = 0;
+        let mut end = 0;
+        for (i, token) in rest.iter().enumerate() {
+            if is_punct(token, '<') {
+                depth += 1;
+            } else if is_punct(token, '>') && !(i > 0 && is_punct(&rest[i - 1], '-')) {
+                depth -= 1;
+                if depth == 0 {
+                    end = i;
+                    break;
+                }
+            }
+        }
+        let generics = parse_generics(&rest[1..end]);
+        rest = &rest[end + 1..];
+        generics
+    } else {
+        (String::new(), String::new())
+    };
+
+    let mut where_clause = Vec::new();
+    let mut fields = Fields::Unit;
+    let mut i = 0;
+    while i < rest.len() {
+        match &rest[i] {
+            TokenTree::Group(g) if g.delimiter() == Delimiter::Brace => {
+                fields = Fields::Named(parse_named(g.stream())?);
+            }
+            TokenTree::Group(g) if g.delimiter() == Delimiter::Parenthesis => {
+                fields = Fields::Unnamed(parse_unnamed(g.stream()));
+            }
+            token if is_ident(token, "where") => {
+                let end = rest[i + 1..]
+                    .iter()
+                    .position(|t| {
+                        matches!(t, TokenTree::Group(g) if g.delimiter() == Delimiter::Brace)
+                            || is_punct(t, ';')
+                    })
+                    .map_or(rest.len(), |p| p + i + 1);
+                for pred in split_top_level(&rest[i + 1..end]) {
+                    where_clause.push(to_source(&pred));
+                }
+                i = end;
+                continue;
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+
+    Ok(Input {
+        name,
+        impl_generics,
+        ty_generics,
+        where_clause,
+        fields,
+    })
+}
+
+// returns generics for `impl<...>` (without defaults) and for type `Name<...>`
+fn parse_generics(tokens: &[TokenTree]) -> (String, String) {
+    let mut impl_params = Vec::new();
+    let mut ty_params = Vec::new();
+    for param in split_top_level(tokens) {
+        let end = param
+            .iter()
+            .position(|t| is_punct(t, '='))
+            .unwrap_or(param.len());
+        let param = &param[..end];
+        impl_params.push(to_source(param));
+        match param {
+            [quote, TokenTree::Ident(life), ..] if is_punct(quote, '\'') => {
+                ty_params.push(format!("'{}", life))
+            }
+            [konst, TokenTree::Ident(ident), ..] if is_ident(konst, "const") => {
+                ty_params.push(ident.to_string())
+            }
+            [TokenTree::Ident(ident), ..] => ty_params.push(ident.to_string()),
+            _ => {}
+        }
+    }
+    (
+        format!("<{}>", impl_params.join(", ")),
+        format!("<{}>", ty_params.join(", ")),
+    )
+}
+
+fn parse_named(stream: TokenStream) -> Result<Vec<(String, String)>, String> {
+    let tokens: Vec<TokenTree> = stream.into_iter().collect();
+    let mut fields = Vec::new();
+    for field in split_top_level(&tokens) {
+        let field = strip_attrs_and_vis(&field);
+        match field {
+            [TokenTree::Ident(name), colon, ty @ ..] if is_punct(colon, ':') => {
+                fields.push((name.to_string(), to_source(ty)))
+            }
+            _ => return Err("can't parse struct field".to_string()),
+        }
+    }
+    Ok(fields)
+}
+
+fn parse_unnamed(stream: TokenStream) -> Vec<String> {
+    let tokens: Vec<TokenTree> = stream.into_iter().collect();
+    split_top_level(&tokens)
+        .iter()
+        .map(|field| to_source(strip_attrs_and_vis(field)))
+        .collect()
+}
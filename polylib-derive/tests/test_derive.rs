@@ -0,0 +1,111 @@
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use polylib::custom_types::Zn;
+use polylib::polynom::Polynomial;
+use polylib::{One, Zero};
+
+#[derive(Zero, One, Clone, Copy, Debug, Default, PartialEq)]
+struct Meters(i32);
+
+#[derive(Zero, One, Clone, Debug, PartialEq)]
+pub struct Pair {
+    pub first: i64,
+    second: Zn<7>,
+}
+
+#[derive(Zero, One, Debug, PartialEq)]
+struct Wrapper<T: Debug, const N: usize>
+where
+    T: Clone,
+{
+    value: T,
+    marker: Size<N>,
+}
+
+#[derive(Zero, One, Debug, PartialEq)]
+struct Size<const N: usize>;
+
+#[derive(Zero, Debug, PartialEq)]
+struct Tagged<'a, T = u8> {
+    value: T,
+    tag: PhantomMarker<'a>,
+}
+
+#[derive(Debug, PartialEq)]
+struct PhantomMarker<'a>(PhantomData<&'a ()>);
+
+impl Zero for PhantomMarker<'_> {
+    fn zero() -> Self {
+        PhantomMarker(PhantomData)
+    }
+
+    fn is_zero(&self) -> bool {
+        true
+    }
+}
+
+impl std::ops::Add for Meters {
+    type Output = Meters;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Meters(self.0 + rhs.0)
+    }
+}
+
+impl std::ops::Mul for Meters {
+    type Output = Meters;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Meters(self.0 * rhs.0)
+    }
+}
+
+#[test]
+fn test_newtype() {
+    assert_eq!(Meters::zero(), Meters(0));
+    assert_eq!(Meters::one(), Meters(1));
+    assert!(Meters(0).is_zero());
+    assert!(!Meters(2).is_zero());
+    assert!(Meters(1).is_one());
+    assert!(!Meters(-1).is_one());
+}
+
+#[test]
+fn test_named() {
+    let zero = Pair::zero();
+    assert_eq!(zero.first, 0);
+    assert_eq!(zero.second, Zn::new(0));
+    assert!(zero.is_zero());
+
+    let one = Pair::one();
+    assert_eq!(one.first, 1);
+    assert_eq!(one.second, Zn::new(1));
+    assert!(one.is_one());
+
+    let half = Pair {
+        first: 1,
+        second: Zn::new(0),
+    };
+    assert!(!half.is_zero());
+    assert!(!half.is_one());
+}
+
+#[test]
+fn test_generic() {
+    let w = Wrapper::<u8, 3>::one();
+    assert_eq!(w.value, 1);
+    assert!(w.is_one());
+    assert!(Wrapper::<f64, 0>::zero().is_zero());
+
+    let t: Tagged = Tagged::zero();
+    assert_eq!(t.value, 0u8);
+    assert!(t.is_zero());
+}
+
+#[test]
+fn test_as_coef() {
+    let poly = Polynomial::<Meters>::from_coefs(vec![Meters(1), Meters(0), Meters(2)]);
+    assert_eq!(poly.len(), 2);
+    assert_eq!(poly.substitude::<Meters, Meters>(Meters(3)), Meters(19));
+}
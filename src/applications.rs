@@ -0,0 +1,14 @@
+//! Module, where applications built on polynomials are presented
+
+pub mod crc;
+pub mod lfsr;
+pub mod poly_hash;
+pub mod reed_solomon;
+pub mod shamir;
+pub mod transfer;
+
+pub use crc::Crc;
+pub use lfsr::Lfsr;
+pub use poly_hash::PolyHash;
+pub use reed_solomon::ReedSolomon;
+pub use transfer::TransferFunction;
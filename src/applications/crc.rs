@@ -0,0 +1,181 @@
+//! Defines `Crc` - table driven cyclic redundancy check of any width up to 64.
+
+use crate::custom_types::Gf2Poly;
+
+/// CRC with generator polynomial g(x) of degree `width`.
+///
+/// Without options checksum is remainder of data(x) * x^width divided by g(x),
+/// where first bit of data is coef of the highest power (most significant bit first).
+/// `with_init`, `with_xor_out` and `reflected` give the usual variants.
+///
+/// Example:
+/// ```
+/// # use polylib::applications::Crc;
+/// assert_eq!(Crc::crc32().checksum(b"123456789"), 0xCBF43926);
+///
+/// // CRC-16/XMODEM: x^16 + x^12 + x^5 + 1
+/// let crc = Crc::new(16, 0x1021);
+/// assert_eq!(crc.checksum(b"123456789"), 0x31C3);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Crc {
+    width: u32,
+    // generator without x^width, as it is written in CRC catalogues
+    poly: u64,
+    init: u64,
+    xor_out: u64,
+    reflected: bool,
+    table: [u64; 256],
+}
+
+// mask of low width bits
+fn mask(width: u32) -> u64 {
+    u64::MAX >> (64 - width)
+}
+
+// reverses low width bits of value
+fn reflect(value: u64, width: u32) -> u64 {
+    value.reverse_bits() >> (64 - width)
+}
+
+impl Crc {
+    /// Creates CRC with generator x^width + poly, where bit i of poly is coef of x^i.
+    /// Init and final xor are zero, bits are not reflected.
+    ///
+    /// Panics if width is not in 1..=64.
+    pub fn new(width: u32, poly: u64) -> Crc {
+        if !(1..=64).contains(&width) {
+            panic!("CRC width {} is not in 1..=64", width)
+        }
+        let mut crc = Crc {
+            width,
+            poly: poly & mask(width),
+            init: 0,
+            xor_out: 0,
+            reflected: false,
+            table: [0; 256],
+        };
+        crc.build_table();
+        crc
+    }
+
+    /// Creates CRC with generator polynomial, see `new`.
+    ///
+    /// Panics if degree of generator is not in 1..=64.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::applications::Crc;
+    /// # use polylib::custom_types::Gf2Poly;
+    /// let g = Gf2Poly::from_powers(&[8, 2, 1, 0]);
+    /// assert_eq!(Crc::from_gf2_poly(&g), Crc::new(8, 0x07));
+    /// ```
+    pub fn from_gf2_poly(generator: &Gf2Poly) -> Crc {
+        let width = match generator.degree() {
+            Some(deg) if (1..=64).contains(&deg) => deg as u32,
+            deg => panic!("CRC generator degree {:?} is not in 1..=64", deg),
+        };
+        let poly = (0..width as usize)
+            .filter(|&i| generator.get(i))
+            .fold(0, |acc, i| acc | 1 << i);
+        Self::new(width, poly)
+    }
+
+    /// Returns CRC with initial register value (given as not reflected).
+    pub fn with_init(mut self, init: u64) -> Crc {
+        self.init = init & mask(self.width);
+        self
+    }
+
+    /// Returns CRC, which result is xored with value.
+    pub fn with_xor_out(mut self, xor_out: u64) -> Crc {
+        self.xor_out = xor_out & mask(self.width);
+        self
+    }
+
+    /// Returns CRC, that processes bytes least significant bit first
+    /// and reflects result (refin = refout = reflected).
+    pub fn reflected(mut self, reflected: bool) -> Crc {
+        self.reflected = reflected;
+        self.build_table();
+        self
+    }
+
+    /// CRC-8/SMBUS: x^8 + x^2 + x + 1.
+    pub fn crc8() -> Crc {
+        Self::new(8, 0x07)
+    }
+
+    /// CRC-16/ARC: x^16 + x^15 + x^2 + 1, reflected.
+    pub fn crc16() -> Crc {
+        Self::new(16, 0x8005).reflected(true)
+    }
+
+    /// CRC-32/ISO-HDLC (Ethernet, zip, png).
+    pub fn crc32() -> Crc {
+        Self::new(32, 0x04C11DB7)
+            .with_init(u64::MAX)
+            .with_xor_out(u64::MAX)
+            .reflected(true)
+    }
+
+    /// CRC-64/XZ.
+    pub fn crc64() -> Crc {
+        Self::new(64, 0x42F0E1EBA9EA3693)
+            .with_init(u64::MAX)
+            .with_xor_out(u64::MAX)
+            .reflected(true)
+    }
+
+    /// Returns width, that is degree of generator.
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Returns generator polynomial.
+    pub fn generator(&self) -> Gf2Poly {
+        let mut ans = Gf2Poly::from_u64(self.poly);
+        ans.set(self.width as usize, true);
+        ans
+    }
+
+    // table[b] is register change after feeding byte b into zero register
+    fn build_table(&mut self) {
+        for byte in 0..256u64 {
+            let mut reg;
+            if self.reflected {
+                let poly = reflect(self.poly, self.width);
+                reg = byte;
+                for _ in 0..8 {
+                    reg = if reg & 1 == 1 { (reg >> 1) ^ poly } else { reg >> 1 };
+                }
+            } else {
+                // register is aligned to the top bit of u64
+                let poly = self.poly << (64 - self.width);
+                reg = byte << 56;
+                for _ in 0..8 {
+                    reg = if reg >> 63 == 1 { (reg << 1) ^ poly } else { reg << 1 };
+                }
+            }
+            self.table[byte as usize] = reg;
+        }
+    }
+
+    /// Returns checksum of data.
+    pub fn checksum(&self, data: &[u8]) -> u64 {
+        let ans = if self.reflected {
+            let mut reg = reflect(self.init, self.width);
+            for &byte in data {
+                reg = self.table[((reg ^ byte as u64) & 0xFF) as usize] ^ (reg >> 8);
+            }
+            reg
+        } else {
+            let mut reg = self.init << (64 - self.width);
+            for &byte in data {
+                reg = self.table[((reg >> 56) ^ byte as u64) as usize] ^ (reg << 8);
+            }
+            reg >> (64 - self.width)
+        };
+        ans ^ self.xor_out
+    }
+}
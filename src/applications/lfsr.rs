@@ -0,0 +1,145 @@
+//! Defines `Lfsr` - linear feedback shift register over GF(2),
+//! and Berlekamp–Massey algorithm, that finds the shortest one for a sequence.
+
+use crate::custom_types::Gf2Poly;
+
+/// Fibonacci LFSR with connection polynomial c(x) = 1 + c1 x + ... + cL x^L,
+/// which outputs bits s0, s1, ..., where s(n) = c1 s(n-1) + ... + cL s(n-L).
+///
+/// Degree L is in 1..=64, so state fits into u64.
+///
+/// Example:
+/// ```
+/// # use polylib::applications::Lfsr;
+/// # use polylib::custom_types::Gf2Poly;
+/// // x^4 + x + 1 is primitive, so period is 2^4 - 1
+/// let mut lfsr = Lfsr::new(&Gf2Poly::from_powers(&[4, 1, 0]), 0b0001);
+/// assert_eq!(lfsr.period(), Some(15));
+/// let bits: Vec<bool> = lfsr.by_ref().take(6).collect();
+/// assert_eq!(bits, vec![true, false, false, false, true, true]);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Lfsr {
+    len: u32,
+    // bit L - i is set, if ci is one
+    taps: u64,
+    // bit j is s(n + j), so bit 0 is the next output
+    state: u64,
+}
+
+impl Lfsr {
+    /// Creates LFSR from connection polynomial and first L output bits
+    /// (bit j of seed is s(j)).
+    ///
+    /// Panics if degree is not in 1..=64 or constant term is zero.
+    pub fn new(feedback: &Gf2Poly, seed: u64) -> Lfsr {
+        let len = match feedback.degree() {
+            Some(deg) if (1..=64).contains(&deg) => deg as u32,
+            deg => panic!("LFSR feedback degree {:?} is not in 1..=64", deg),
+        };
+        if !feedback.get(0) {
+            panic!("LFSR feedback polynomial must have constant term 1")
+        }
+        let taps = (1..=len)
+            .filter(|&i| feedback.get(i as usize))
+            .fold(0, |acc, i| acc | 1 << (len - i));
+        Lfsr { len, taps, state: seed & Self::mask(len) }
+    }
+
+    fn mask(len: u32) -> u64 {
+        u64::MAX >> (64 - len)
+    }
+
+    /// Returns length L of register, that is degree of connection polynomial.
+    pub fn degree(&self) -> u32 {
+        self.len
+    }
+
+    /// Returns next L output bits (bit j is the j-th next bit).
+    pub fn state(&self) -> u64 {
+        self.state
+    }
+
+    /// Returns connection polynomial.
+    pub fn feedback(&self) -> Gf2Poly {
+        let mut ans = Gf2Poly::from_u64(1);
+        for i in 1..=self.len {
+            ans.set(i as usize, (self.taps >> (self.len - i)) & 1 == 1);
+        }
+        ans
+    }
+
+    /// Returns next output bit and shifts register.
+    pub fn next_bit(&mut self) -> bool {
+        let out = self.state & 1 == 1;
+        let new = (self.state & self.taps).count_ones() as u64 & 1;
+        self.state = (self.state >> 1) | (new << (self.len - 1));
+        out
+    }
+
+    /// Returns count of steps, after which state repeats,
+    /// or `None` if register is longer than 32 bits.
+    pub fn period(&self) -> Option<u64> {
+        if self.len > 32 {
+            return None;
+        }
+        // constant term is one, so step is invertible and state comes back
+        let mut lfsr = self.clone();
+        let mut steps = 0;
+        loop {
+            lfsr.next_bit();
+            steps += 1;
+            if lfsr.state == self.state {
+                return Some(steps);
+            }
+        }
+    }
+}
+
+impl Iterator for Lfsr {
+    type Item = bool;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_bit())
+    }
+}
+
+/// Returns shortest LFSR, that generates bits: its connection polynomial
+/// and length L (linear complexity). Degree of polynomial may be less than L.
+///
+/// 2L bits of LFSR output are enough to find it.
+///
+/// Example:
+/// ```
+/// # use polylib::applications::lfsr::{berlekamp_massey, Lfsr};
+/// # use polylib::custom_types::Gf2Poly;
+/// let feedback = Gf2Poly::from_powers(&[5, 2, 0]);
+/// let bits: Vec<bool> = Lfsr::new(&feedback, 0b10110).take(10).collect();
+/// assert_eq!(berlekamp_massey(&bits), (feedback, 5));
+/// ```
+pub fn berlekamp_massey(bits: &[bool]) -> (Gf2Poly, usize) {
+    let mut current = Gf2Poly::from_u64(1);
+    let mut previous = Gf2Poly::from_u64(1);
+    let mut len = 0;
+    // previous is multiplied by x^shift before it is added
+    let mut shift = 1;
+    for n in 0..bits.len() {
+        let discrepancy = (1..=len).fold(bits[n], |acc, i| {
+            acc ^ (current.get(i) && bits[n - i])
+        });
+        if !discrepancy {
+            shift += 1;
+            continue;
+        }
+        let next = current.clone() + previous.shl(shift);
+        if 2 * len <= n {
+            len = n + 1 - len;
+            previous = current;
+            shift = 1;
+        } else {
+            shift += 1;
+        }
+        current = next;
+    }
+    (current, len)
+}
@@ -0,0 +1,113 @@
+//! Defines `PolyHash` - rolling polynomial hash of a sequence over `Zn`.
+
+use std::ops::Range;
+
+use crate::custom_types::Zn;
+use crate::{One, Zero};
+
+/// Polynomial hash of sequence s0, s1, ..., s(k-1) with base b:
+/// s0 b^(k-1) + s1 b^(k-2) + ... + s(k-1) in Zn<N>.
+///
+/// Prefix hashes and powers of base are precomputed,
+/// so hash of any substring is found in O(1).
+/// Equal substrings have equal hashes, different ones of same length
+/// collide with probability about k / N for random base.
+///
+/// Example:
+/// ```
+/// # use polylib::applications::PolyHash;
+/// # use polylib::custom_types::Zn;
+/// let hash = PolyHash::new(b"abracadabra", Zn::<1_000_000_007>::new(131));
+/// assert!(hash.substring_eq(0..4, 7..11)); // "abra"
+/// assert!(!hash.substring_eq(0..4, 1..5));
+/// ```
+#[derive(Clone, Debug)]
+pub struct PolyHash<const N: u32> {
+    base: Zn<N>,
+    // powers[i] is base^i
+    powers: Vec<Zn<N>>,
+    // prefix[i] is hash of first i symbols
+    prefix: Vec<Zn<N>>,
+}
+
+impl<const N: u32> PolyHash<N> {
+    /// Precomputes hashes of all prefixes of data.
+    pub fn new<T: Copy + Into<u32>>(data: &[T], base: Zn<N>) -> PolyHash<N> {
+        let mut powers = Vec::with_capacity(data.len() + 1);
+        let mut prefix = Vec::with_capacity(data.len() + 1);
+        powers.push(Zn::one());
+        prefix.push(Zn::zero());
+        for (i, &symbol) in data.iter().enumerate() {
+            powers.push(powers[i] * base);
+            prefix.push(prefix[i] * base + Zn::new(symbol.into()));
+        }
+        PolyHash { base, powers, prefix }
+    }
+
+    /// Returns base of hash.
+    pub fn base(&self) -> Zn<N> {
+        self.base
+    }
+
+    /// Returns count of symbols in data.
+    pub fn len(&self) -> usize {
+        self.prefix.len() - 1
+    }
+
+    /// Returns true, if data is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns base^k for k <= len.
+    pub fn power(&self, k: usize) -> Zn<N> {
+        self.powers[k]
+    }
+
+    /// Returns hash of first k symbols.
+    pub fn prefix(&self, k: usize) -> Zn<N> {
+        self.prefix[k]
+    }
+
+    /// Returns hash of data[range].
+    ///
+    /// Panics if range is out of data.
+    pub fn hash(&self, range: Range<usize>) -> Zn<N> {
+        if range.start > range.end || range.end > self.len() {
+            panic!("range {:?} is out of data with len {}", range, self.len())
+        }
+        self.prefix[range.end] - self.prefix[range.start] * self.powers[range.len()]
+    }
+
+    /// Returns true, if data[lhs] and data[rhs] are equal (up to hash collision).
+    ///
+    /// Panics if a range is out of data.
+    pub fn substring_eq(&self, lhs: Range<usize>, rhs: Range<usize>) -> bool {
+        lhs.len() == rhs.len() && self.hash(lhs) == self.hash(rhs)
+    }
+
+    /// Returns length of longest common prefix of data[lhs..] and data[rhs..]
+    /// (up to hash collision) by binary search.
+    ///
+    /// Panics if lhs or rhs is greater than len.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::applications::PolyHash;
+    /// # use polylib::custom_types::Zn;
+    /// let hash = PolyHash::new(b"abcabd", Zn::<998_244_353>::new(257));
+    /// assert_eq!(hash.common_prefix(0, 3), 2);
+    /// ```
+    pub fn common_prefix(&self, lhs: usize, rhs: usize) -> usize {
+        let (mut low, mut high) = (0, self.len() - lhs.max(rhs));
+        while low < high {
+            let mid = (low + high).div_ceil(2);
+            if self.substring_eq(lhs..lhs + mid, rhs..rhs + mid) {
+                low = mid;
+            } else {
+                high = mid - 1;
+            }
+        }
+        low
+    }
+}
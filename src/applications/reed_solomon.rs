@@ -0,0 +1,190 @@
+//! Defines `ReedSolomon` - systematic Reed–Solomon code over any finite field.
+
+use crate::polynom::{times, Polynomial};
+use crate::{CoefField, One, PolyError};
+
+/// Reed–Solomon code with `parity` check symbols over field F,
+/// which corrects up to `parity / 2` wrong symbols.
+///
+/// Generator polynomial is (x - 1)(x - alpha)...(x - alpha^(parity - 1)).
+/// Codeword is polynomial c(x), where c[i] is coef of x^i:
+/// first `parity` symbols are check symbols, the rest are message symbols.
+///
+/// Codeword length should not exceed multiplicative order of alpha,
+/// so alpha should be primitive element of the field.
+///
+/// Example:
+/// ```
+/// # use polylib::applications::ReedSolomon;
+/// # use polylib::custom_types::Gf256;
+/// let rs = ReedSolomon::new(4, Gf256::new(3)); // 3 is primitive in AES field
+/// let message: Vec<Gf256> = b"hello".iter().map(|&b| Gf256::new(b as u64)).collect();
+/// let mut codeword = rs.encode(&message);
+/// assert_eq!(codeword.len(), 9);
+///
+/// codeword[1] = Gf256::new(0);
+/// codeword[6] = Gf256::new(0xFF);
+/// assert_eq!(rs.decode(&codeword), Ok(message));
+/// ```
+#[derive(Clone, Debug)]
+pub struct ReedSolomon<F: One> {
+    parity: usize,
+    alpha: F,
+    generator: Polynomial<F>,
+}
+
+impl<F: CoefField> ReedSolomon<F> {
+    /// Creates code with `parity` check symbols and primitive element alpha.
+    pub fn new(parity: usize, alpha: F) -> ReedSolomon<F> {
+        let mut generator = Polynomial::<F>::one();
+        let mut root = F::one();
+        for _ in 0..parity {
+            let factor = Polynomial::from_coefs(vec![-root.clone(), F::one()]);
+            generator = (generator * factor).reduce();
+            root = root * alpha.clone();
+        }
+        ReedSolomon { parity, alpha, generator }
+    }
+
+    /// Returns count of check symbols.
+    pub fn parity(&self) -> usize {
+        self.parity
+    }
+
+    /// Returns generator polynomial.
+    pub fn generator(&self) -> &Polynomial<F> {
+        &self.generator
+    }
+
+    /// Returns codeword: check symbols followed by message.
+    /// Check symbols are minus remainder of message(x) * x^parity divided by generator,
+    /// so codeword is divisible by generator.
+    pub fn encode(&self, message: &[F]) -> Vec<F> {
+        let mut coefs = vec![F::zero(); self.parity];
+        coefs.extend_from_slice(message);
+        let (_, rem) = Polynomial::<F>::from_coefs(coefs).div_rem(&self.generator);
+        let mut codeword: Vec<F> = (0..self.parity as u32).map(|i| -rem.coef(i)).collect();
+        codeword.extend_from_slice(message);
+        codeword
+    }
+
+    /// Returns syndromes r(1), r(alpha), ..., r(alpha^(parity - 1)) of received word.
+    /// They all are zero for valid codeword.
+    pub fn syndromes(&self, received: &[F]) -> Vec<F> {
+        let poly = Polynomial::<F>::from_coefs(received.to_vec());
+        let mut point = F::one();
+        let mut ans = Vec::with_capacity(self.parity);
+        for _ in 0..self.parity {
+            ans.push(poly.substitude(point.clone()));
+            point = point * self.alpha.clone();
+        }
+        ans
+    }
+
+    /// Returns corrected message of received word.
+    ///
+    /// Error locator is found by Berlekamp–Massey algorithm, error positions
+    /// by Chien search and error values by Forney algorithm.
+    /// Returns `PolyError::TooManyErrors`, if word can't be corrected.
+    ///
+    /// Panics if received word is shorter than `parity`.
+    pub fn decode(&self, received: &[F]) -> Result<Vec<F>, PolyError> {
+        if received.len() < self.parity {
+            panic!(
+                "Received word has {} symbols, but code has {} check symbols",
+                received.len(),
+                self.parity
+            )
+        }
+        let syndromes = self.syndromes(received);
+        if syndromes.iter().all(F::is_zero) {
+            return Ok(received[self.parity..].to_vec());
+        }
+
+        let locator = berlekamp_massey(&syndromes);
+        let errors = locator.len() - 1;
+        if 2 * errors > self.parity {
+            return Err(PolyError::TooManyErrors);
+        }
+        let locator_poly = Polynomial::<F>::from_coefs(locator.clone());
+
+        // Chien search: error at position i, if locator(alpha^-i) is zero
+        let alpha_inv = F::one() / self.alpha.clone();
+        let mut positions = Vec::new();
+        let mut point = F::one();
+        let mut point_inv = F::one();
+        for i in 0..received.len() {
+            let value: F = locator_poly.substitude(point_inv.clone());
+            if value.is_zero() {
+                positions.push((i, point.clone(), point_inv.clone()));
+            }
+            point = point * self.alpha.clone();
+            point_inv = point_inv * alpha_inv.clone();
+        }
+        if positions.len() != errors {
+            return Err(PolyError::TooManyErrors);
+        }
+
+        // Forney: e = -X * omega(X^-1) / locator'(X^-1), omega = S * locator mod x^parity
+        let product = (Polynomial::<F>::from_coefs(syndromes) * locator_poly).reduce();
+        let omega = Polynomial::<F>::from_coefs(
+            (0..self.parity as u32).map(|i| product.coef(i)).collect(),
+        );
+        let derivative = Polynomial::<F>::from_coefs(
+            (1..locator.len()).map(|i| times(locator[i].clone(), i as u32)).collect(),
+        );
+        let mut corrected = received.to_vec();
+        for (i, point, point_inv) in positions {
+            let num: F = omega.substitude(point_inv.clone());
+            let den: F = derivative.substitude(point_inv);
+            if den.is_zero() {
+                return Err(PolyError::TooManyErrors);
+            }
+            corrected[i] = corrected[i].clone() + point * num / den;
+        }
+
+        if !self.syndromes(&corrected).iter().all(F::is_zero) {
+            return Err(PolyError::TooManyErrors);
+        }
+        Ok(corrected.split_off(self.parity))
+    }
+}
+
+// returns coefs of shortest connection polynomial C with C[0] = 1,
+// such that sum C[j] s[n - j] is zero for each n >= deg C
+fn berlekamp_massey<F: CoefField>(syndromes: &[F]) -> Vec<F> {
+    let mut current = vec![F::one()];
+    let mut previous = vec![F::one()];
+    let mut len = 0;
+    let mut shift = 1;
+    let mut last_discrepancy = F::one();
+    for n in 0..syndromes.len() {
+        let discrepancy = (1..current.len().min(n + 1)).fold(syndromes[n].clone(), |acc, i| {
+            acc + current[i].clone() * syndromes[n - i].clone()
+        });
+        if discrepancy.is_zero() {
+            shift += 1;
+            continue;
+        }
+        // current -= discrepancy / last_discrepancy * x^shift * previous
+        let factor = discrepancy.clone() / last_discrepancy.clone();
+        let mut next = current.clone();
+        if next.len() < previous.len() + shift {
+            next.resize(previous.len() + shift, F::zero());
+        }
+        for (i, coef) in previous.iter().enumerate() {
+            next[i + shift] = next[i + shift].clone() - factor.clone() * coef.clone();
+        }
+        if 2 * len <= n {
+            len = n + 1 - len;
+            previous = current;
+            last_discrepancy = discrepancy;
+            shift = 1;
+        } else {
+            shift += 1;
+        }
+        current = next;
+    }
+    current.resize(len + 1, F::zero());
+    current
+}
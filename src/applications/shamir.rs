@@ -0,0 +1,100 @@
+//! Defines Shamir secret sharing over prime field `Zp`.
+//!
+//! Secret is constant term of random polynomial of degree k - 1,
+//! shares are its values at points 1, 2, ..., n.
+//! Any k shares restore polynomial by Lagrange interpolation,
+//! while k - 1 shares tell nothing about secret.
+//!
+//! Crate has no secure random generator, so randomness is taken from
+//! caller: it should be cryptographically secure (as OS generator).
+//! `Xorshift64` is predictable and is fine for tests only.
+
+use crate::custom_types::Zp;
+use crate::polynom::Polynomial;
+use crate::{One, PolyError, Zero};
+
+/// One share of secret: value y of polynomial at point x.
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub struct Share<const P: u32> {
+    /// Point, it is never zero.
+    pub x: Zp<P>,
+    /// Value of polynomial at x.
+    pub y: Zp<P>,
+}
+
+// uniform value of Zp from random u64, values above the last full block are rejected
+fn uniform<const P: u32>(random: &mut impl FnMut() -> u64) -> Zp<P> {
+    let limit = u64::MAX - u64::MAX % P as u64;
+    loop {
+        let value = random();
+        if value < limit {
+            return Zp::new((value % P as u64) as u32);
+        }
+    }
+}
+
+/// Splits secret into n shares, any k of which restore it.
+///
+/// Coefs of polynomial are made from values of `random`, that should be
+/// cryptographically secure source of uniform u64.
+///
+/// Panics if k is zero, k > n or n >= P (points must be distinct and nonzero).
+///
+/// Example:
+/// ```
+/// # use polylib::applications::shamir::{reconstruct, split};
+/// # use polylib::custom_types::Zp;
+/// # use polylib::random::Xorshift64;
+/// type F = Zp<2147483647>;
+/// // not secure, only for example
+/// let mut rng = Xorshift64::new(42);
+/// let shares = split(F::new(123456), 3, 5, || rng.next_u64());
+/// assert_eq!(reconstruct(&shares[1..4]), Ok(F::new(123456)));
+/// assert_eq!(reconstruct(&[shares[4], shares[0], shares[2]]), Ok(F::new(123456)));
+/// ```
+pub fn split<const P: u32>(
+    secret: Zp<P>,
+    k: usize,
+    n: usize,
+    mut random: impl FnMut() -> u64,
+) -> Vec<Share<P>> {
+    if k == 0 || k > n {
+        panic!("Can't split secret into {} shares with threshold {}", n, k)
+    }
+    if n as u64 >= P as u64 {
+        panic!("Can't make {} distinct nonzero points in Z{}", n, P)
+    }
+    let mut coefs = vec![secret];
+    coefs.extend((1..k).map(|_| uniform(&mut random)));
+    let poly = Polynomial::<Zp<P>>::from_coefs(coefs);
+    (1..=n)
+        .map(|x| {
+            let x = Zp::new(x as u32);
+            Share { x, y: poly.substitude(x) }
+        })
+        .collect()
+}
+
+/// Returns secret, that is value at zero of polynomial,
+/// which passes through all shares (Lagrange interpolation).
+///
+/// With less than k shares result is unrelated to secret.
+/// Returns `PolyError::DivisionByZero` if two shares have the same point.
+pub fn reconstruct<const P: u32>(shares: &[Share<P>]) -> Result<Zp<P>, PolyError> {
+    let mut secret = Zp::zero();
+    for (i, share) in shares.iter().enumerate() {
+        // basis polynomial at zero: product of x_j / (x_j - x_i)
+        let mut num = Zp::one();
+        let mut den = Zp::one();
+        for (j, other) in shares.iter().enumerate() {
+            if i == j {
+                continue;
+            }
+            num *= other.x;
+            den *= other.x - share.x;
+        }
+        let inv = den.inv().ok_or(PolyError::DivisionByZero)?;
+        secret += share.y * num * inv;
+    }
+    Ok(secret)
+}
@@ -0,0 +1,156 @@
+//! Defines `TransferFunction` - discrete-time linear filter, given by Z-transform
+//! H(z) = B(z^-1) / A(z^-1) of its impulse response.
+
+use crate::polynom::Polynomial;
+use crate::{CoefField, PolyError};
+
+/// Transfer function H(z) = (b0 + b1 z^-1 + ... + bM z^-M) / (a0 + a1 z^-1 + ... + aN z^-N),
+/// where numerator and denominator are polynomials in z^-1 (coef of x^k is coef of z^-k),
+/// as `b` and `a` of difference equation
+/// `a0 y(n) + a1 y(n-1) + ... = b0 x(n) + b1 x(n-1) + ...`.
+///
+/// a0 should not be zero, so filter is causal. Coefs should form a field.
+///
+/// Example:
+/// ```
+/// # use polylib::applications::TransferFunction;
+/// # use polylib::polynom::Polynomial;
+/// // y(n) = x(n) + y(n-1) / 2: H(z) = 1 / (1 - z^-1 / 2)
+/// let h = TransferFunction::new(
+///     Polynomial::from_coefs(vec![1.0]),
+///     Polynomial::from_coefs(vec![1.0, -0.5]),
+/// );
+/// assert_eq!(h.impulse_response(4), vec![1.0, 0.5, 0.25, 0.125]);
+/// assert_eq!(h.filter(&[2.0, 0.0, 1.0]), vec![2.0, 1.0, 1.5]);
+/// assert_eq!(h.poles(), vec![(0.5, 0.0)]);
+/// assert!(h.is_stable());
+/// ```
+#[derive(Clone, Debug)]
+pub struct TransferFunction<T> {
+    // coefs of z^0, z^-1, ... up to degree
+    b: Vec<T>,
+    a: Vec<T>,
+}
+
+// dense coefs of reduced polynomial
+fn dense<T: CoefField>(p: &Polynomial<T>) -> Vec<T> {
+    let p = p.clone().reduce();
+    let len = p.degree().map_or(0, |deg| deg as usize + 1);
+    (0..len).map(|i| p.coef(i as u32)).collect()
+}
+
+impl<T: CoefField> TransferFunction<T> {
+    /// Creates transfer function B / A from polynomials in z^-1.
+    /// Returns `PolyError::DivisionByZero`, if constant term a0 of denominator is zero.
+    pub fn try_new(num: Polynomial<T>, den: Polynomial<T>) -> Result<Self, PolyError> {
+        let b = dense(&num);
+        let a = dense(&den);
+        if a.first().is_none_or(|a0| a0.is_zero()) {
+            return Err(PolyError::DivisionByZero);
+        }
+        Ok(TransferFunction { b, a })
+    }
+
+    /// Same as `try_new`, but panics, if constant term of denominator is zero.
+    pub fn new(num: Polynomial<T>, den: Polynomial<T>) -> Self {
+        match Self::try_new(num, den) {
+            Ok(ans) => ans,
+            Err(err) => panic!("Can't create TransferFunction: {}", err),
+        }
+    }
+
+    /// Returns numerator B(z^-1).
+    pub fn numerator(&self) -> Polynomial<T> {
+        Polynomial::from_coefs(self.b.clone())
+    }
+
+    /// Returns denominator A(z^-1).
+    pub fn denominator(&self) -> Polynomial<T> {
+        Polynomial::from_coefs(self.a.clone())
+    }
+
+    /// Returns first `len` values of impulse response h(0), h(1), ...,
+    /// that are coefs of power series B / A in z^-1.
+    pub fn impulse_response(&self, len: usize) -> Vec<T> {
+        let mut impulse = vec![T::zero(); len];
+        if let Some(first) = impulse.first_mut() {
+            *first = T::one();
+        }
+        self.filter(&impulse)
+    }
+
+    /// Returns output y(0), y(1), ... of filter for input x(0), x(1), ...
+    /// by difference equation, initial state is zero.
+    pub fn filter(&self, input: &[T]) -> Vec<T> {
+        let mut output: Vec<T> = Vec::with_capacity(input.len());
+        for n in 0..input.len() {
+            let mut acc = T::zero();
+            for (i, b) in self.b.iter().enumerate().take(n + 1) {
+                acc = acc + b.clone() * input[n - i].clone();
+            }
+            for (i, a) in self.a.iter().enumerate().take(n + 1).skip(1) {
+                acc = acc - a.clone() * output[n - i].clone();
+            }
+            output.push(acc / self.a[0].clone());
+        }
+        output
+    }
+
+    /// Returns series connection: output of self is input of other,
+    /// H(z) = H1(z) H2(z).
+    pub fn cascade(&self, other: &Self) -> Self {
+        Self::new(self.numerator() * other.numerator(), self.denominator() * other.denominator())
+    }
+
+    /// Returns parallel connection: outputs of self and other are summed up,
+    /// H(z) = H1(z) + H2(z). Common factors of denominators are not cancelled.
+    pub fn parallel(&self, other: &Self) -> Self {
+        let num = self.numerator() * other.denominator() + other.numerator() * self.denominator();
+        Self::new(num, self.denominator() * other.denominator())
+    }
+}
+
+impl TransferFunction<f64> {
+    // roots in z of z^d * p(1 / z), where d is maximal degree of numerator and denominator
+    fn roots_in_z(&self, coefs: &[f64]) -> Vec<(f64, f64)> {
+        let n = self.a.len().max(self.b.len());
+        let mut reversed = vec![0.0; n];
+        for (i, c) in coefs.iter().enumerate() {
+            reversed[n - 1 - i] = *c;
+        }
+        Polynomial::<f64>::from_coefs(reversed).complex_roots()
+    }
+
+    /// Returns zeros of H(z) as pairs (re, im), sorted by re, then by im
+    /// (see `Polynomial::complex_roots`). If numerator is shorter
+    /// than denominator, zeros at z = 0 are included.
+    /// Panics for zero numerator.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::applications::TransferFunction;
+    /// # use polylib::polynom::Polynomial;
+    /// // moving average (1 + z^-1) / 2 has zero at -1 and pole at 0
+    /// let h = TransferFunction::new(
+    ///     Polynomial::from_coefs(vec![0.5, 0.5]),
+    ///     Polynomial::from_coefs(vec![1.0]),
+    /// );
+    /// assert_eq!(h.zeros(), vec![(-1.0, 0.0)]);
+    /// assert_eq!(h.poles(), vec![(0.0, 0.0)]);
+    /// ```
+    pub fn zeros(&self) -> Vec<(f64, f64)> {
+        self.roots_in_z(&self.b)
+    }
+
+    /// Returns poles of H(z) as pairs (re, im), sorted by re, then by im.
+    /// If denominator is shorter than numerator, poles at z = 0 are included.
+    pub fn poles(&self) -> Vec<(f64, f64)> {
+        self.roots_in_z(&self.a)
+    }
+
+    /// Returns true, if every pole is strictly inside unit circle,
+    /// so impulse response decays.
+    pub fn is_stable(&self) -> bool {
+        self.poles().iter().all(|(re, im)| re.hypot(*im) < 1.0)
+    }
+}
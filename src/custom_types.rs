@@ -1,7 +1,41 @@
 //! Module, where all custom types are presented and are re-exported
 
 pub mod zn;
+pub mod dyn_zn;
+pub mod zp;
 pub mod matrix;
+pub mod dyn_matrix;
+pub mod tropical;
+pub mod boolean;
+pub mod gf2_poly;
+pub mod gf2k;
+pub mod bigint;
+pub mod fraction;
+pub mod gaussian;
+pub mod eisenstein;
+pub mod dual;
+pub mod jet;
+pub mod fixed_point;
+#[cfg(feature = "counting")]
+pub mod counting;
 
 pub use zn::Zn;
+pub use dyn_zn::DynZn;
+pub use zp::Zp;
 pub use matrix::Matrix;
+pub use dyn_matrix::DynMatrix;
+pub use tropical::Tropical;
+pub use boolean::Bool;
+pub use gf2_poly::Gf2Poly;
+pub use gf2k::{Gf256, Gf2k};
+pub use bigint::BigInt;
+pub use fraction::Fraction;
+pub use gaussian::GaussianInt;
+pub use eisenstein::EisensteinInt;
+pub use dual::Dual;
+pub use jet::Jet;
+pub use fixed_point::Fixed;
+// std overflow wrappers, that implement `Zero` and `One`
+pub use std::num::{Saturating, Wrapping};
+#[cfg(feature = "counting")]
+pub use counting::{CountingOps, OpStats};
@@ -0,0 +1,484 @@
+//! Defines type `BigInt` - arbitrary precision integer.
+
+use std::cmp::Ordering;
+use std::fmt::Display;
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
+
+use crate::{One, Zero};
+
+/// Integer of any size: sign and magnitude, stored as u64 limbs (lowest first).
+///
+/// Division rounds toward zero, same as for primitive integers.
+///
+/// Example:
+/// ```
+/// # use polylib::custom_types::BigInt;
+/// let a = BigInt::from(u64::MAX);
+/// let b = a.clone() * a; // doesn't fit into u64
+/// assert_eq!(b.to_string(), "340282366920938463426481119284349108225");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub struct BigInt {
+    negative: bool,
+    // no trailing zero limbs, zero has no limbs and is not negative
+    mag: Vec<u64>,
+}
+
+fn trim(mut mag: Vec<u64>) -> Vec<u64> {
+    while mag.last() == Some(&0) {
+        mag.pop();
+    }
+    mag
+}
+
+fn cmp_mag(a: &[u64], b: &[u64]) -> Ordering {
+    if a.len() != b.len() {
+        return a.len().cmp(&b.len());
+    }
+    for (x, y) in a.iter().rev().zip(b.iter().rev()) {
+        if x != y {
+            return x.cmp(y);
+        }
+    }
+    Ordering::Equal
+}
+
+fn add_mag(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let (a, b) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut ans = Vec::with_capacity(a.len() + 1);
+    let mut carry = 0;
+    for (i, x) in a.iter().enumerate() {
+        let (s, c1) = x.overflowing_add(*b.get(i).unwrap_or(&0));
+        let (s, c2) = s.overflowing_add(carry);
+        ans.push(s);
+        carry = (c1 as u64) + (c2 as u64);
+    }
+    if carry > 0 {
+        ans.push(carry);
+    }
+    ans
+}
+
+// a - b, where |a| >= |b|
+fn sub_mag(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let mut ans = Vec::with_capacity(a.len());
+    let mut borrow = 0;
+    for (i, x) in a.iter().enumerate() {
+        let (d, b1) = x.overflowing_sub(*b.get(i).unwrap_or(&0));
+        let (d, b2) = d.overflowing_sub(borrow);
+        ans.push(d);
+        borrow = (b1 as u64) + (b2 as u64);
+    }
+    trim(ans)
+}
+
+fn mul_mag(a: &[u64], b: &[u64]) -> Vec<u64> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let mut ans = vec![0u64; a.len() + b.len()];
+    for (i, x) in a.iter().enumerate() {
+        let mut carry = 0u128;
+        for (j, y) in b.iter().enumerate() {
+            let cur = ans[i + j] as u128 + (*x as u128) * (*y as u128) + carry;
+            ans[i + j] = cur as u64;
+            carry = cur >> 64;
+        }
+        ans[i + b.len()] = carry as u64;
+    }
+    trim(ans)
+}
+
+// quotient and remainder of a by one limb
+fn div_rem_small(a: &[u64], b: u64) -> (Vec<u64>, u64) {
+    let mut ans = vec![0u64; a.len()];
+    let mut rem = 0u128;
+    for i in (0..a.len()).rev() {
+        let cur = (rem << 64) | a[i] as u128;
+        ans[i] = (cur / b as u128) as u64;
+        rem = cur % b as u128;
+    }
+    (trim(ans), rem as u64)
+}
+
+// binary long division, b is not zero
+fn div_rem_mag(a: &[u64], b: &[u64]) -> (Vec<u64>, Vec<u64>) {
+    if cmp_mag(a, b) == Ordering::Less {
+        return (Vec::new(), a.to_vec());
+    }
+    if b.len() == 1 {
+        let (q, r) = div_rem_small(a, b[0]);
+        return (q, trim(vec![r]));
+    }
+    let mut quotient = vec![0u64; a.len()];
+    let mut rem: Vec<u64> = Vec::with_capacity(b.len() + 1);
+    for bit in (0..a.len() * 64).rev() {
+        // rem = rem * 2 + bit
+        let mut carry = (a[bit / 64] >> (bit % 64)) & 1;
+        for limb in rem.iter_mut() {
+            let next = *limb >> 63;
+            *limb = (*limb << 1) | carry;
+            carry = next;
+        }
+        if carry > 0 {
+            rem.push(carry);
+        }
+        if cmp_mag(&rem, b) != Ordering::Less {
+            rem = sub_mag(&rem, b);
+            quotient[bit / 64] |= 1 << (bit % 64);
+        }
+    }
+    (trim(quotient), rem)
+}
+
+impl BigInt {
+    fn from_parts(negative: bool, mag: Vec<u64>) -> BigInt {
+        let mag = trim(mag);
+        BigInt {
+            negative: negative && !mag.is_empty(),
+            mag,
+        }
+    }
+
+    /// Checks, if self is less than zero.
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// Returns absolute value.
+    pub fn abs(&self) -> BigInt {
+        Self::from_parts(false, self.mag.clone())
+    }
+
+    /// Returns -1, 0 or 1, same as sign of self.
+    pub fn signum(&self) -> i32 {
+        match (self.negative, self.mag.is_empty()) {
+            (_, true) => 0,
+            (true, _) => -1,
+            (false, _) => 1,
+        }
+    }
+
+    /// Returns self in power exp.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::BigInt;
+    /// assert_eq!(BigInt::from(-3).pow(41).to_string(), "-36472996377170786403");
+    /// ```
+    pub fn pow(&self, mut exp: u32) -> BigInt {
+        let mut ans = BigInt::one();
+        let mut base = self.clone();
+        while exp > 0 {
+            if exp & 1 == 1 {
+                ans = &ans * &base;
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = &base * &base;
+            }
+        }
+        ans
+    }
+
+    /// Returns quotient (rounded toward zero) and remainder (with sign of self).
+    /// Panics on division by zero.
+    pub fn div_rem(&self, rhs: &BigInt) -> (BigInt, BigInt) {
+        if rhs.is_zero() {
+            panic!("Can't divide BigInt by zero");
+        }
+        let (q, r) = div_rem_mag(&self.mag, &rhs.mag);
+        (
+            Self::from_parts(self.negative != rhs.negative, q),
+            Self::from_parts(self.negative, r),
+        )
+    }
+
+    /// Returns greatest common divisor, it is never negative.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::BigInt;
+    /// assert_eq!(BigInt::from(-12).gcd(&BigInt::from(18)), BigInt::from(6));
+    /// ```
+    pub fn gcd(&self, rhs: &BigInt) -> BigInt {
+        let mut a = self.abs();
+        let mut b = rhs.abs();
+        while !b.is_zero() {
+            let r = a.div_rem(&b).1;
+            a = b;
+            b = r;
+        }
+        a
+    }
+
+    /// Returns value as i128, if it fits.
+    pub fn to_i128(&self) -> Option<i128> {
+        if self.mag.len() > 2 {
+            return None;
+        }
+        let mag = self.mag.iter().rev().fold(0u128, |acc, l| (acc << 64) | *l as u128);
+        if self.negative {
+            0i128.checked_sub_unsigned(mag)
+        } else {
+            i128::try_from(mag).ok()
+        }
+    }
+}
+
+impl From<i128> for BigInt {
+    fn from(value: i128) -> Self {
+        let mag = value.unsigned_abs();
+        Self::from_parts(value < 0, vec![mag as u64, (mag >> 64) as u64])
+    }
+}
+
+impl From<u128> for BigInt {
+    fn from(value: u128) -> Self {
+        Self::from_parts(false, vec![value as u64, (value >> 64) as u64])
+    }
+}
+
+impl From<i64> for BigInt {
+    fn from(value: i64) -> Self {
+        Self::from(value as i128)
+    }
+}
+
+impl From<u64> for BigInt {
+    fn from(value: u64) -> Self {
+        Self::from_parts(false, vec![value])
+    }
+}
+
+impl From<i32> for BigInt {
+    fn from(value: i32) -> Self {
+        Self::from(value as i128)
+    }
+}
+
+impl Zero for BigInt {
+    fn zero() -> Self {
+        BigInt::default()
+    }
+
+    fn is_zero(&self) -> bool {
+        self.mag.is_empty()
+    }
+}
+
+impl One for BigInt {
+    fn one() -> Self {
+        BigInt::from(1u64)
+    }
+
+    fn is_one(&self) -> bool {
+        !self.negative && self.mag == [1]
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => cmp_mag(&self.mag, &other.mag),
+            (true, true) => cmp_mag(&other.mag, &self.mag),
+        }
+    }
+}
+
+impl Neg for BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> Self::Output {
+        Self::from_parts(!self.negative, self.mag)
+    }
+}
+
+impl Neg for &BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> Self::Output {
+        -self.clone()
+    }
+}
+
+impl Add for &BigInt {
+    type Output = BigInt;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        if self.negative == rhs.negative {
+            return BigInt::from_parts(self.negative, add_mag(&self.mag, &rhs.mag));
+        }
+        match cmp_mag(&self.mag, &rhs.mag) {
+            Ordering::Less => BigInt::from_parts(rhs.negative, sub_mag(&rhs.mag, &self.mag)),
+            _ => BigInt::from_parts(self.negative, sub_mag(&self.mag, &rhs.mag)),
+        }
+    }
+}
+
+impl Sub for &BigInt {
+    type Output = BigInt;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + &(-rhs)
+    }
+}
+
+impl Mul for &BigInt {
+    type Output = BigInt;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        BigInt::from_parts(self.negative != rhs.negative, mul_mag(&self.mag, &rhs.mag))
+    }
+}
+
+impl Div for &BigInt {
+    type Output = BigInt;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        self.div_rem(rhs).0
+    }
+}
+
+impl Rem for &BigInt {
+    type Output = BigInt;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        self.div_rem(rhs).1
+    }
+}
+
+// owned versions use versions for references
+macro_rules! impl_owned_op {
+    ($($tr:ident $f:ident),*) => {
+        $(
+            impl $tr for BigInt {
+                type Output = BigInt;
+
+                fn $f(self, rhs: Self) -> Self::Output {
+                    (&self).$f(&rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_owned_op!(Add add, Sub sub, Mul mul, Div div, Rem rem);
+
+impl AddAssign for BigInt {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = &*self + &rhs;
+    }
+}
+
+impl SubAssign for BigInt {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = &*self - &rhs;
+    }
+}
+
+impl MulAssign for BigInt {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = &*self * &rhs;
+    }
+}
+
+impl Display for BigInt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.mag.is_empty() {
+            return write!(f, "0");
+        }
+        // split into 19-digit chunks
+        const CHUNK: u64 = 10_000_000_000_000_000_000;
+        let mut chunks = Vec::new();
+        let mut mag = self.mag.clone();
+        while !mag.is_empty() {
+            let (q, r) = div_rem_small(&mag, CHUNK);
+            chunks.push(r);
+            mag = q;
+        }
+        if self.negative {
+            write!(f, "-")?;
+        }
+        write!(f, "{}", chunks.last().unwrap())?;
+        for c in chunks.iter().rev().skip(1) {
+            write!(f, "{:019}", c)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod bigint_tests {
+    use super::BigInt;
+    use crate::polynom::Polynomial;
+    use crate::{One, Zero};
+
+    fn big(v: i128) -> BigInt {
+        BigInt::from(v)
+    }
+
+    #[test]
+    fn test_small() {
+        let values = [0i128, 1, -1, 7, -13, 1 << 64, -(1 << 70) + 5, i64::MAX as i128];
+        for a in values {
+            for b in values {
+                assert_eq!((big(a) + big(b)).to_i128(), Some(a + b), "{} + {}", a, b);
+                assert_eq!((big(a) - big(b)).to_i128(), Some(a - b), "{} - {}", a, b);
+                assert_eq!(big(a).cmp(&big(b)), a.cmp(&b), "{} cmp {}", a, b);
+                if let Some(m) = a.checked_mul(b) {
+                    assert_eq!((big(a) * big(b)).to_i128(), Some(m), "{} * {}", a, b);
+                }
+                if b != 0 {
+                    assert_eq!((big(a) / big(b)).to_i128(), Some(a / b), "{} / {}", a, b);
+                    assert_eq!((big(a) % big(b)).to_i128(), Some(a % b), "{} % {}", a, b);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_large() {
+        let a = BigInt::from(3).pow(200);
+        let b = BigInt::from(-7).pow(90);
+        let (q, r) = a.div_rem(&b);
+        assert_eq!(&(&q * &b) + &r, a);
+        assert!(r.abs() < b.abs());
+        assert_eq!((&a * &b) / b.clone(), a);
+        assert_eq!(a.gcd(&BigInt::from(6).pow(50)), BigInt::from(3).pow(50));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(BigInt::zero().to_string(), "0");
+        assert_eq!(big(-42).to_string(), "-42");
+        assert_eq!(BigInt::from(10).pow(40).to_string(), format!("1{}", "0".repeat(40)));
+        assert_eq!(big(i128::MIN).to_string(), i128::MIN.to_string());
+    }
+
+    #[test]
+    fn test_i128_bounds() {
+        assert_eq!(big(i128::MIN).to_i128(), Some(i128::MIN));
+        assert_eq!(big(i128::MAX).to_i128(), Some(i128::MAX));
+        assert_eq!((big(i128::MAX) + BigInt::one()).to_i128(), None);
+        assert!((big(5) - big(5)).is_zero());
+        assert!(!(big(5) - big(5)).is_negative());
+    }
+
+    #[test]
+    fn test_poly() {
+        // (x + 1)^8 at 10^6 overflows i128
+        let p = Polynomial::<BigInt>::from_coefs(vec![BigInt::one(), BigInt::one()]).pow(8);
+        let v: BigInt = p.reduce().substitude(BigInt::from(10).pow(6));
+        assert_eq!(v, BigInt::from(1_000_001).pow(8));
+        assert_eq!(v.to_i128(), None);
+    }
+}
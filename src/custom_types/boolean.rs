@@ -0,0 +1,134 @@
+//! Defines type `Bool` - element of boolean (or, and) semiring.
+
+use std::fmt::Display;
+use std::ops::{Add, AddAssign, Mul, MulAssign};
+
+use crate::random::{Random, Xorshift64};
+use crate::{One, Zero};
+
+/// Element of boolean semiring.
+///
+/// Addition is `or`, multiplication is `and`.
+/// So `zero` is `false` and `one` is `true`.
+/// Powers of adjacency matrix over it give reachability.
+///
+/// Example:
+/// ```
+/// # use polylib::custom_types::Bool;
+/// let t = Bool::new(true);
+/// let f = Bool::new(false);
+/// assert_eq!(t + f, t); // true or false
+/// assert_eq!(t * f, f); // true and false
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone, Debug, Default)]
+pub struct Bool(bool);
+
+impl Bool {
+    /// Creates element from bool.
+    pub fn new(value: bool) -> Bool {
+        Bool(value)
+    }
+
+    /// Returns holding value.
+    pub fn value(&self) -> bool {
+        self.0
+    }
+}
+
+impl From<bool> for Bool {
+    fn from(value: bool) -> Self {
+        Bool(value)
+    }
+}
+
+impl Zero for Bool {
+    fn zero() -> Self {
+        Bool(false)
+    }
+
+    fn is_zero(&self) -> bool {
+        !self.0
+    }
+}
+
+impl One for Bool {
+    fn one() -> Self {
+        Bool(true)
+    }
+
+    fn is_one(&self) -> bool {
+        self.0
+    }
+}
+
+impl Add for Bool {
+    type Output = Bool;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn add(self, rhs: Self) -> Self::Output {
+        Bool(self.0 || rhs.0)
+    }
+}
+
+impl AddAssign for Bool {
+    #[allow(clippy::suspicious_op_assign_impl)]
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+impl Mul for Bool {
+    type Output = Bool;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, rhs: Self) -> Self::Output {
+        Bool(self.0 && rhs.0)
+    }
+}
+
+impl MulAssign for Bool {
+    #[allow(clippy::suspicious_op_assign_impl)]
+    fn mul_assign(&mut self, rhs: Self) {
+        self.0 &= rhs.0;
+    }
+}
+
+impl Random for Bool {
+    fn random(rng: &mut Xorshift64) -> Self {
+        Bool(rng.next_u64() & 1 == 1)
+    }
+}
+
+impl Display for Bool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0 as u8)
+    }
+}
+
+#[cfg(test)]
+mod boolean_tests {
+    use super::Bool;
+    use crate::{One, Zero};
+
+    #[test]
+    fn test_ops() {
+        let (t, f) = (Bool::one(), Bool::zero());
+        assert_eq!(t + t, t);
+        assert_eq!(t + f, t);
+        assert_eq!(f + f, f);
+        assert_eq!(t * t, t);
+        assert_eq!(t * f, f);
+        assert_eq!(f * f, f);
+        let mut a = f;
+        a += t;
+        assert!(a.is_one());
+        a *= f;
+        assert!(a.is_zero());
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Bool::new(true).to_string(), "1");
+        assert_eq!(Bool::from(false).to_string(), "0");
+    }
+}
@@ -0,0 +1,246 @@
+//! Defines type `CountingOps` - wrapper, that counts arithmetic operations.
+//!
+//! Available with feature `counting`.
+
+use std::cell::Cell;
+use std::fmt::Display;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::{One, Zero};
+
+thread_local! {
+    static ADDITIONS: Cell<u64> = const { Cell::new(0) };
+    static MULTIPLICATIONS: Cell<u64> = const { Cell::new(0) };
+}
+
+fn count(counter: &'static std::thread::LocalKey<Cell<u64>>) {
+    counter.with(|c| c.set(c.get() + 1));
+}
+
+/// Count of operations with `CountingOps` values, performed in current thread.
+///
+/// Subtractions and negations are counted as additions.
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Default)]
+pub struct OpStats {
+    pub additions: u64,
+    pub multiplications: u64,
+}
+
+impl OpStats {
+    /// Returns count of operations since last `reset`.
+    pub fn current() -> OpStats {
+        OpStats {
+            additions: ADDITIONS.with(Cell::get),
+            multiplications: MULTIPLICATIONS.with(Cell::get),
+        }
+    }
+
+    /// Sets all counters of current thread to zero.
+    pub fn reset() {
+        ADDITIONS.with(|c| c.set(0));
+        MULTIPLICATIONS.with(|c| c.set(0));
+    }
+
+    /// Runs function and returns its result with count of operations it made.
+    /// If function calls `reset`, only operations after it are counted.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::{CountingOps, OpStats};
+    /// # use polylib::polynom::Polynomial;
+    /// type C = CountingOps<i64>;
+    /// let p = Polynomial::<C>::from_coefs(vec![C::new(1); 9]);
+    /// let (value, stats) = OpStats::measure(|| p.substitude::<C, C>(C::new(2)));
+    /// assert_eq!(value.into_inner(), 511);
+    /// assert_eq!(stats.additions, 9);
+    /// ```
+    pub fn measure<R>(f: impl FnOnce() -> R) -> (R, OpStats) {
+        // counters start from zero, so `reset` inside f can't make them less than before
+        let before = Self::current();
+        Self::reset();
+        let ans = f();
+        let stats = Self::current();
+        ADDITIONS.with(|c| c.set(before.additions + stats.additions));
+        MULTIPLICATIONS.with(|c| c.set(before.multiplications + stats.multiplications));
+        (ans, stats)
+    }
+}
+
+/// Wrapper, that counts additions and multiplications with it.
+///
+/// Use it as polynomial coefficients or points
+/// to compare evaluation strategies. See `OpStats`.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Debug, Default)]
+pub struct CountingOps<T>(T);
+
+impl<T> CountingOps<T> {
+    /// Wraps value.
+    pub fn new(value: T) -> CountingOps<T> {
+        CountingOps(value)
+    }
+
+    /// Returns holding value.
+    pub fn value(&self) -> &T {
+        &self.0
+    }
+
+    /// Returns holding value, consuming wrapper.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: Zero> Zero for CountingOps<T> {
+    fn zero() -> Self {
+        Self::new(T::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+}
+
+impl<T: One> One for CountingOps<T> {
+    fn one() -> Self {
+        Self::new(T::one())
+    }
+
+    fn is_one(&self) -> bool {
+        self.0.is_one()
+    }
+}
+
+impl<T: Add<Output = T>> Add for CountingOps<T> {
+    type Output = CountingOps<T>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        count(&ADDITIONS);
+        Self::new(self.0 + rhs.0)
+    }
+}
+
+impl<T: AddAssign> AddAssign for CountingOps<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        count(&ADDITIONS);
+        self.0 += rhs.0;
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for CountingOps<T> {
+    type Output = CountingOps<T>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        count(&ADDITIONS);
+        Self::new(self.0 - rhs.0)
+    }
+}
+
+impl<T: SubAssign> SubAssign for CountingOps<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        count(&ADDITIONS);
+        self.0 -= rhs.0;
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for CountingOps<T> {
+    type Output = CountingOps<T>;
+
+    fn neg(self) -> Self::Output {
+        count(&ADDITIONS);
+        Self::new(-self.0)
+    }
+}
+
+impl<T: Mul<Output = T>> Mul for CountingOps<T> {
+    type Output = CountingOps<T>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        count(&MULTIPLICATIONS);
+        Self::new(self.0 * rhs.0)
+    }
+}
+
+impl<T: MulAssign> MulAssign for CountingOps<T> {
+    fn mul_assign(&mut self, rhs: Self) {
+        count(&MULTIPLICATIONS);
+        self.0 *= rhs.0;
+    }
+}
+
+impl<T: Display> Display for CountingOps<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod counting_tests {
+    use super::{CountingOps, OpStats};
+    use crate::polynom::{Polynomial, X};
+
+    type C = CountingOps<i64>;
+
+    #[test]
+    fn test_count() {
+        OpStats::reset();
+        let a = C::new(3);
+        let b = C::new(4);
+        assert_eq!((a + b).into_inner(), 7);
+        assert_eq!((a * b).into_inner(), 12);
+        assert_eq!((a - b).into_inner(), -1);
+        assert_eq!((-a).into_inner(), -3);
+        assert_eq!(
+            OpStats::current(),
+            OpStats {
+                additions: 3,
+                multiplications: 1
+            }
+        );
+        OpStats::reset();
+        assert_eq!(OpStats::current(), OpStats::default());
+    }
+
+    #[test]
+    fn test_substitude() {
+        let x = X::<C>::default();
+        // binary powering: x^8 takes 3 squares and 1 multiplication by one
+        let p = x.pow(8);
+        let (value, stats) = OpStats::measure(|| p.substitude::<C, C>(C::new(2)));
+        assert_eq!(value.into_inner(), 256);
+        assert_eq!(stats.multiplications, 5);
+        assert_eq!(stats.additions, 1);
+    }
+
+    #[test]
+    fn test_mul_pow() {
+        let a = Polynomial::<C>::from_coefs(vec![C::new(1); 3]);
+        let (_, stats) = OpStats::measure(|| a.clone() * a.clone());
+        assert_eq!(stats.multiplications, 9);
+        assert_eq!(stats.additions, 0);
+
+        let (p, stats) = OpStats::measure(|| a.clone().pow(2));
+        assert!(stats.multiplications >= 9);
+        assert_eq!(p.reduce().substitude::<C, C>(C::new(1)).into_inner(), 9);
+    }
+
+    #[test]
+    fn test_measure_reset() {
+        OpStats::reset();
+        let a = C::new(3);
+        let _ = a + a;
+        let (_, stats) = OpStats::measure(|| {
+            let _ = a * a;
+            OpStats::reset();
+            a + a
+        });
+        assert_eq!(
+            stats,
+            OpStats {
+                additions: 1,
+                multiplications: 0
+            }
+        );
+        // outer counters keep operations before and inside measure
+        assert_eq!(OpStats::current().additions, 2);
+    }
+}
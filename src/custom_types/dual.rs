@@ -0,0 +1,211 @@
+//! Defines type `Dual` - dual number for automatic differentiation.
+
+use std::fmt::Display;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::{One, Zero};
+
+/// Dual number value + deriv * ε, where ε^2 = 0.
+///
+/// Substitution of `Dual::variable(x)` into polynomial p
+/// gives p(x) and p'(x) at once.
+///
+/// Example:
+/// ```
+/// # use polylib::custom_types::Dual;
+/// # use polylib::polynom::Polynomial;
+/// let p = Polynomial::<i32>::from_coefs(vec![1, 0, 3]); // 3x^2 + 1
+/// let ans: Dual<i32> = p.substitude(Dual::variable(2));
+/// assert_eq!(ans.value(), &13); // p(2)
+/// assert_eq!(ans.deriv(), &12); // p'(2) = 6 * 2
+/// ```
+#[derive(PartialEq, Eq, Hash, Copy, Clone, Debug, Default)]
+pub struct Dual<T> {
+    value: T,
+    deriv: T,
+}
+
+impl<T> Dual<T> {
+    /// Creates value + deriv * ε.
+    pub fn new(value: T, deriv: T) -> Dual<T> {
+        Dual { value, deriv }
+    }
+
+    /// Returns value part.
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Returns derivative part.
+    pub fn deriv(&self) -> &T {
+        &self.deriv
+    }
+
+    /// Returns pair (value, deriv).
+    pub fn into_parts(self) -> (T, T) {
+        (self.value, self.deriv)
+    }
+}
+
+impl<T: Zero> Dual<T> {
+    /// Creates constant: derivative part is zero.
+    pub fn constant(value: T) -> Dual<T> {
+        Dual::new(value, T::zero())
+    }
+}
+
+impl<T: One> Dual<T> {
+    /// Creates independent variable: derivative part is one.
+    pub fn variable(value: T) -> Dual<T> {
+        Dual::new(value, T::one())
+    }
+}
+
+impl<T: Zero> From<T> for Dual<T> {
+    fn from(value: T) -> Self {
+        Self::constant(value)
+    }
+}
+
+impl<T: Zero> Zero for Dual<T> {
+    fn zero() -> Self {
+        Dual::new(T::zero(), T::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.value.is_zero() && self.deriv.is_zero()
+    }
+}
+
+impl<T: Zero + One> One for Dual<T> {
+    fn one() -> Self {
+        Dual::new(T::one(), T::zero())
+    }
+
+    fn is_one(&self) -> bool {
+        self.value.is_one() && self.deriv.is_zero()
+    }
+}
+
+impl<T: Add<Output = T>> Add for Dual<T> {
+    type Output = Dual<T>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Dual::new(self.value + rhs.value, self.deriv + rhs.deriv)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Dual<T> {
+    type Output = Dual<T>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Dual::new(self.value - rhs.value, self.deriv - rhs.deriv)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Dual<T> {
+    type Output = Dual<T>;
+
+    fn neg(self) -> Self::Output {
+        Dual::new(-self.value, -self.deriv)
+    }
+}
+
+impl<T> Mul for Dual<T>
+where
+    T: Clone + Add<Output = T> + Mul<Output = T>,
+{
+    type Output = Dual<T>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        // (a + bε)(c + dε) = ac + (ad + bc)ε
+        Dual::new(
+            self.value.clone() * rhs.value.clone(),
+            self.value * rhs.deriv + self.deriv * rhs.value,
+        )
+    }
+}
+
+impl<T> Div for Dual<T>
+where
+    T: Clone + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+{
+    type Output = Dual<T>;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        // (a + bε) / (c + dε) = a / c + (bc - ad) / c^2 ε
+        let den = rhs.value.clone() * rhs.value.clone();
+        Dual::new(
+            self.value.clone() / rhs.value.clone(),
+            (self.deriv * rhs.value - self.value * rhs.deriv) / den,
+        )
+    }
+}
+
+// coef * point, so polynomials with plain coefs accept dual points
+macro_rules! impl_scalar_mul {
+    ($($t:ty),*) => {
+        $(
+            impl Mul<Dual<$t>> for $t {
+                type Output = Dual<$t>;
+
+                fn mul(self, rhs: Dual<$t>) -> Self::Output {
+                    Dual::new(self * rhs.value, self * rhs.deriv)
+                }
+            }
+        )*
+    };
+}
+
+impl_scalar_mul!(i8, i16, i32, i64, i128, isize);
+impl_scalar_mul!(u8, u16, u32, u64, u128, usize);
+impl_scalar_mul!(f32, f64);
+
+impl<T: Display> Display for Dual<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({} + {}ε)", self.value, self.deriv)
+    }
+}
+
+#[cfg(test)]
+mod dual_tests {
+    use super::Dual;
+    use crate::{One, Zero};
+
+    type D = Dual<i32>;
+
+    #[test]
+    fn test_ops() {
+        let a = D::new(2, 3);
+        let b = D::new(-1, 4);
+        assert_eq!(a + b, D::new(1, 7));
+        assert_eq!(a - b, D::new(3, -1));
+        assert_eq!(a * b, D::new(-2, 5));
+        assert_eq!(-a, D::new(-2, -3));
+        assert_eq!(3 * a, D::new(6, 9));
+        assert_eq!(a * D::one(), a);
+        assert!((a * D::zero()).is_zero());
+    }
+
+    #[test]
+    fn test_div() {
+        let a = Dual::new(1.0, 2.0);
+        let b = Dual::new(2.0, 1.0);
+        // (a / b) * b == a
+        assert_eq!(a / b * b, a);
+        assert_eq!((a / b).into_parts(), (0.5, 0.75));
+    }
+
+    #[test]
+    fn test_chain() {
+        // d/dx (x^3) at 2 is 12
+        let x = D::variable(2);
+        assert_eq!(x * x * x, D::new(8, 12));
+        assert_eq!(D::constant(5) * x, D::new(10, 5));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(D::new(1, -2).to_string(), "(1 + -2ε)");
+    }
+}
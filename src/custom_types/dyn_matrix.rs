@@ -0,0 +1,816 @@
+//! Defines type `DynMatrix` - matrix with runtime sizes.
+
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
+};
+
+use super::matrix::{charpoly_coefs, Matrix};
+use super::Zn;
+use crate::polynom::Polynomial;
+use crate::{CoefRing, One, PolyError, Zero};
+
+/// Type `DynMatrix`. Same as `Matrix`, but sizes are known only at runtime.
+/// T - type of element.
+///
+/// Operations with mismatched sizes panic, `checked_*` functions return error instead.
+/// Sizes are unknown for `Zero` and `One`, so `DynMatrix` can't be substituded
+/// into polynomial. Convert it to `Matrix` for that.
+///
+/// Example:
+/// ```
+/// # use polylib::custom_types::{DynMatrix, Matrix};
+/// let m = DynMatrix::from_data(2, 3, vec![1, 2, 3, 4, 5, 6]);
+/// assert_eq!(m[(1, 0)], 4);
+/// let fixed: Matrix<2, 3, i32> = m.try_into().unwrap();
+/// assert_eq!(fixed[(1, 0)], 4);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DynMatrix<T> {
+    rows: usize,
+    cols: usize,
+    data: Vec<T>,
+}
+
+impl<T> DynMatrix<T> {
+    /// Returns matrix<rows, cols>, where each element is value
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::DynMatrix;
+    /// let m = DynMatrix::full(2, 3, 3); // is matrix [[3, 3, 3], [3, 3, 3]]
+    /// assert_eq!(m.get_data(), &vec![3, 3, 3, 3, 3, 3]);
+    /// ```
+    pub fn full(rows: usize, cols: usize, value: T) -> DynMatrix<T>
+    where
+        T: Clone,
+    {
+        DynMatrix {
+            rows,
+            cols,
+            data: vec![value; rows * cols],
+        }
+    }
+
+    /// Returns matrix<rows, cols>, where each element on
+    /// main diagonal is value
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::DynMatrix;
+    /// let m = DynMatrix::eye(2, 3, 3); // is matrix [[3, 0, 0], [0, 3, 0]]
+    /// assert_eq!(m.get_data(), &vec![3, 0, 0, 0, 3, 0]);
+    /// ```
+    pub fn eye(rows: usize, cols: usize, value: T) -> DynMatrix<T>
+    where
+        T: Clone + Zero,
+    {
+        let mut ans = Self::full(rows, cols, T::zero());
+        for i in 0..rows.min(cols) {
+            ans[(i, i)] = value.clone();
+        }
+        ans
+    }
+
+    /// Returns square identity matrix
+    pub fn identity(size: usize) -> DynMatrix<T>
+    where
+        T: Clone + Zero + One,
+    {
+        Self::eye(size, size, T::one())
+    }
+
+    /// Returns matrix<rows, cols>, where
+    /// elements are got from data
+    ///
+    /// Panics if data has not rows * cols elements.
+    pub fn from_data(rows: usize, cols: usize, data: Vec<T>) -> DynMatrix<T> {
+        match Self::try_from_data(rows, cols, data) {
+            Ok(ans) => ans,
+            Err(err) => panic!("Can't create matrix: {}", err),
+        }
+    }
+
+    /// Same as `from_data`, but returns error instead of panic
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::DynMatrix;
+    /// # use polylib::PolyError;
+    /// let m = DynMatrix::try_from_data(2, 2, vec![1, 2, 3]);
+    /// assert_eq!(m, Err(PolyError::DimensionMismatch { rows: 2, cols: 2, len: 3 }));
+    /// ```
+    pub fn try_from_data(
+        rows: usize,
+        cols: usize,
+        data: Vec<T>,
+    ) -> Result<DynMatrix<T>, PolyError> {
+        if data.len() != rows * cols {
+            return Err(PolyError::DimensionMismatch { rows, cols, len: data.len() });
+        }
+        Ok(DynMatrix { rows, cols, data })
+    }
+
+    /// Returns count of rows
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns count of columns
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Returns pair (rows, cols)
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    /// Returns matrix elements in 1d vector
+    ///
+    pub fn get_data(&self) -> &Vec<T> {
+        &self.data
+    }
+
+    /// Set matrix elements from 1d vector. Sizes are not changed.
+    ///
+    pub fn set_data(&mut self, data: Vec<T>) {
+        if let Err(err) = self.try_set_data(data) {
+            panic!("Can't set data to matrix: {}", err)
+        }
+    }
+
+    /// Same as `set_data`, but returns error instead of panic.
+    /// Matrix is not changed on error.
+    ///
+    pub fn try_set_data(&mut self, data: Vec<T>) -> Result<(), PolyError> {
+        if data.len() != self.rows * self.cols {
+            return Err(PolyError::DimensionMismatch {
+                rows: self.rows,
+                cols: self.cols,
+                len: data.len(),
+            });
+        }
+        self.data = data;
+        Ok(())
+    }
+
+    /// Returns element [row, col] or error, if index is out of matrix
+    pub fn try_get(&self, row: usize, col: usize) -> Result<&T, PolyError> {
+        if row >= self.rows || col >= self.cols {
+            return Err(PolyError::IndexOutOfBounds {
+                row,
+                col,
+                rows: self.rows,
+                cols: self.cols,
+            });
+        }
+        Ok(&self.data[row * self.cols + col])
+    }
+
+    /// Returns element [row, col] or `None`, if index is out of matrix
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        if row >= self.rows || col >= self.cols {
+            return None;
+        }
+        self.data.get(row * self.cols + col)
+    }
+
+    /// Returns mutable element [row, col] or `None`, if index is out of matrix
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        if row >= self.rows || col >= self.cols {
+            return None;
+        }
+        self.data.get_mut(row * self.cols + col)
+    }
+
+    /// Returns transposed matrix<cols, rows>
+    pub fn transpose(self) -> DynMatrix<T> {
+        let (rows, cols) = (self.rows, self.cols);
+        let mut cells: Vec<Option<T>> = self.data.into_iter().map(Some).collect();
+        let data = (0..rows * cols)
+            .map(|k| {
+                let (row, col) = (k / rows, k % rows);
+                cells[col * cols + row].take().expect("each cell is taken once")
+            })
+            .collect();
+        DynMatrix { rows: cols, cols: rows, data }
+    }
+
+    /// Returns matrix, where each element is multiplied by scalar k.
+    /// Same as `Matrix::scale`.
+    pub fn scale<A>(mut self, k: A) -> DynMatrix<T>
+    where
+        A: Clone,
+        T: MulAssign<A>,
+    {
+        for elem in self.data.iter_mut() {
+            *elem *= k.clone();
+        }
+        self
+    }
+
+    // error if self and rhs have different sizes
+    fn check_same_shape(&self, rhs: &DynMatrix<T>) -> Result<(), PolyError> {
+        if self.shape() != rhs.shape() {
+            return Err(PolyError::ShapeMismatch {
+                lhs: self.shape(),
+                rhs: rhs.shape(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Returns self + rhs, or error if sizes are different
+    pub fn checked_add(mut self, rhs: DynMatrix<T>) -> Result<DynMatrix<T>, PolyError>
+    where
+        T: AddAssign<T>,
+    {
+        self.check_same_shape(&rhs)?;
+        for (lhs, rhs) in self.data.iter_mut().zip(rhs.data) {
+            *lhs += rhs;
+        }
+        Ok(self)
+    }
+
+    /// Returns self - rhs, or error if sizes are different
+    pub fn checked_sub(mut self, rhs: DynMatrix<T>) -> Result<DynMatrix<T>, PolyError>
+    where
+        T: SubAssign<T>,
+    {
+        self.check_same_shape(&rhs)?;
+        for (lhs, rhs) in self.data.iter_mut().zip(rhs.data) {
+            *lhs -= rhs;
+        }
+        Ok(self)
+    }
+
+    /// Returns self * rhs, or error if self.cols() != rhs.rows()
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::DynMatrix;
+    /// # use polylib::PolyError;
+    /// let a = DynMatrix::from_data(1, 2, vec![1, 2]);
+    /// let b = DynMatrix::from_data(2, 1, vec![3, 4]);
+    /// assert_eq!(a.checked_mul(&b).unwrap().get_data(), &vec![11]);
+    /// assert_eq!(a.checked_mul(&a), Err(PolyError::ShapeMismatch { lhs: (1, 2), rhs: (1, 2) }));
+    /// ```
+    pub fn checked_mul(&self, rhs: &DynMatrix<T>) -> Result<DynMatrix<T>, PolyError>
+    where
+        T: Clone + Zero,
+        T: Mul<T, Output = T>,
+        T: AddAssign<T>,
+    {
+        if self.cols != rhs.rows {
+            return Err(PolyError::ShapeMismatch {
+                lhs: self.shape(),
+                rhs: rhs.shape(),
+            });
+        }
+        let mut ans = Self::full(self.rows, rhs.cols, T::zero());
+        for n in 0..self.rows {
+            for k in 0..self.cols {
+                for m in 0..rhs.cols {
+                    ans[(n, m)] += self[(n, k)].clone() * rhs[(k, m)].clone();
+                }
+            }
+        }
+        Ok(ans)
+    }
+
+    /// Returns self in power exp. Uses square-and-multiply.
+    /// Panics if matrix is not square.
+    pub fn pow(&self, mut exp: u64) -> DynMatrix<T>
+    where
+        T: Clone + Zero + One,
+        T: Mul<T, Output = T>,
+        T: AddAssign<T>,
+    {
+        if self.rows != self.cols {
+            panic!("Can't raise non square matrix<{}, {}> to power", self.rows, self.cols);
+        }
+        let mut ans = Self::identity(self.rows);
+        let mut base = self.clone();
+        while exp > 0 {
+            if exp & 1 == 1 {
+                ans *= base.clone();
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = base.clone() * base;
+            }
+        }
+        ans
+    }
+
+    /// Returns determinant. It does not use division, so T may be any ring.
+    /// Panics if matrix is not square.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::DynMatrix;
+    /// let m = DynMatrix::from_data(2, 2, vec![1, 2, 3, 4]);
+    /// assert_eq!(m.determinant(), -2);
+    /// ```
+    pub fn determinant(&self) -> T
+    where
+        T: CoefRing,
+    {
+        if self.rows != self.cols {
+            panic!("Can't get determinant of non square matrix<{}, {}>", self.rows, self.cols);
+        }
+        let c0 = charpoly_coefs(&self.data, self.rows).pop().expect("charpoly is not empty");
+        if self.rows.is_multiple_of(2) {
+            c0
+        } else {
+            -c0
+        }
+    }
+}
+
+impl<T> DynMatrix<T>
+where
+    T: Clone + Zero,
+    T: Add<T, Output = T>,
+{
+    /// Returns Toeplitz matrix of multiplication by polynomial p of degree n:
+    /// matrix<n + cols, cols> with elements `a(i - j)`, so product of it
+    /// and column of coefs of q (of degree less than cols) is column of coefs of p * q.
+    /// Zero polynomial has degree 0 here.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::DynMatrix;
+    /// # use polylib::polynom::Polynomial;
+    /// let p = Polynomial::<i32>::from_coefs(vec![1, 2, 3]);
+    /// let m = DynMatrix::toeplitz(&p, 2); // [[1, 0], [2, 1], [3, 2], [0, 3]]
+    /// assert_eq!(m.get_data(), &vec![1, 0, 2, 1, 3, 2, 0, 3]);
+    /// // (1 + 2x + 3x^2)(4 + 5x)
+    /// let q = DynMatrix::from_data(2, 1, vec![4, 5]);
+    /// assert_eq!((m * q).get_data(), &vec![4, 13, 22, 15]);
+    /// ```
+    pub fn toeplitz<U>(p: &Polynomial<T, U>, cols: usize) -> DynMatrix<T> {
+        let p = p.clone().reduce();
+        let rows = p.degree().unwrap_or(0) as usize + cols;
+        let mut ans = Self::full(rows, cols, T::zero());
+        for j in 0..cols {
+            for i in j..rows {
+                ans[(i, j)] = p.coef((i - j) as u32);
+            }
+        }
+        ans
+    }
+
+    /// Returns Hankel matrix<size, size> with elements `a(i + j)`,
+    /// that is moment matrix, if coefs a0, a1, ... are moments of a measure.
+    /// Coefs above degree are zero.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::DynMatrix;
+    /// # use polylib::polynom::Polynomial;
+    /// let p = Polynomial::<i32>::from_coefs(vec![1, 2, 3, 4]);
+    /// let m = DynMatrix::hankel(&p, 3); // [[1, 2, 3], [2, 3, 4], [3, 4, 0]]
+    /// assert_eq!(m.get_data(), &vec![1, 2, 3, 2, 3, 4, 3, 4, 0]);
+    /// ```
+    pub fn hankel<U>(p: &Polynomial<T, U>, size: usize) -> DynMatrix<T> {
+        let p = p.clone().reduce();
+        let mut ans = Self::full(size, size, T::zero());
+        for i in 0..size {
+            for j in 0..size {
+                ans[(i, j)] = p.coef((i + j) as u32);
+            }
+        }
+        ans
+    }
+}
+
+impl DynMatrix<i64> {
+    /// Returns Smith normal form (U, S, V), such that U * self * V = S.
+    ///
+    /// S is diagonal matrix diag(d1, d2, ..., dk, 0, ..., 0) of the same shape as self,
+    /// where each di is positive and divides d(i+1). Numbers di are invariant factors.
+    /// U and V are square unimodular matrices (their determinants are 1 or -1).
+    ///
+    /// Elements may overflow i64 in intermediate steps (panic in debug build).
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::DynMatrix;
+    /// let a = DynMatrix::from_data(2, 3, vec![2, 4, 4, -6, 6, 12]);
+    /// let (u, s, v) = a.smith_normal_form();
+    /// assert_eq!(s, DynMatrix::from_data(2, 3, vec![2, 0, 0, 0, 6, 0]));
+    /// assert_eq!(u * a * v, s);
+    /// ```
+    pub fn smith_normal_form(&self) -> (DynMatrix<i64>, DynMatrix<i64>, DynMatrix<i64>) {
+        let (rows, cols) = self.shape();
+        let mut u = DynMatrix::identity(rows);
+        let mut s = self.clone();
+        let mut v = DynMatrix::identity(cols);
+
+        for t in 0..rows.min(cols) {
+            loop {
+                // nonzero element of least absolute value goes to [t, t]
+                let pivot = (t..rows)
+                    .flat_map(|i| (t..cols).map(move |j| (i, j)))
+                    .filter(|&(i, j)| s[(i, j)] != 0)
+                    .min_by_key(|&(i, j)| s[(i, j)].unsigned_abs());
+                let (pi, pj) = match pivot {
+                    Some(pivot) => pivot,
+                    // rest of matrix is zero
+                    None => return (u, s, v),
+                };
+                s.swap_rows(t, pi);
+                u.swap_rows(t, pi);
+                s.swap_cols(t, pj);
+                v.swap_cols(t, pj);
+
+                // eliminate column t and row t, remainders are less than pivot
+                let mut done = true;
+                for i in t + 1..rows {
+                    let q = s[(i, t)] / s[(t, t)];
+                    s.add_row(t, i, -q);
+                    u.add_row(t, i, -q);
+                    done &= s[(i, t)] == 0;
+                }
+                for j in t + 1..cols {
+                    let q = s[(t, j)] / s[(t, t)];
+                    s.add_col(t, j, -q);
+                    v.add_col(t, j, -q);
+                    done &= s[(t, j)] == 0;
+                }
+                if !done {
+                    continue;
+                }
+
+                // pivot should divide the rest, otherwise add bad row and repeat
+                let bad_row = (t + 1..rows)
+                    .find(|&i| (t + 1..cols).any(|j| s[(i, j)] % s[(t, t)] != 0));
+                match bad_row {
+                    Some(i) => {
+                        s.add_row(i, t, 1);
+                        u.add_row(i, t, 1);
+                    }
+                    None => break,
+                }
+            }
+            if s[(t, t)] < 0 {
+                // row t -= 2 * row t, so it is negated
+                s.add_row(t, t, -2);
+                u.add_row(t, t, -2);
+            }
+        }
+        (u, s, v)
+    }
+
+    fn swap_rows(&mut self, a: usize, b: usize) {
+        for j in 0..self.cols {
+            self.data.swap(a * self.cols + j, b * self.cols + j);
+        }
+    }
+
+    fn swap_cols(&mut self, a: usize, b: usize) {
+        for i in 0..self.rows {
+            self.data.swap(i * self.cols + a, i * self.cols + b);
+        }
+    }
+
+    // row dst += k * row src
+    fn add_row(&mut self, src: usize, dst: usize, k: i64) {
+        for j in 0..self.cols {
+            let value = self[(src, j)];
+            self[(dst, j)] += k * value;
+        }
+    }
+
+    // col dst += k * col src
+    fn add_col(&mut self, src: usize, dst: usize, k: i64) {
+        for i in 0..self.rows {
+            let value = self[(i, src)];
+            self[(i, dst)] += k * value;
+        }
+    }
+}
+
+impl<const N: usize, const M: usize, T> From<Matrix<N, M, T>> for DynMatrix<T> {
+    fn from(value: Matrix<N, M, T>) -> Self {
+        DynMatrix {
+            rows: N,
+            cols: M,
+            data: value.into_data(),
+        }
+    }
+}
+
+impl<const N: usize, const M: usize, T> TryFrom<DynMatrix<T>> for Matrix<N, M, T> {
+    type Error = PolyError;
+
+    fn try_from(value: DynMatrix<T>) -> Result<Self, Self::Error> {
+        if value.shape() != (N, M) {
+            return Err(PolyError::ShapeMismatch {
+                lhs: value.shape(),
+                rhs: (N, M),
+            });
+        }
+        Ok(Matrix::from_data(value.data))
+    }
+}
+
+impl<T> Index<(usize, usize)> for DynMatrix<T> {
+    type Output = T;
+
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        if index.0 >= self.rows || index.1 >= self.cols {
+            panic!(
+                "try to get [{}, {}] from matrix<{}, {}>",
+                index.0, index.1, self.rows, self.cols
+            )
+        }
+        &self.data[index.0 * self.cols + index.1]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for DynMatrix<T> {
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
+        if index.0 >= self.rows || index.1 >= self.cols {
+            panic!(
+                "try to set [{}, {}] to matrix<{}, {}>",
+                index.0, index.1, self.rows, self.cols
+            )
+        }
+        &mut self.data[index.0 * self.cols + index.1]
+    }
+}
+
+impl<T: AddAssign<T>> AddAssign for DynMatrix<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        if let Err(err) = self.check_same_shape(&rhs) {
+            panic!("Can't add matrices: {}", err);
+        }
+        for (lhs, rhs) in self.data.iter_mut().zip(rhs.data) {
+            *lhs += rhs;
+        }
+    }
+}
+
+impl<T: AddAssign<T>> Add for DynMatrix<T> {
+    type Output = DynMatrix<T>;
+
+    fn add(mut self, rhs: Self) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for DynMatrix<T> {
+    type Output = DynMatrix<T>;
+
+    fn neg(self) -> Self::Output {
+        DynMatrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self.data.into_iter().map(|x| -x).collect(),
+        }
+    }
+}
+
+impl<T: SubAssign<T>> SubAssign for DynMatrix<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        if let Err(err) = self.check_same_shape(&rhs) {
+            panic!("Can't subtract matrices: {}", err);
+        }
+        for (lhs, rhs) in self.data.iter_mut().zip(rhs.data) {
+            *lhs -= rhs;
+        }
+    }
+}
+
+impl<T: SubAssign<T>> Sub for DynMatrix<T> {
+    type Output = DynMatrix<T>;
+
+    fn sub(mut self, rhs: Self) -> Self::Output {
+        self -= rhs;
+        self
+    }
+}
+
+impl<T> Mul for DynMatrix<T>
+where
+    T: Clone + Zero,
+    T: Mul<T, Output = T>,
+    T: AddAssign<T>,
+{
+    type Output = DynMatrix<T>;
+
+    fn mul(self, rhs: DynMatrix<T>) -> Self::Output {
+        match self.checked_mul(&rhs) {
+            Ok(ans) => ans,
+            Err(err) => panic!("Can't multiply matrices: {}", err),
+        }
+    }
+}
+
+impl<T> MulAssign for DynMatrix<T>
+where
+    T: Clone + Zero,
+    T: Mul<T, Output = T>,
+    T: AddAssign<T>,
+{
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.clone() * rhs;
+    }
+}
+
+impl<T> MulAssign<T> for DynMatrix<T>
+where
+    T: MulAssign<T> + Clone,
+{
+    fn mul_assign(&mut self, rhs: T) {
+        for elem in self.data.iter_mut() {
+            *elem *= rhs.clone();
+        }
+    }
+}
+
+impl<T> Mul<T> for DynMatrix<T>
+where
+    T: MulAssign<T> + Clone,
+{
+    type Output = DynMatrix<T>;
+
+    fn mul(mut self, rhs: T) -> Self::Output {
+        self *= rhs;
+        self
+    }
+}
+
+impl<T> DivAssign<T> for DynMatrix<T>
+where
+    T: DivAssign<T> + Clone,
+{
+    fn div_assign(&mut self, rhs: T) {
+        for elem in self.data.iter_mut() {
+            *elem /= rhs.clone();
+        }
+    }
+}
+
+impl<T> Div<T> for DynMatrix<T>
+where
+    T: DivAssign<T> + Clone,
+{
+    type Output = DynMatrix<T>;
+
+    fn div(mut self, rhs: T) -> Self::Output {
+        self /= rhs;
+        self
+    }
+}
+
+// scalar * matrix of same type
+macro_rules! impl_left_scalar_mul {
+    ($($t:ty),*) => {
+        $(
+            impl Mul<DynMatrix<$t>> for $t {
+                type Output = DynMatrix<$t>;
+
+                fn mul(self, rhs: DynMatrix<$t>) -> Self::Output {
+                    rhs.scale(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_left_scalar_mul!(i8, i16, i64, i128, isize);
+impl_left_scalar_mul!(u8, u16, u32, u64, u128, usize);
+impl_left_scalar_mul!(f32, f64);
+
+// i32 scales any elems, so polynomials with i32 coefs accept any matrix points
+impl<T> Mul<DynMatrix<T>> for i32
+where
+    T: MulAssign<i32>,
+{
+    type Output = DynMatrix<T>;
+
+    fn mul(self, rhs: DynMatrix<T>) -> Self::Output {
+        rhs.scale(self)
+    }
+}
+
+impl<const P: u32> Mul<DynMatrix<Zn<P>>> for Zn<P> {
+    type Output = DynMatrix<Zn<P>>;
+
+    fn mul(self, rhs: DynMatrix<Zn<P>>) -> Self::Output {
+        rhs.scale(self)
+    }
+}
+
+#[cfg(test)]
+mod dyn_matrix_test {
+    use std::panic;
+
+    use super::DynMatrix;
+    use crate::custom_types::Matrix;
+    use crate::PolyError;
+
+    #[test]
+    fn test_create() {
+        let m = DynMatrix::eye(3, 2, -2);
+        assert_eq!(m.data, vec![-2, 0, 0, -2, 0, 0]);
+        assert_eq!(m.shape(), (3, 2));
+        assert_eq!(DynMatrix::<i32>::identity(2).data, vec![1, 0, 0, 1]);
+
+        assert_eq!(
+            DynMatrix::try_from_data(1, 2, vec![1]),
+            Err(PolyError::DimensionMismatch { rows: 1, cols: 2, len: 1 })
+        );
+        panic::catch_unwind(|| {
+            let _ = DynMatrix::from_data(2, 2, vec![1, 2]);
+        })
+        .expect_err("incorrect size of m");
+
+        let mut m = DynMatrix::full(1, 2, 0);
+        assert!(m.try_set_data(vec![1, 2, 3]).is_err());
+        m.set_data(vec![1, 2]);
+        assert_eq!(m.try_get(0, 1), Ok(&2));
+        assert!(m.try_get(1, 0).is_err());
+        assert_eq!(m.get(0, 0), Some(&1));
+        assert_eq!(m.get(0, 2), None);
+        *m.get_mut(0, 0).unwrap() = 3;
+        assert!(m.get_mut(1, 0).is_none());
+        assert_eq!(m.data, vec![3, 2]);
+    }
+
+    #[test]
+    fn test_smith_normal_form() {
+        let check = |a: DynMatrix<i64>, factors: Vec<i64>| {
+            let (u, s, v) = a.smith_normal_form();
+            assert_eq!(u.clone() * a.clone() * v.clone(), s);
+            assert_eq!(u.determinant().abs(), 1);
+            assert_eq!(v.determinant().abs(), 1);
+            let mut expect = DynMatrix::full(a.rows(), a.cols(), 0);
+            for (i, d) in factors.into_iter().enumerate() {
+                expect[(i, i)] = d;
+            }
+            assert_eq!(s, expect);
+        };
+        check(DynMatrix::from_data(2, 2, vec![2, 0, 0, 3]), vec![1, 6]);
+        check(DynMatrix::from_data(2, 2, vec![0, 0, 0, 0]), vec![]);
+        check(DynMatrix::from_data(3, 3, vec![2, 4, 4, -6, 6, 12, 10, -4, -16]), vec![2, 6, 12]);
+        check(DynMatrix::from_data(3, 2, vec![4, 6, 6, 9, -2, -3]), vec![1]);
+        check(DynMatrix::from_data(1, 3, vec![-4, 6, 10]), vec![2]);
+        check(DynMatrix::from_data(0, 2, vec![]), vec![]);
+    }
+
+    #[test]
+    fn test_ops() {
+        let a = DynMatrix::<i32>::from_data(2, 2, vec![1, 2, 3, 4]);
+        let b = DynMatrix::from_data(2, 2, vec![3, -5, 2, 0]);
+        assert_eq!((a.clone() + b.clone()).data, vec![4, -3, 5, 4]);
+        assert_eq!((a.clone() - b.clone()).data, vec![-2, 7, 1, 4]);
+        assert_eq!((a.clone() * b.clone()).data, vec![7, -5, 17, -15]);
+        assert_eq!((-a.clone()).data, vec![-1, -2, -3, -4]);
+        assert_eq!((a.clone() * -3).data, vec![-3, -6, -9, -12]);
+        assert_eq!((2 * a.clone()).data, vec![2, 4, 6, 8]);
+        assert_eq!((a.clone() / 2).data, vec![0, 1, 1, 2]);
+        assert_eq!((2.0 * DynMatrix::full(1, 1, 1.5f64)).data, vec![3.0]);
+        assert_eq!(a.pow(3), a.clone() * a.clone() * a.clone());
+
+        let c = DynMatrix::from_data(2, 3, vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!((a.clone() * c.clone()).shape(), (2, 3));
+        assert_eq!(
+            c.checked_mul(&a),
+            Err(PolyError::ShapeMismatch { lhs: (2, 3), rhs: (2, 2) })
+        );
+        assert!(a.clone().checked_add(c.clone()).is_err());
+        assert!(a.clone().checked_sub(c.clone()).is_err());
+        panic::catch_unwind(|| {
+            let _ = c.clone() + a.clone();
+        })
+        .expect_err("different sizes");
+    }
+
+    #[test]
+    fn test_transpose_determinant() {
+        let c = DynMatrix::from_data(2, 3, vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(c.clone().transpose().data, vec![1, 4, 2, 5, 3, 6]);
+        assert_eq!(c.clone().transpose().shape(), (3, 2));
+
+        let m = DynMatrix::from_data(3, 3, vec![2, -1, 0, 1, 3, 4, 0, 5, -2]);
+        assert_eq!(m.determinant(), -54);
+        assert_eq!(DynMatrix::<i32>::from_data(0, 0, vec![]).determinant(), 1);
+    }
+
+    #[test]
+    fn test_convert() {
+        let m = Matrix::<2, 3, i32>::from_data(vec![1, 2, 3, 4, 5, 6]);
+        let d = DynMatrix::from(m.clone());
+        assert_eq!(d.shape(), (2, 3));
+        assert_eq!(Matrix::<2, 3, i32>::try_from(d.clone()), Ok(m));
+        assert_eq!(
+            Matrix::<3, 2, i32>::try_from(d),
+            Err(PolyError::ShapeMismatch { lhs: (2, 3), rhs: (3, 2) })
+        );
+    }
+}
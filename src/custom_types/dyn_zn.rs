@@ -0,0 +1,432 @@
+//! Defines type `DynZn` is remains of n, where n is known only at runtime.
+
+use std::fmt::Display;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::{One, PolyError, Zero};
+
+/// Struct, that hold remain of n and n itself.
+///
+/// `Zero::zero()` and `One::one()` can't know modulus, so they
+/// return values with modulus 0. Such values are plain integers, that take
+/// modulus of other operand in arithmetic, so polynomial substitution
+/// and generic code like `T::zero() - T::one()` work as usual.
+///
+/// Example:
+/// ```
+/// # use polylib::custom_types::DynZn;
+/// # use polylib::polynom::Polynomial;
+/// # use polylib::{One, Zero};
+/// let n = 7; // may be read from input
+/// let p = Polynomial::<DynZn>::from_coefs(vec![DynZn::new(1, n), DynZn::new(1, n)]);
+/// let v: DynZn = p.substitude(DynZn::new(6, n));
+/// assert_eq!(v.value(), 0);
+/// assert_eq!(v.modulus(), 7);
+///
+/// let minus_one = DynZn::zero() - DynZn::one(); // no modulus yet
+/// assert_eq!((minus_one * DynZn::new(1, n)).value(), 6);
+/// ```
+///
+/// `==` is exact: value without modulus equals only the same value
+/// without modulus, use `congruent` to compare it with remains.
+/// Otherwise `1 == 1 (mod 5) == 6` would break transitivity.
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub struct DynZn {
+    repr: Repr,
+}
+
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+enum Repr {
+    // integer without modulus, reduced only when modulus joins
+    Wild(i64),
+    // value < modulus, modulus > 0
+    Mod { value: u32, modulus: u32 },
+}
+
+impl DynZn {
+    /// Creates DynZn. If value is equal to or more than modulus, takes only remain.
+    /// Panics for zero modulus.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::DynZn;
+    /// let val = DynZn::new(7, 5); // 7 > 5, so val is 2
+    /// assert_eq!(val.value(), 2);
+    /// ```
+    pub fn new(value: u32, modulus: u32) -> DynZn {
+        if modulus == 0 {
+            panic!("Can't create DynZn with modulus 0");
+        }
+        DynZn {
+            repr: Repr::Mod {
+                value: value % modulus,
+                modulus,
+            },
+        }
+    }
+
+    /// Same as `new`, but returns error for zero modulus instead of panic.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::DynZn;
+    /// # use polylib::PolyError;
+    /// assert_eq!(DynZn::try_new(7, 5).map(|v| v.value()), Ok(2));
+    /// assert_eq!(DynZn::try_new(7, 0), Err(PolyError::InvalidModulus(0)));
+    /// ```
+    pub fn try_new(value: u32, modulus: u32) -> Result<DynZn, PolyError> {
+        if modulus == 0 {
+            return Err(PolyError::InvalidModulus(modulus));
+        }
+        Ok(Self::new(value, modulus))
+    }
+
+    /// Creates DynZn from signed value. Negative values are wrapped into [0, modulus).
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::DynZn;
+    /// assert_eq!(DynZn::from_i64(-7, 5).value(), 3);
+    /// ```
+    pub fn from_i64(value: i64, modulus: u32) -> DynZn {
+        if modulus == 0 {
+            panic!("Can't create DynZn with modulus 0");
+        }
+        DynZn {
+            repr: Repr::Mod {
+                value: value.rem_euclid(modulus as i64) as u32,
+                modulus,
+            },
+        }
+    }
+
+    /// Returns holding value. Value without modulus (see `Zero` and `One`)
+    /// is an integer, that is returned modulo 2^32.
+    pub fn value(&self) -> u32 {
+        match self.repr {
+            Repr::Wild(value) => value as u32,
+            Repr::Mod { value, .. } => value,
+        }
+    }
+
+    /// Returns modulus. It is 0 for values, made by `Zero` and `One`.
+    pub fn modulus(&self) -> u32 {
+        match self.repr {
+            Repr::Wild(_) => 0,
+            Repr::Mod { modulus, .. } => modulus,
+        }
+    }
+
+    /// Checks, if values are equal as remains: integer without modulus
+    /// is taken by modulus of other value. Values with different moduli
+    /// are never congruent. Unlike `==`, it is not transitive.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::DynZn;
+    /// # use polylib::One;
+    /// let mut six = DynZn::one(); // no modulus yet
+    /// six *= 6;
+    /// assert!(six.congruent(&DynZn::new(1, 5)));
+    /// assert_ne!(six, DynZn::new(1, 5));
+    /// ```
+    pub fn congruent(&self, other: &DynZn) -> bool {
+        match (self.repr, other.repr) {
+            (Repr::Wild(a), Repr::Mod { value, modulus })
+            | (Repr::Mod { value, modulus }, Repr::Wild(a)) => {
+                a.rem_euclid(modulus as i64) == value as i64
+            }
+            _ => self == other,
+        }
+    }
+
+    /// Returns self in power exp. Uses square-and-multiply.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::DynZn;
+    /// assert_eq!(DynZn::new(3, 7).pow(6).value(), 1);
+    /// ```
+    pub fn pow(self, mut exp: u64) -> DynZn {
+        let mut ans = Self::one();
+        let mut base = self;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                ans *= base;
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base *= base;
+            }
+        }
+        ans
+    }
+
+    fn wild(value: i64) -> DynZn {
+        DynZn {
+            repr: Repr::Wild(value),
+        }
+    }
+
+    // applies op to integers without modulus, or to values modulo common modulus,
+    // integer without modulus takes modulus of other operand
+    fn combine(
+        self,
+        rhs: DynZn,
+        wild: fn(i64, i64) -> Option<i64>,
+        modular: fn(u64, u64, u64) -> u64,
+    ) -> DynZn {
+        let (a, b, modulus) = match (self.repr, rhs.repr) {
+            (Repr::Wild(a), Repr::Wild(b)) => match wild(a, b) {
+                Some(value) => return Self::wild(value),
+                None => panic!("DynZn without modulus overflows i64"),
+            },
+            (Repr::Wild(a), Repr::Mod { value, modulus }) => {
+                (Self::from_i64(a, modulus).value(), value, modulus)
+            }
+            (Repr::Mod { value, modulus }, Repr::Wild(b)) => {
+                (value, Self::from_i64(b, modulus).value(), modulus)
+            }
+            (Repr::Mod { value: a, modulus }, Repr::Mod { value: b, modulus: m }) => {
+                if modulus != m {
+                    panic!("Can't use DynZn with modulus {} and {} together", modulus, m);
+                }
+                (a, b, modulus)
+            }
+        };
+        let m = modulus as u64;
+        DynZn {
+            repr: Repr::Mod {
+                value: (modular(a as u64, b as u64, m) % m) as u32,
+                modulus,
+            },
+        }
+    }
+}
+
+impl Default for DynZn {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+/// Integer without modulus equals value with modulus, if it has the same remain.
+/// Integer without modulus is zero only if it is zero in every modulus,
+/// that is only for zero itself.
+impl Zero for DynZn {
+    fn zero() -> Self {
+        Self::wild(0)
+    }
+
+    fn is_zero(&self) -> bool {
+        match self.repr {
+            Repr::Wild(value) => value == 0,
+            Repr::Mod { value, .. } => value == 0,
+        }
+    }
+}
+
+impl One for DynZn {
+    fn one() -> Self {
+        Self::wild(1)
+    }
+
+    fn is_one(&self) -> bool {
+        match self.repr {
+            Repr::Wild(value) => value == 1,
+            Repr::Mod { value, modulus } => value == 1 % modulus,
+        }
+    }
+}
+
+impl Add for DynZn {
+    type Output = DynZn;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.combine(rhs, i64::checked_add, |a, b, _| a + b)
+    }
+}
+
+impl AddAssign for DynZn {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Neg for DynZn {
+    type Output = DynZn;
+
+    fn neg(self) -> Self::Output {
+        Self::zero() - self
+    }
+}
+
+impl Sub for DynZn {
+    type Output = DynZn;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.combine(rhs, i64::checked_sub, |a, b, m| a + m - b)
+    }
+}
+
+impl SubAssign for DynZn {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul for DynZn {
+    type Output = DynZn;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.combine(rhs, i64::checked_mul, |a, b, _| a * b)
+    }
+}
+
+impl MulAssign for DynZn {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl MulAssign<i32> for DynZn {
+    fn mul_assign(&mut self, rhs: i32) {
+        *self = *self * Self::wild(rhs as i64);
+    }
+}
+
+impl Display for DynZn {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.repr {
+            Repr::Wild(value) => write!(f, "<Z0 {}>", value),
+            Repr::Mod { value, modulus } => write!(f, "<Z{} {}>", modulus, value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod dyn_zn_tests {
+    use super::DynZn;
+    use crate::custom_types::Zn;
+    use crate::polynom::Polynomial;
+    use crate::{One, PolyError, Zero};
+
+    #[test]
+    fn test_create() {
+        assert_eq!(DynZn::new(7, 5).value(), 2);
+        assert_eq!(DynZn::new(7, 5).modulus(), 5);
+        assert_eq!(DynZn::from_i64(-1, 10).value(), 9);
+        assert_eq!(DynZn::try_new(1, 0), Err(PolyError::InvalidModulus(0)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_zero_modulus() {
+        DynZn::new(1, 0);
+    }
+
+    #[test]
+    fn test_same_as_zn() {
+        type Z = Zn<100>;
+        for a in [0u32, 1, 32, 57, 99] {
+            for b in [0u32, 1, 13, 99] {
+                let (da, db) = (DynZn::new(a, 100), DynZn::new(b, 100));
+                let (za, zb) = (Z::new(a), Z::new(b));
+                assert_eq!((da + db).value(), (za + zb).value());
+                assert_eq!((da - db).value(), (za - zb).value());
+                assert_eq!((da * db).value(), (za * zb).value());
+                let mut d = da;
+                d *= -(b as i32);
+                let mut z = za;
+                z *= -(b as i32);
+                assert_eq!(d.value(), z.value());
+            }
+        }
+    }
+
+    #[test]
+    fn test_wildcard() {
+        let a = DynZn::new(3, 5);
+        assert_eq!(DynZn::zero() + a, a);
+        assert_eq!((DynZn::one() * a).modulus(), 5);
+        assert_eq!((DynZn::zero() - a).value(), 2);
+        assert!(DynZn::one().congruent(&DynZn::new(1, 5)));
+        assert_ne!(DynZn::one(), DynZn::new(1, 5));
+        assert!(DynZn::new(1, 5).is_one());
+        assert!(DynZn::new(0, 5).is_zero());
+    }
+
+    #[test]
+    fn test_wildcard_intermediates() {
+        let seven = DynZn::new(0, 7);
+        // negative integer without modulus
+        let minus_one = DynZn::zero() - DynZn::one();
+        assert_eq!(minus_one.modulus(), 0);
+        assert_eq!((minus_one + seven).value(), 6);
+        assert_eq!((minus_one * DynZn::new(1, 7)).value(), 6);
+        assert_eq!(-DynZn::one(), minus_one);
+        assert_eq!((-DynZn::new(2, 7)).value(), 5);
+        let mut a = DynZn::one();
+        a *= -1;
+        assert_eq!(a, minus_one);
+        assert_eq!((a + DynZn::new(3, 7)).value(), 2);
+
+        // large integer without modulus is not truncated
+        let mut big = DynZn::one();
+        for _ in 0..40 {
+            big = big + big;
+        }
+        assert_eq!(big * DynZn::new(1, 1_000_003), DynZn::from_i64(1 << 40, 1_000_003));
+        assert!(big.congruent(&DynZn::from_i64(1 << 40, 1_000_003)));
+        assert_eq!(DynZn::new(1, 101) * big * big, DynZn::new(2, 101).pow(80));
+
+        // integer without modulus is zero only if it is zero
+        let two = DynZn::one() + DynZn::one();
+        assert!(two.congruent(&DynZn::new(0, 2)));
+        assert!(!two.is_zero());
+        assert!(DynZn::new(0, 2).is_zero());
+        assert!(DynZn::new(1, 3).is_one());
+        assert!(DynZn::new(0, 1).is_one());
+        assert!(!minus_one.is_one() && !minus_one.is_zero());
+
+        // == is transitive, congruence is not
+        let (one, six) = (DynZn::one(), DynZn::from_i64(6, 5));
+        let wild_six = two + two + two;
+        assert!(one.congruent(&six) && six.congruent(&wild_six));
+        assert!(!one.congruent(&wild_six));
+        assert_ne!(one, six);
+        assert_ne!(six, wild_six);
+    }
+
+    #[test]
+    fn test_polynomial_sub() {
+        let p = Polynomial::<DynZn>::from_coefs(vec![DynZn::new(1, 7), DynZn::new(3, 7)]);
+        let q = Polynomial::<DynZn>::from_coefs(vec![DynZn::new(2, 7), DynZn::new(3, 7)]);
+        let d = (p - q).reduce();
+        assert_eq!(d.len(), 1);
+        assert_eq!(d.coef(0), DynZn::new(6, 7));
+    }
+
+    #[test]
+    fn test_large_modulus() {
+        let a = DynZn::new(4_294_967_290, 4_294_967_291);
+        assert_eq!((a * a).value(), 1);
+        assert_eq!((a + a).value(), 4_294_967_289);
+        assert_eq!(DynZn::new(123_456, 4_294_967_291).pow(4_294_967_290).value(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "overflows")]
+    fn test_wildcard_overflow() {
+        let mut big = DynZn::one();
+        big *= -(1 << 30);
+        big *= 1 << 30;
+        let _ = big * big;
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_different_modulus() {
+        let _ = DynZn::new(1, 5) + DynZn::new(1, 7);
+    }
+}
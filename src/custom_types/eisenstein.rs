@@ -0,0 +1,327 @@
+//! Defines type `EisensteinInt` - element of Z[ω], where ω is primitive cube root of unity.
+
+use std::fmt::Display;
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
+
+use crate::{One, Zero};
+
+/// Eisenstein integer a + b * ω, where ω^2 + ω + 1 = 0.
+///
+/// Z[ω] is Euclidean ring: `/` and `%` give quotient and remainder,
+/// such that norm of remainder is less than norm of divisor.
+///
+/// Example:
+/// ```
+/// # use polylib::custom_types::EisensteinInt;
+/// let w = EisensteinInt::omega();
+/// assert_eq!(w * w * w, EisensteinInt::new(1, 0));
+/// // 3 = -ω^2 (1 - ω)^2
+/// let a = EisensteinInt::new(1, -1);
+/// assert_eq!(-(w * w) * a * a, EisensteinInt::new(3, 0));
+/// ```
+#[derive(PartialEq, Eq, Hash, Copy, Clone, Debug, Default)]
+pub struct EisensteinInt {
+    a: i64,
+    b: i64,
+}
+
+// rounds num / den to nearest integer, den > 0
+fn div_round(num: i128, den: i128) -> i128 {
+    (2 * num + den).div_euclid(2 * den)
+}
+
+impl EisensteinInt {
+    /// Creates a + b * ω.
+    pub fn new(a: i64, b: i64) -> EisensteinInt {
+        EisensteinInt { a, b }
+    }
+
+    /// Returns ω = (-1 + sqrt(-3)) / 2.
+    pub fn omega() -> EisensteinInt {
+        EisensteinInt { a: 0, b: 1 }
+    }
+
+    /// Returns coef a of a + b * ω.
+    pub fn a(&self) -> i64 {
+        self.a
+    }
+
+    /// Returns coef b of a + b * ω.
+    pub fn b(&self) -> i64 {
+        self.b
+    }
+
+    /// Returns complex conjugate a + b * ω^2 = (a - b) - b * ω.
+    pub fn conj(&self) -> EisensteinInt {
+        EisensteinInt::new(self.a - self.b, -self.b)
+    }
+
+    /// Returns norm a^2 - ab + b^2. It is multiplicative.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::EisensteinInt;
+    /// assert_eq!(EisensteinInt::new(2, -1).norm(), 7);
+    /// ```
+    pub fn norm(&self) -> i64 {
+        self.a * self.a - self.a * self.b + self.b * self.b
+    }
+
+    /// Checks if self is one of six units: ±1, ±ω, ±ω^2.
+    pub fn is_unit(&self) -> bool {
+        self.norm() == 1
+    }
+
+    /// Returns quotient and remainder of Euclidean division.
+    /// Both coefs of quotient are rounded to nearest integers,
+    /// so norm of remainder is at most 3/4 of norm of rhs.
+    ///
+    /// Panics if rhs is zero.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::EisensteinInt;
+    /// let a = EisensteinInt::new(7, 3);
+    /// let b = EisensteinInt::new(2, -1);
+    /// let (q, r) = a.div_rem(&b);
+    /// assert_eq!(q * b + r, a);
+    /// assert!(r.norm() < b.norm());
+    /// ```
+    pub fn div_rem(&self, rhs: &EisensteinInt) -> (EisensteinInt, EisensteinInt) {
+        if rhs.is_zero() {
+            panic!("Can't divide eisenstein integer by zero");
+        }
+        // self / rhs = self * conj(rhs) / norm(rhs), product may not fit into i64
+        let (a, b) = (self.a as i128, self.b as i128);
+        let (c, d) = (rhs.a as i128 - rhs.b as i128, -(rhs.b as i128));
+        let den = c * c - c * d + d * d;
+        let q = EisensteinInt::new(
+            div_round(a * c - b * d, den) as i64,
+            div_round(a * d + b * c - b * d, den) as i64,
+        );
+        (q, *self - q * *rhs)
+    }
+
+    /// Returns greatest common divisor, normalized by unit factor,
+    /// so that a > b >= 0, or zero.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::EisensteinInt;
+    /// // 7 = (3 + ω)(2 - ω)
+    /// let p = EisensteinInt::new(3, 1);
+    /// let g = EisensteinInt::gcd(EisensteinInt::new(7, 0), p * EisensteinInt::new(5, 0));
+    /// assert_eq!(g, p);
+    /// ```
+    pub fn gcd(a: EisensteinInt, b: EisensteinInt) -> EisensteinInt {
+        let (mut a, mut b) = (a, b);
+        while !b.is_zero() {
+            let r = a.div_rem(&b).1;
+            a = b;
+            b = r;
+        }
+        a.normalized()
+    }
+
+    // multiplies by unit, so result lies in sector [0, 60) degrees
+    fn normalized(self) -> EisensteinInt {
+        // 1 + ω = -ω^2 turns by 60 degrees
+        let turn = EisensteinInt::new(1, 1);
+        let mut value = self;
+        for _ in 0..6 {
+            if value.a > value.b && value.b >= 0 {
+                return value;
+            }
+            value *= turn;
+        }
+        value
+    }
+}
+
+impl From<i64> for EisensteinInt {
+    fn from(value: i64) -> Self {
+        EisensteinInt::new(value, 0)
+    }
+}
+
+impl Zero for EisensteinInt {
+    fn zero() -> Self {
+        EisensteinInt::new(0, 0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.a == 0 && self.b == 0
+    }
+}
+
+impl One for EisensteinInt {
+    fn one() -> Self {
+        EisensteinInt::new(1, 0)
+    }
+
+    fn is_one(&self) -> bool {
+        self.a == 1 && self.b == 0
+    }
+}
+
+impl Add for EisensteinInt {
+    type Output = EisensteinInt;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        EisensteinInt::new(self.a + rhs.a, self.b + rhs.b)
+    }
+}
+
+impl Sub for EisensteinInt {
+    type Output = EisensteinInt;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        EisensteinInt::new(self.a - rhs.a, self.b - rhs.b)
+    }
+}
+
+impl Neg for EisensteinInt {
+    type Output = EisensteinInt;
+
+    fn neg(self) -> Self::Output {
+        EisensteinInt::new(-self.a, -self.b)
+    }
+}
+
+impl Mul for EisensteinInt {
+    type Output = EisensteinInt;
+
+    // ω^2 = -1 - ω
+    fn mul(self, rhs: Self) -> Self::Output {
+        let bd = self.b * rhs.b;
+        EisensteinInt::new(self.a * rhs.a - bd, self.a * rhs.b + self.b * rhs.a - bd)
+    }
+}
+
+impl Div for EisensteinInt {
+    type Output = EisensteinInt;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        self.div_rem(&rhs).0
+    }
+}
+
+impl Rem for EisensteinInt {
+    type Output = EisensteinInt;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        self.div_rem(&rhs).1
+    }
+}
+
+impl AddAssign for EisensteinInt {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for EisensteinInt {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl MulAssign for EisensteinInt {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl Display for EisensteinInt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.a, self.b) {
+            (a, 0) => write!(f, "{}", a),
+            (0, 1) => write!(f, "ω"),
+            (0, -1) => write!(f, "-ω"),
+            (0, b) => write!(f, "{}ω", b),
+            (a, 1) => write!(f, "({}+ω)", a),
+            (a, -1) => write!(f, "({}-ω)", a),
+            (a, b) if b < 0 => write!(f, "({}{}ω)", a, b),
+            (a, b) => write!(f, "({}+{}ω)", a, b),
+        }
+    }
+}
+
+#[cfg(test)]
+mod eisenstein_tests {
+    use super::EisensteinInt;
+    use crate::{One, Zero};
+
+    type E = EisensteinInt;
+
+    #[test]
+    fn test_ops() {
+        let w = E::omega();
+        assert_eq!(w * w + w + E::one(), E::zero());
+        let a = E::new(2, 3);
+        let b = E::new(-1, 4);
+        assert_eq!(a + b, E::new(1, 7));
+        assert_eq!(a - b, E::new(3, -1));
+        // (2 + 3ω)(-1 + 4ω) = -2 + 5ω + 12ω^2 = -14 - 7ω
+        assert_eq!(a * b, E::new(-14, -7));
+        assert_eq!(a * a.conj(), E::from(a.norm()));
+        assert_eq!((a * b).norm(), a.norm() * b.norm());
+        let units = [E::one(), -E::one(), w, -w, w * w, -(w * w)];
+        assert!(units.iter().all(|u| u.is_unit()));
+    }
+
+    #[test]
+    fn test_div_rem() {
+        let values = [E::new(7, 3), E::new(-11, 5), E::new(0, -9), E::new(13, 13)];
+        let divisors = [E::new(2, 1), E::new(-3, 2), E::new(0, 4), E::new(5, 0)];
+        for a in values {
+            for b in divisors {
+                let (q, r) = a.div_rem(&b);
+                assert_eq!(q * b + r, a);
+                assert!(4 * r.norm() <= 3 * b.norm());
+            }
+        }
+        assert_eq!(E::new(-14, -7) / E::new(2, 3), E::new(-1, 4));
+        assert!((E::new(-14, -7) % E::new(-1, 4)).is_zero());
+
+        // a * conj(b) overflows i64
+        let a = E::new(4_000_000_000, -4_000_000_000);
+        let b = E::new(3_000_000_000, 1);
+        let (q, r) = a.div_rem(&b);
+        assert_eq!(q * b + r, a);
+        assert!(r.norm() < b.norm());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_div_zero() {
+        let _ = E::new(1, 1) / E::zero();
+    }
+
+    #[test]
+    fn test_gcd() {
+        // 7 = (3 + ω)(2 - ω), 13 = (4 + ω)(3 - ω)
+        let p = E::new(3, 1);
+        let a = p * E::new(4, 1);
+        let b = p * E::new(2, -1);
+        assert_eq!(E::gcd(a, b), p);
+        assert_eq!(E::gcd(-a * E::omega(), b), p);
+        assert_eq!(E::gcd(E::new(7, 0), E::new(4, 1)), E::one());
+        assert_eq!(E::gcd(E::zero(), E::new(0, -4)), E::new(4, 0));
+        // every associate normalizes to the same value
+        let mut u = E::one();
+        for _ in 0..6 {
+            assert_eq!(E::gcd(p * u, E::zero()), p);
+            u *= E::new(1, 1);
+        }
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(E::new(3, 0).to_string(), "3");
+        assert_eq!(E::new(0, -1).to_string(), "-ω");
+        assert_eq!(E::new(0, 2).to_string(), "2ω");
+        assert_eq!(E::new(1, -2).to_string(), "(1-2ω)");
+        assert_eq!(E::new(-1, 1).to_string(), "(-1+ω)");
+    }
+}
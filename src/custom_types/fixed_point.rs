@@ -0,0 +1,287 @@
+//! Defines type `Fixed` - signed fixed-point number.
+
+use std::fmt::Display;
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::{One, Zero};
+
+/// Signed fixed-point number with `FRAC_BITS` fractional bits, backed by i64.
+/// Value is bits / 2^FRAC_BITS.
+///
+/// `+`, `-` and unary `-` overflow like i64 does (panic in debug build, wrap in release).
+/// `*` and `/` panic on overflow in every build.
+/// Use `wrapping_*`, `saturating_*` and `checked_*` methods for explicit behaviour.
+/// Products and quotients are rounded to nearest, ties away from zero.
+///
+/// Example:
+/// ```
+/// # use polylib::custom_types::Fixed;
+/// type Q16 = Fixed<16>;
+/// let a = Q16::from_int(3);
+/// let b = Q16::from_f64(0.5);
+/// assert_eq!(a * b, Q16::from_f64(1.5));
+/// ```
+///
+/// `FRAC_BITS` must be less than 63:
+/// ```compile_fail
+/// # use polylib::custom_types::Fixed;
+/// let a = Fixed::<63>::from_int(1);
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone, Debug, Default)]
+pub struct Fixed<const FRAC_BITS: u32>(i64);
+
+impl<const FRAC_BITS: u32> Fixed<FRAC_BITS> {
+    const ONE: i64 = {
+        assert!(FRAC_BITS < 63, "Fixed needs at least one integer bit");
+        1 << FRAC_BITS
+    };
+
+    /// Creates number from raw bits.
+    pub const fn from_bits(bits: i64) -> Fixed<FRAC_BITS> {
+        Fixed(bits)
+    }
+
+    /// Returns raw bits.
+    pub const fn to_bits(self) -> i64 {
+        self.0
+    }
+
+    /// Creates number from integer. Panics on overflow in debug build.
+    pub fn from_int(value: i64) -> Fixed<FRAC_BITS> {
+        Fixed(value * Self::ONE)
+    }
+
+    /// Creates nearest number to float. Out of range values are saturated.
+    pub fn from_f64(value: f64) -> Fixed<FRAC_BITS> {
+        Fixed((value * Self::ONE as f64).round() as i64)
+    }
+
+    /// Returns value as float.
+    pub fn to_f64(self) -> f64 {
+        self.0 as f64 / Self::ONE as f64
+    }
+
+    /// Returns integer part, rounded toward negative infinity.
+    pub fn floor(self) -> i64 {
+        self.0 >> FRAC_BITS
+    }
+
+    // num / den, rounded to nearest, ties away from zero
+    fn div_round(num: i128, den: i128) -> i128 {
+        (num.abs() + den.abs() / 2) / den.abs() * (num.signum() * den.signum())
+    }
+
+    // product of bits in i128, rounded to nearest
+    fn mul_bits(lhs: i64, rhs: i64) -> i128 {
+        Self::div_round(lhs as i128 * rhs as i128, Self::ONE as i128)
+    }
+
+    /// Returns self + rhs, wrapping around at the bounds of i64 bits.
+    pub fn wrapping_add(self, rhs: Self) -> Fixed<FRAC_BITS> {
+        Fixed(self.0.wrapping_add(rhs.0))
+    }
+
+    /// Returns self - rhs, wrapping around at the bounds of i64 bits.
+    pub fn wrapping_sub(self, rhs: Self) -> Fixed<FRAC_BITS> {
+        Fixed(self.0.wrapping_sub(rhs.0))
+    }
+
+    /// Returns self * rhs, wrapping around at the bounds of i64 bits.
+    pub fn wrapping_mul(self, rhs: Self) -> Fixed<FRAC_BITS> {
+        Fixed(Self::mul_bits(self.0, rhs.0) as i64)
+    }
+
+    /// Returns self + rhs, saturating at the bounds.
+    pub fn saturating_add(self, rhs: Self) -> Fixed<FRAC_BITS> {
+        Fixed(self.0.saturating_add(rhs.0))
+    }
+
+    /// Returns self - rhs, saturating at the bounds.
+    pub fn saturating_sub(self, rhs: Self) -> Fixed<FRAC_BITS> {
+        Fixed(self.0.saturating_sub(rhs.0))
+    }
+
+    /// Returns self * rhs, saturating at the bounds.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::Fixed;
+    /// let big = Fixed::<32>::from_int(1 << 30);
+    /// assert_eq!(big.saturating_mul(big), Fixed::from_bits(i64::MAX));
+    /// ```
+    pub fn saturating_mul(self, rhs: Self) -> Fixed<FRAC_BITS> {
+        let prod = Self::mul_bits(self.0, rhs.0);
+        Fixed(prod.clamp(i64::MIN as i128, i64::MAX as i128) as i64)
+    }
+
+    /// Returns self * rhs, or `None` on overflow.
+    pub fn checked_mul(self, rhs: Self) -> Option<Fixed<FRAC_BITS>> {
+        i64::try_from(Self::mul_bits(self.0, rhs.0)).ok().map(Fixed)
+    }
+}
+
+impl<const FRAC_BITS: u32> From<i32> for Fixed<FRAC_BITS> {
+    fn from(value: i32) -> Self {
+        Self::from_int(value as i64)
+    }
+}
+
+impl<const FRAC_BITS: u32> Zero for Fixed<FRAC_BITS> {
+    fn zero() -> Self {
+        Fixed(0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl<const FRAC_BITS: u32> One for Fixed<FRAC_BITS> {
+    fn one() -> Self {
+        Fixed(Self::ONE)
+    }
+
+    fn is_one(&self) -> bool {
+        self.0 == Self::ONE
+    }
+}
+
+impl<const FRAC_BITS: u32> Add for Fixed<FRAC_BITS> {
+    type Output = Fixed<FRAC_BITS>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Fixed(self.0 + rhs.0)
+    }
+}
+
+impl<const FRAC_BITS: u32> Sub for Fixed<FRAC_BITS> {
+    type Output = Fixed<FRAC_BITS>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Fixed(self.0 - rhs.0)
+    }
+}
+
+impl<const FRAC_BITS: u32> Neg for Fixed<FRAC_BITS> {
+    type Output = Fixed<FRAC_BITS>;
+
+    fn neg(self) -> Self::Output {
+        Fixed(-self.0)
+    }
+}
+
+impl<const FRAC_BITS: u32> Mul for Fixed<FRAC_BITS> {
+    type Output = Fixed<FRAC_BITS>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        match self.checked_mul(rhs) {
+            Some(ans) => ans,
+            None => panic!("Fixed multiplication overflow"),
+        }
+    }
+}
+
+impl<const FRAC_BITS: u32> Div for Fixed<FRAC_BITS> {
+    type Output = Fixed<FRAC_BITS>;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        if rhs.0 == 0 {
+            panic!("Can't divide fixed-point number by zero");
+        }
+        let ans = Self::div_round((self.0 as i128) << FRAC_BITS, rhs.0 as i128);
+        match i64::try_from(ans) {
+            Ok(bits) => Fixed(bits),
+            Err(_) => panic!("Fixed division overflow"),
+        }
+    }
+}
+
+impl<const FRAC_BITS: u32> AddAssign for Fixed<FRAC_BITS> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const FRAC_BITS: u32> SubAssign for Fixed<FRAC_BITS> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const FRAC_BITS: u32> MulAssign for Fixed<FRAC_BITS> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<const FRAC_BITS: u32> Display for Fixed<FRAC_BITS> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_f64())
+    }
+}
+
+#[cfg(test)]
+mod fixed_point_tests {
+    use super::Fixed;
+    use crate::{One, Zero};
+
+    type Q8 = Fixed<8>;
+
+    #[test]
+    fn test_convert() {
+        assert_eq!(Q8::from_int(3).to_bits(), 768);
+        assert_eq!(Q8::from_f64(-1.5).to_f64(), -1.5);
+        assert_eq!(Q8::from_f64(-1.5).floor(), -2);
+        assert_eq!(Q8::from(2), Q8::from_int(2));
+        assert!(Q8::one().is_one());
+        assert!(Q8::zero().is_zero());
+        assert_eq!(Q8::from_f64(0.25).to_string(), "0.25");
+    }
+
+    #[test]
+    fn test_ops() {
+        let a = Q8::from_f64(2.5);
+        let b = Q8::from_f64(-0.75);
+        assert_eq!(a + b, Q8::from_f64(1.75));
+        assert_eq!(a - b, Q8::from_f64(3.25));
+        assert_eq!(a * b, Q8::from_f64(-1.875));
+        assert_eq!(a / Q8::from_int(2), Q8::from_f64(1.25));
+        assert_eq!(-a, Q8::from_f64(-2.5));
+        // 1 / 3 is rounded to 85 / 256
+        assert_eq!((Q8::one() / Q8::from_int(3)).to_bits(), 85);
+        assert_eq!((-Q8::one() / Q8::from_int(3)).to_bits(), -85);
+        // 2 / 3 is rounded to 171 / 256
+        assert_eq!((Q8::from_int(2) / Q8::from_int(3)).to_bits(), 171);
+        assert_eq!((Q8::from_int(2) / Q8::from_int(-3)).to_bits(), -171);
+        // ties are rounded away from zero in both products and quotients
+        let half = Q8::from_f64(0.5);
+        assert_eq!((Q8::from_bits(1) * half).to_bits(), 1);
+        assert_eq!((Q8::from_bits(-1) * half).to_bits(), -1);
+        assert_eq!((Q8::from_bits(1) / Q8::from_int(2)).to_bits(), 1);
+        assert_eq!((Q8::from_bits(-1) / Q8::from_int(2)).to_bits(), -1);
+    }
+
+    #[test]
+    fn test_overflow() {
+        let max = Q8::from_bits(i64::MAX);
+        assert_eq!(max.wrapping_add(Q8::from_bits(1)), Q8::from_bits(i64::MIN));
+        assert_eq!(max.saturating_add(Q8::one()), max);
+        assert_eq!(Q8::from_bits(i64::MIN).saturating_sub(Q8::one()), Q8::from_bits(i64::MIN));
+        assert_eq!(max.saturating_mul(Q8::from_int(2)), max);
+        assert_eq!((-max).saturating_mul(Q8::from_int(2)), Q8::from_bits(i64::MIN));
+        assert_eq!(max.checked_mul(Q8::from_int(2)), None);
+        assert_eq!(max.wrapping_mul(Q8::from_int(2)), Q8::from_bits(-2));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_mul_overflow() {
+        let _ = Q8::from_bits(i64::MAX) * Q8::from_int(2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_div_overflow() {
+        let _ = Q8::from_bits(i64::MAX) / Q8::from_bits(1);
+    }
+}
@@ -0,0 +1,294 @@
+//! Defines type `Fraction` - rational number.
+
+use std::cmp::Ordering;
+use std::fmt::Display;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
+
+use crate::{One, Zero};
+
+/// Rational number num / den. It is always reduced,
+/// and denominator is always positive.
+///
+/// T is integer type, like `i64` or `BigInt`.
+///
+/// Example:
+/// ```
+/// # use polylib::custom_types::Fraction;
+/// let a = Fraction::new(1, 6);
+/// let b = Fraction::new(1, 3);
+/// assert_eq!(a + b, Fraction::new(1, 2));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Fraction<T> {
+    num: T,
+    den: T,
+}
+
+fn gcd<T>(a: T, b: T) -> T
+where
+    T: Clone + Zero + PartialOrd + Rem<Output = T> + Neg<Output = T>,
+{
+    let (mut a, mut b) = (a, b);
+    while !b.is_zero() {
+        let r = a % b.clone();
+        a = b;
+        b = r;
+    }
+    if a < T::zero() {
+        -a
+    } else {
+        a
+    }
+}
+
+impl<T> Fraction<T>
+where
+    T: Clone + Zero + One + PartialOrd,
+    T: Mul<Output = T> + Div<Output = T> + Rem<Output = T> + Neg<Output = T>,
+{
+    /// Creates reduced fraction num / den. Panics if den is zero.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::Fraction;
+    /// let a = Fraction::new(4, -6);
+    /// assert_eq!((a.numer(), a.denom()), (&-2, &3));
+    /// ```
+    pub fn new(num: T, den: T) -> Fraction<T> {
+        if den.is_zero() {
+            panic!("Can't create fraction with zero denominator");
+        }
+        let g = gcd(num.clone(), den.clone());
+        let (mut num, mut den) = (num / g.clone(), den / g);
+        if den < T::zero() {
+            num = -num;
+            den = -den;
+        }
+        Fraction { num, den }
+    }
+
+    /// Returns 1 / self. Panics if self is zero.
+    pub fn recip(self) -> Fraction<T> {
+        Self::new(self.den, self.num)
+    }
+}
+
+impl<T> Fraction<T> {
+    /// Returns numerator.
+    pub fn numer(&self) -> &T {
+        &self.num
+    }
+
+    /// Returns denominator. It is always positive.
+    pub fn denom(&self) -> &T {
+        &self.den
+    }
+}
+
+impl<T: One> From<T> for Fraction<T> {
+    fn from(value: T) -> Self {
+        Fraction {
+            num: value,
+            den: T::one(),
+        }
+    }
+}
+
+impl<T: Zero + One> Zero for Fraction<T> {
+    fn zero() -> Self {
+        Fraction {
+            num: T::zero(),
+            den: T::one(),
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.num.is_zero()
+    }
+}
+
+impl<T: One> One for Fraction<T> {
+    fn one() -> Self {
+        Fraction {
+            num: T::one(),
+            den: T::one(),
+        }
+    }
+
+    fn is_one(&self) -> bool {
+        self.num.is_one() && self.den.is_one()
+    }
+}
+
+impl<T> Add for Fraction<T>
+where
+    T: Clone + Zero + One + PartialOrd + Add<Output = T>,
+    T: Mul<Output = T> + Div<Output = T> + Rem<Output = T> + Neg<Output = T>,
+{
+    type Output = Fraction<T>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.num * rhs.den.clone() + rhs.num * self.den.clone(),
+            self.den * rhs.den,
+        )
+    }
+}
+
+impl<T> Sub for Fraction<T>
+where
+    T: Clone + Zero + One + PartialOrd + Sub<Output = T>,
+    T: Mul<Output = T> + Div<Output = T> + Rem<Output = T> + Neg<Output = T>,
+{
+    type Output = Fraction<T>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.num * rhs.den.clone() - rhs.num * self.den.clone(),
+            self.den * rhs.den,
+        )
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Fraction<T> {
+    type Output = Fraction<T>;
+
+    fn neg(self) -> Self::Output {
+        Fraction {
+            num: -self.num,
+            den: self.den,
+        }
+    }
+}
+
+impl<T> Mul for Fraction<T>
+where
+    T: Clone + Zero + One + PartialOrd,
+    T: Mul<Output = T> + Div<Output = T> + Rem<Output = T> + Neg<Output = T>,
+{
+    type Output = Fraction<T>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(self.num * rhs.num, self.den * rhs.den)
+    }
+}
+
+impl<T> Div for Fraction<T>
+where
+    T: Clone + Zero + One + PartialOrd,
+    T: Mul<Output = T> + Div<Output = T> + Rem<Output = T> + Neg<Output = T>,
+{
+    type Output = Fraction<T>;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        if rhs.is_zero() {
+            panic!("Can't divide fraction by zero");
+        }
+        Self::new(self.num * rhs.den, self.den * rhs.num)
+    }
+}
+
+macro_rules! impl_assign {
+    ($($tr:ident $f:ident $op:ident $bound:ident),*) => {
+        $(
+            impl<T> $tr for Fraction<T>
+            where
+                T: Clone + Zero + One + PartialOrd + $bound<Output = T>,
+                T: Mul<Output = T> + Div<Output = T> + Rem<Output = T> + Neg<Output = T>,
+            {
+                fn $f(&mut self, rhs: Self) {
+                    *self = self.clone().$op(rhs);
+                }
+            }
+        )*
+    };
+}
+
+impl_assign!(
+    AddAssign add_assign add Add,
+    SubAssign sub_assign sub Sub,
+    MulAssign mul_assign mul Mul,
+    DivAssign div_assign div Div
+);
+
+impl<T> PartialOrd for Fraction<T>
+where
+    T: Clone + PartialOrd + Mul<Output = T>,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        // denominators are positive
+        (self.num.clone() * other.den.clone()).partial_cmp(&(other.num.clone() * self.den.clone()))
+    }
+}
+
+impl<T: Display + One> Display for Fraction<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.den.is_one() {
+            write!(f, "{}", self.num)
+        } else {
+            write!(f, "{}/{}", self.num, self.den)
+        }
+    }
+}
+
+#[cfg(test)]
+mod fraction_tests {
+    use super::Fraction;
+    use crate::custom_types::BigInt;
+    use crate::{One, Zero};
+
+    type Q = Fraction<i64>;
+
+    #[test]
+    fn test_new() {
+        assert_eq!(Q::new(2, 4), Q::new(1, 2));
+        assert_eq!(Q::new(-2, -4), Q::new(1, 2));
+        assert_eq!(*Q::new(3, -9).denom(), 3);
+        assert_eq!(*Q::new(0, -9).denom(), 1);
+        assert!(Q::new(0, 5).is_zero());
+        assert!(Q::new(5, 5).is_one());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_zero_den() {
+        Q::new(1, 0);
+    }
+
+    #[test]
+    fn test_ops() {
+        let a = Q::new(1, 2);
+        let b = Q::new(-2, 3);
+        assert_eq!(a + b, Q::new(-1, 6));
+        assert_eq!(a - b, Q::new(7, 6));
+        assert_eq!(a * b, Q::new(-1, 3));
+        assert_eq!(a / b, Q::new(-3, 4));
+        assert_eq!(-a, Q::new(-1, 2));
+        assert_eq!(b.recip(), Q::new(-3, 2));
+        let mut c = a;
+        c += b;
+        c -= b;
+        c *= b;
+        c /= b;
+        assert_eq!(c, a);
+        assert!(b < a);
+        assert!(Q::new(1, 3) < Q::new(1, 2));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Q::new(6, 3).to_string(), "2");
+        assert_eq!(Q::new(-1, 3).to_string(), "-1/3");
+    }
+
+    #[test]
+    fn test_bigint() {
+        type B = Fraction<BigInt>;
+        // sum of 1 / k for k in 1..=30 has 13-digit denominator, and it is reduced
+        let mut h = B::zero();
+        for k in 1..=30 {
+            h += B::new(BigInt::one(), BigInt::from(k));
+        }
+        assert_eq!(h.to_string(), "9304682830147/2329089562800");
+    }
+}
@@ -0,0 +1,318 @@
+//! Defines type `GaussianInt` - element of Z[i].
+
+use std::fmt::Display;
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
+
+use crate::{One, Zero};
+
+/// Gaussian integer re + im * i, where i^2 = -1.
+///
+/// Z[i] is Euclidean ring: `/` and `%` give quotient and remainder,
+/// such that norm of remainder is less than norm of divisor.
+///
+/// Example:
+/// ```
+/// # use polylib::custom_types::GaussianInt;
+/// let a = GaussianInt::new(1, 2);
+/// let b = GaussianInt::new(3, -1);
+/// assert_eq!(a * b, GaussianInt::new(5, 5));
+/// ```
+#[derive(PartialEq, Eq, Hash, Copy, Clone, Debug, Default)]
+pub struct GaussianInt {
+    re: i64,
+    im: i64,
+}
+
+// rounds num / den to nearest integer, den > 0
+fn div_round(num: i128, den: i128) -> i128 {
+    (2 * num + den).div_euclid(2 * den)
+}
+
+impl GaussianInt {
+    /// Creates re + im * i.
+    pub fn new(re: i64, im: i64) -> GaussianInt {
+        GaussianInt { re, im }
+    }
+
+    /// Returns imaginary unit i.
+    pub fn i() -> GaussianInt {
+        GaussianInt { re: 0, im: 1 }
+    }
+
+    /// Returns real part.
+    pub fn re(&self) -> i64 {
+        self.re
+    }
+
+    /// Returns imaginary part.
+    pub fn im(&self) -> i64 {
+        self.im
+    }
+
+    /// Returns conjugate re - im * i.
+    pub fn conj(&self) -> GaussianInt {
+        GaussianInt::new(self.re, -self.im)
+    }
+
+    /// Returns norm re^2 + im^2. It is multiplicative.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::GaussianInt;
+    /// assert_eq!(GaussianInt::new(3, -4).norm(), 25);
+    /// ```
+    pub fn norm(&self) -> i64 {
+        self.re * self.re + self.im * self.im
+    }
+
+    /// Checks if self is one of units: 1, -1, i, -i.
+    pub fn is_unit(&self) -> bool {
+        self.norm() == 1
+    }
+
+    /// Returns quotient and remainder of Euclidean division.
+    /// Quotient is rounded to nearest Gaussian integer,
+    /// so norm of remainder is at most half of norm of rhs.
+    ///
+    /// Panics if rhs is zero.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::GaussianInt;
+    /// let a = GaussianInt::new(7, 3);
+    /// let b = GaussianInt::new(2, 1);
+    /// let (q, r) = a.div_rem(&b);
+    /// assert_eq!(q * b + r, a);
+    /// assert!(r.norm() < b.norm());
+    /// ```
+    pub fn div_rem(&self, rhs: &GaussianInt) -> (GaussianInt, GaussianInt) {
+        if rhs.is_zero() {
+            panic!("Can't divide gaussian integer by zero");
+        }
+        // self / rhs = self * conj(rhs) / norm(rhs), product may not fit into i64
+        let (a, b) = (self.re as i128, self.im as i128);
+        let (c, d) = (rhs.re as i128, rhs.im as i128);
+        let den = c * c + d * d;
+        let q = GaussianInt::new(
+            div_round(a * c + b * d, den) as i64,
+            div_round(b * c - a * d, den) as i64,
+        );
+        (q, *self - q * *rhs)
+    }
+
+    /// Returns greatest common divisor, normalized to lie
+    /// in first quadrant (re > 0, im >= 0), or zero.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::GaussianInt;
+    /// // 5 = (2 + i)(2 - i), 2 + i = (2 + i) * 1
+    /// let g = GaussianInt::gcd(GaussianInt::new(5, 0), GaussianInt::new(2, 1));
+    /// assert_eq!(g, GaussianInt::new(2, 1));
+    /// ```
+    pub fn gcd(a: GaussianInt, b: GaussianInt) -> GaussianInt {
+        let (mut a, mut b) = (a, b);
+        while !b.is_zero() {
+            let r = a.div_rem(&b).1;
+            a = b;
+            b = r;
+        }
+        a.normalized()
+    }
+
+    // multiplies by unit, so result is in first quadrant
+    fn normalized(self) -> GaussianInt {
+        let mut value = self;
+        for _ in 0..4 {
+            if value.re > 0 && value.im >= 0 {
+                return value;
+            }
+            value *= Self::i();
+        }
+        value
+    }
+}
+
+impl From<i64> for GaussianInt {
+    fn from(value: i64) -> Self {
+        GaussianInt::new(value, 0)
+    }
+}
+
+impl Zero for GaussianInt {
+    fn zero() -> Self {
+        GaussianInt::new(0, 0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.re == 0 && self.im == 0
+    }
+}
+
+impl One for GaussianInt {
+    fn one() -> Self {
+        GaussianInt::new(1, 0)
+    }
+
+    fn is_one(&self) -> bool {
+        self.re == 1 && self.im == 0
+    }
+}
+
+impl Add for GaussianInt {
+    type Output = GaussianInt;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        GaussianInt::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl Sub for GaussianInt {
+    type Output = GaussianInt;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        GaussianInt::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+impl Neg for GaussianInt {
+    type Output = GaussianInt;
+
+    fn neg(self) -> Self::Output {
+        GaussianInt::new(-self.re, -self.im)
+    }
+}
+
+impl Mul for GaussianInt {
+    type Output = GaussianInt;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        GaussianInt::new(
+            self.re * rhs.re - self.im * rhs.im,
+            self.re * rhs.im + self.im * rhs.re,
+        )
+    }
+}
+
+impl Div for GaussianInt {
+    type Output = GaussianInt;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        self.div_rem(&rhs).0
+    }
+}
+
+impl Rem for GaussianInt {
+    type Output = GaussianInt;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        self.div_rem(&rhs).1
+    }
+}
+
+impl AddAssign for GaussianInt {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for GaussianInt {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl MulAssign for GaussianInt {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl Display for GaussianInt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.re, self.im) {
+            (re, 0) => write!(f, "{}", re),
+            (0, 1) => write!(f, "i"),
+            (0, -1) => write!(f, "-i"),
+            (0, im) => write!(f, "{}i", im),
+            (re, 1) => write!(f, "({}+i)", re),
+            (re, -1) => write!(f, "({}-i)", re),
+            (re, im) if im < 0 => write!(f, "({}{}i)", re, im),
+            (re, im) => write!(f, "({}+{}i)", re, im),
+        }
+    }
+}
+
+#[cfg(test)]
+mod gaussian_tests {
+    use super::GaussianInt;
+    use crate::{One, Zero};
+
+    type G = GaussianInt;
+
+    #[test]
+    fn test_ops() {
+        let a = G::new(2, 3);
+        let b = G::new(-1, 4);
+        assert_eq!(a + b, G::new(1, 7));
+        assert_eq!(a - b, G::new(3, -1));
+        assert_eq!(a * b, G::new(-14, 5));
+        assert_eq!(-a, G::new(-2, -3));
+        assert_eq!(G::i() * G::i(), -G::one());
+        assert_eq!(a * a.conj(), G::from(a.norm()));
+        assert!(G::zero().is_zero());
+    }
+
+    #[test]
+    fn test_div_rem() {
+        let values = [G::new(7, 3), G::new(-11, 5), G::new(0, -9), G::new(13, 13)];
+        let divisors = [G::new(2, 1), G::new(-3, 2), G::new(0, 4), G::new(5, 0)];
+        for a in values {
+            for b in divisors {
+                let (q, r) = a.div_rem(&b);
+                assert_eq!(q * b + r, a);
+                assert!(2 * r.norm() <= b.norm());
+            }
+        }
+        assert_eq!(G::new(5, 5) / G::new(1, 2), G::new(3, -1));
+        assert!((G::new(5, 5) % G::new(1, 2)).is_zero());
+    }
+
+    #[test]
+    fn test_div_rem_large() {
+        // a * conj(b) overflows i64
+        let a = G::new(4_000_000_000, 4_000_000_000);
+        let b = G::new(3_000_000_000, 1);
+        let (q, r) = a.div_rem(&b);
+        assert_eq!(q, G::new(1, 1));
+        assert_eq!(q * b + r, a);
+        assert!(2 * r.norm() <= b.norm());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_div_zero() {
+        let _ = G::new(1, 1) / G::zero();
+    }
+
+    #[test]
+    fn test_gcd() {
+        // 5 = (2 + i)(2 - i), 13 = (3 + 2i)(3 - 2i)
+        let a = G::new(2, 1) * G::new(3, 2);
+        let b = G::new(2, 1) * G::new(2, -1);
+        assert_eq!(G::gcd(a, b), G::new(2, 1));
+        assert_eq!(G::gcd(-a * G::i(), b), G::new(2, 1));
+        assert_eq!(G::gcd(G::new(3, 0), G::new(2, 1)), G::one());
+        assert_eq!(G::gcd(G::zero(), G::new(0, -4)), G::new(4, 0));
+        assert!(G::new(0, -1).is_unit());
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(G::new(3, 0).to_string(), "3");
+        assert_eq!(G::new(0, -1).to_string(), "-i");
+        assert_eq!(G::new(0, 2).to_string(), "2i");
+        assert_eq!(G::new(1, -2).to_string(), "(1-2i)");
+        assert_eq!(G::new(-1, 1).to_string(), "(-1+i)");
+    }
+}
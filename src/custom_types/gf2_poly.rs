@@ -0,0 +1,441 @@
+//! Defines type `Gf2Poly` - dense polynomial over GF(2).
+
+use std::fmt::Display;
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
+
+use crate::{One, PolyError, Zero};
+
+/// Polynomial over GF(2). Coefficients are bits, packed into u64 words
+/// (bit `i % 64` of word `i / 64` is coef of x^i).
+///
+/// Addition is XOR, so subtraction is same as addition.
+///
+/// Example:
+/// ```
+/// # use polylib::custom_types::Gf2Poly;
+/// let a = Gf2Poly::from_powers(&[1, 0]); // x + 1
+/// assert_eq!(a.clone() * a, Gf2Poly::from_powers(&[2, 0])); // (x + 1)^2 = x^2 + 1
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub struct Gf2Poly {
+    // no trailing zero words, so zero polynomial has no words
+    words: Vec<u64>,
+}
+
+// carry-less product of two words as (low, high) words
+fn clmul(a: u64, b: u64) -> (u64, u64) {
+    let mut lo = 0;
+    let mut hi = 0;
+    for i in 0..64 {
+        if (b >> i) & 1 == 1 {
+            lo ^= a << i;
+            if i > 0 {
+                hi ^= a >> (64 - i);
+            }
+        }
+    }
+    (lo, hi)
+}
+
+impl Gf2Poly {
+    fn from_words(mut words: Vec<u64>) -> Gf2Poly {
+        while words.last() == Some(&0) {
+            words.pop();
+        }
+        Gf2Poly { words }
+    }
+
+    /// Returns polynomial, where bit i of value is coef of x^i.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::Gf2Poly;
+    /// let p = Gf2Poly::from_u64(0b1011); // x^3 + x + 1
+    /// assert_eq!(p.degree(), Some(3));
+    /// ```
+    pub fn from_u64(value: u64) -> Gf2Poly {
+        Self::from_words(vec![value])
+    }
+
+    /// Returns polynomial with packed coefs, see `words`.
+    pub fn from_words_le(words: Vec<u64>) -> Gf2Poly {
+        Self::from_words(words)
+    }
+
+    /// Returns sum of x^p for each p of powers. Repeated powers cancel out.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::Gf2Poly;
+    /// let p = Gf2Poly::from_powers(&[32, 26, 0]);
+    /// assert_eq!(p.to_string(), "1 + x^26 + x^32");
+    /// ```
+    pub fn from_powers(powers: &[usize]) -> Gf2Poly {
+        let mut ans = Gf2Poly::default();
+        for p in powers {
+            ans.set(*p, !ans.get(*p));
+        }
+        ans
+    }
+
+    /// Returns packed coefs, lowest word first.
+    pub fn words(&self) -> &[u64] {
+        &self.words
+    }
+
+    /// Returns degree, or `None` for zero polynomial.
+    pub fn degree(&self) -> Option<usize> {
+        let last = self.words.last()?;
+        Some((self.words.len() - 1) * 64 + 63 - last.leading_zeros() as usize)
+    }
+
+    /// Returns coef of x^index.
+    pub fn get(&self, index: usize) -> bool {
+        match self.words.get(index / 64) {
+            Some(w) => (w >> (index % 64)) & 1 == 1,
+            None => false,
+        }
+    }
+
+    /// Sets coef of x^index.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::Gf2Poly;
+    /// let mut p = Gf2Poly::from_u64(1);
+    /// p.set(100, true);
+    /// assert_eq!(p.degree(), Some(100));
+    /// p.set(100, false);
+    /// assert_eq!(p.degree(), Some(0));
+    /// ```
+    pub fn set(&mut self, index: usize, value: bool) {
+        let word = index / 64;
+        if value {
+            if self.words.len() <= word {
+                self.words.resize(word + 1, 0);
+            }
+            self.words[word] |= 1 << (index % 64);
+        } else if word < self.words.len() {
+            self.words[word] &= !(1 << (index % 64));
+            let words = std::mem::take(&mut self.words);
+            *self = Self::from_words(words);
+        }
+    }
+
+    /// Returns self * x^shift.
+    pub fn shl(&self, shift: usize) -> Gf2Poly {
+        if self.words.is_empty() {
+            return Gf2Poly::default();
+        }
+        let (whole, bits) = (shift / 64, shift % 64);
+        let mut words = vec![0; self.words.len() + whole + 1];
+        for (i, w) in self.words.iter().enumerate() {
+            words[i + whole] |= w << bits;
+            if bits > 0 {
+                words[i + whole + 1] |= w >> (64 - bits);
+            }
+        }
+        Self::from_words(words)
+    }
+
+    /// Returns value at point (`true` is 1).
+    pub fn substitude(&self, point: bool) -> bool {
+        if !point {
+            return self.get(0);
+        }
+        self.words.iter().fold(0, |acc, w| acc ^ w.count_ones()) % 2 == 1
+    }
+
+    /// Returns quotient and remainder, or error if rhs is zero.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::Gf2Poly;
+    /// let a = Gf2Poly::from_u64(0b1101); // x^3 + x^2 + 1
+    /// let b = Gf2Poly::from_u64(0b11); // x + 1
+    /// let (q, r) = a.checked_div_rem(&b).unwrap();
+    /// assert_eq!(q, Gf2Poly::from_u64(0b100)); // x^2
+    /// assert_eq!(r, Gf2Poly::from_u64(1));
+    /// ```
+    pub fn checked_div_rem(&self, rhs: &Gf2Poly) -> Result<(Self, Self), PolyError> {
+        let rhs_degree = rhs.degree().ok_or(PolyError::DivisionByZero)?;
+        let mut quotient = Gf2Poly::default();
+        let mut remainder = self.clone();
+        while let Some(degree) = remainder.degree() {
+            if degree < rhs_degree {
+                break;
+            }
+            let shift = degree - rhs_degree;
+            quotient.set(shift, true);
+            remainder += rhs.shl(shift);
+        }
+        Ok((quotient, remainder))
+    }
+
+    /// Same as `checked_div_rem`, but panics on division by zero.
+    pub fn div_rem(&self, rhs: &Gf2Poly) -> (Gf2Poly, Gf2Poly) {
+        match self.checked_div_rem(rhs) {
+            Ok(ans) => ans,
+            Err(err) => panic!("can't divide polynomials: {}", err),
+        }
+    }
+
+    /// Returns greatest common divisor.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::Gf2Poly;
+    /// let a = Gf2Poly::from_u64(0b101); // x^2 + 1 = (x + 1)^2
+    /// let b = Gf2Poly::from_u64(0b110); // x^2 + x = x (x + 1)
+    /// assert_eq!(a.gcd(&b), Gf2Poly::from_u64(0b11));
+    /// ```
+    pub fn gcd(&self, rhs: &Gf2Poly) -> Gf2Poly {
+        let mut a = self.clone();
+        let mut b = rhs.clone();
+        while !b.is_zero() {
+            let r = a.div_rem(&b).1;
+            a = b;
+            b = r;
+        }
+        a
+    }
+}
+
+impl Zero for Gf2Poly {
+    fn zero() -> Self {
+        Gf2Poly::default()
+    }
+
+    fn is_zero(&self) -> bool {
+        self.words.is_empty()
+    }
+}
+
+impl One for Gf2Poly {
+    fn one() -> Self {
+        Gf2Poly::from_u64(1)
+    }
+
+    fn is_one(&self) -> bool {
+        self.words == [1]
+    }
+}
+
+impl AddAssign<&Gf2Poly> for Gf2Poly {
+    fn add_assign(&mut self, rhs: &Gf2Poly) {
+        if self.words.len() < rhs.words.len() {
+            self.words.resize(rhs.words.len(), 0);
+        }
+        for (a, b) in self.words.iter_mut().zip(rhs.words.iter()) {
+            *a ^= b;
+        }
+        let words = std::mem::take(&mut self.words);
+        *self = Self::from_words(words);
+    }
+}
+
+impl AddAssign for Gf2Poly {
+    fn add_assign(&mut self, rhs: Self) {
+        *self += &rhs;
+    }
+}
+
+impl Add for Gf2Poly {
+    type Output = Gf2Poly;
+
+    fn add(mut self, rhs: Self) -> Self::Output {
+        self += &rhs;
+        self
+    }
+}
+
+impl SubAssign for Gf2Poly {
+    #[allow(clippy::suspicious_op_assign_impl)]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self += &rhs;
+    }
+}
+
+impl Sub for Gf2Poly {
+    type Output = Gf2Poly;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + rhs
+    }
+}
+
+impl Neg for Gf2Poly {
+    type Output = Gf2Poly;
+
+    fn neg(self) -> Self::Output {
+        self
+    }
+}
+
+impl Mul for &Gf2Poly {
+    type Output = Gf2Poly;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        if self.is_zero() || rhs.is_zero() {
+            return Gf2Poly::default();
+        }
+        let mut words = vec![0; self.words.len() + rhs.words.len()];
+        for (i, a) in self.words.iter().enumerate() {
+            for (j, b) in rhs.words.iter().enumerate() {
+                let (lo, hi) = clmul(*a, *b);
+                words[i + j] ^= lo;
+                words[i + j + 1] ^= hi;
+            }
+        }
+        Gf2Poly::from_words(words)
+    }
+}
+
+impl Mul for Gf2Poly {
+    type Output = Gf2Poly;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+impl MulAssign for Gf2Poly {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = &*self * &rhs;
+    }
+}
+
+impl Div for Gf2Poly {
+    type Output = Gf2Poly;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        self.div_rem(&rhs).0
+    }
+}
+
+impl Rem for Gf2Poly {
+    type Output = Gf2Poly;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        self.div_rem(&rhs).1
+    }
+}
+
+impl Display for Gf2Poly {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let degree = match self.degree() {
+            Some(d) => d,
+            None => return write!(f, "0"),
+        };
+        let mut first = true;
+        for i in (0..=degree).filter(|i| self.get(*i)) {
+            if !first {
+                write!(f, " + ")?;
+            }
+            first = false;
+            match i {
+                0 => write!(f, "1")?,
+                1 => write!(f, "x")?,
+                _ => write!(f, "x^{}", i)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod gf2_poly_tests {
+    use super::Gf2Poly;
+    use crate::custom_types::Zn;
+    use crate::polynom::Polynomial;
+    use crate::{One, PolyError, Zero};
+
+    // same polynomial with `Polynomial<Zn<2>>`, to compare results
+    fn slow(p: &Gf2Poly) -> Polynomial<Zn<2>> {
+        let n = p.degree().map_or(0, |d| d + 1);
+        Polynomial::from_coefs((0..n).map(|i| Zn::new(p.get(i) as u32)).collect())
+    }
+
+    fn pseudo_random(seed: u64, len: usize) -> Gf2Poly {
+        let mut x = seed;
+        let words = (0..len)
+            .map(|_| {
+                x ^= x << 13;
+                x ^= x >> 7;
+                x ^= x << 17;
+                x
+            })
+            .collect();
+        Gf2Poly::from_words_le(words)
+    }
+
+    #[test]
+    fn test_create() {
+        assert!(Gf2Poly::from_u64(0).is_zero());
+        assert!(Gf2Poly::from_words_le(vec![1, 0, 0]).is_one());
+        assert_eq!(Gf2Poly::from_powers(&[3, 3]), Gf2Poly::zero());
+        assert_eq!(Gf2Poly::from_powers(&[64]).words(), &[0, 1]);
+        assert_eq!(Gf2Poly::zero().degree(), None);
+        assert_eq!(Gf2Poly::from_powers(&[130, 5]).degree(), Some(130));
+    }
+
+    #[test]
+    fn test_add() {
+        let a = Gf2Poly::from_powers(&[100, 1]);
+        let b = Gf2Poly::from_powers(&[100, 0]);
+        assert_eq!(a.clone() + b.clone(), Gf2Poly::from_u64(0b11));
+        assert_eq!(a.clone() - b, Gf2Poly::from_u64(0b11));
+        assert!((a.clone() + a).is_zero());
+    }
+
+    #[test]
+    fn test_mul() {
+        let a = pseudo_random(1, 3);
+        let b = pseudo_random(2, 2);
+        let fast = &a * &b;
+        let expected = (slow(&a) * slow(&b)).reduce();
+        for i in 0..=fast.degree().unwrap() {
+            assert_eq!(fast.get(i) as u32, expected.coef(i as u32).value(), "bad coef {}", i);
+        }
+        assert_eq!(fast.degree(), Some(a.degree().unwrap() + b.degree().unwrap()));
+    }
+
+    #[test]
+    fn test_div_rem() {
+        let a = pseudo_random(3, 5);
+        let b = pseudo_random(4, 2);
+        let (q, r) = a.div_rem(&b);
+        assert!(r.degree() < b.degree());
+        assert_eq!(q * b + r, a);
+        assert_eq!(
+            a.checked_div_rem(&Gf2Poly::zero()),
+            Err(PolyError::DivisionByZero)
+        );
+    }
+
+    #[test]
+    fn test_gcd() {
+        let common = Gf2Poly::from_powers(&[7, 3, 0]);
+        let a = &pseudo_random(5, 2) * &common;
+        let b = &pseudo_random(6, 2) * &common;
+        let g = a.gcd(&b);
+        assert!((a % g.clone()).is_zero());
+        assert!((b % g.clone()).is_zero());
+        assert!((g % common).is_zero());
+    }
+
+    #[test]
+    fn test_substitude() {
+        let p = Gf2Poly::from_powers(&[70, 3, 0]);
+        assert!(p.substitude(false));
+        assert!(p.substitude(true));
+        assert!(!Gf2Poly::from_powers(&[70, 3]).substitude(true));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Gf2Poly::zero().to_string(), "0");
+        assert_eq!(Gf2Poly::from_u64(0b1011).to_string(), "1 + x + x^3");
+    }
+}
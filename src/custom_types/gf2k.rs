@@ -0,0 +1,263 @@
+//! Defines type `Gf2k` - binary extension field GF(2^K).
+
+use std::fmt::Display;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::random::{Random, Xorshift64};
+use crate::{One, Zero};
+
+/// Element of GF(2^K). Value is polynomial over GF(2) of degree less than K,
+/// bit i is coef of x^i. Multiplication is done modulo POLY.
+///
+/// POLY must have degree K (it is checked at compile time)
+/// and must be irreducible, otherwise `Div` gives wrong results.
+///
+/// Example:
+/// ```
+/// # use polylib::custom_types::Gf2k;
+/// type Aes = Gf2k<8, 0x11B>; // x^8 + x^4 + x^3 + x + 1
+/// assert_eq!(Aes::new(0x57) * Aes::new(0x83), Aes::new(0xC1));
+/// ```
+///
+/// Every value is made through the check, `Default` and `Random` too:
+/// ```compile_fail
+/// # use polylib::custom_types::Gf2k;
+/// let a = Gf2k::<8, 0x1B>::default();
+/// ```
+#[derive(PartialEq, Eq, Hash, Copy, Clone, Debug)]
+pub struct Gf2k<const K: u32, const POLY: u64>(u64);
+
+/// GF(2^8) with AES polynomial x^8 + x^4 + x^3 + x + 1.
+pub type Gf256 = Gf2k<8, 0x11B>;
+
+impl<const K: u32, const POLY: u64> Gf2k<K, POLY> {
+    /// Creates element from bits of value, reduced modulo POLY.
+    pub fn new(mut value: u64) -> Gf2k<K, POLY> {
+        const {
+            assert!(K >= 1 && K <= 63, "Gf2k supports only 1 <= K <= 63");
+            assert!(POLY >> K == 1, "POLY must have degree K");
+        };
+        while value >> K != 0 {
+            let shift = 63 - value.leading_zeros() - K;
+            value ^= POLY << shift;
+        }
+        Gf2k(value)
+    }
+
+    /// Returns holding bits.
+    pub fn value(&self) -> u64 {
+        self.0
+    }
+
+    /// Returns self in power exp.
+    pub fn pow(self, mut exp: u64) -> Gf2k<K, POLY> {
+        let mut ans = Self::new(1);
+        let mut base = self;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                ans *= base;
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base *= base;
+            }
+        }
+        ans
+    }
+
+    /// Returns inverse element, or `None` for zero.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::Gf256;
+    /// assert_eq!(Gf256::new(0x53).inv(), Some(Gf256::new(0xCA)));
+    /// ```
+    pub fn inv(self) -> Option<Gf2k<K, POLY>> {
+        if self.0 == 0 {
+            return None;
+        }
+        // multiplicative group has 2^K - 1 elements
+        Some(self.pow((1u64 << K) - 2))
+    }
+}
+
+impl<const K: u32, const POLY: u64> Default for Gf2k<K, POLY> {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl<const K: u32, const POLY: u64> Zero for Gf2k<K, POLY> {
+    fn zero() -> Self {
+        Self::new(0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl<const K: u32, const POLY: u64> One for Gf2k<K, POLY> {
+    fn one() -> Self {
+        Self::new(1)
+    }
+
+    fn is_one(&self) -> bool {
+        self.0 == 1
+    }
+}
+
+impl<const K: u32, const POLY: u64> Add for Gf2k<K, POLY> {
+    type Output = Gf2k<K, POLY>;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn add(self, rhs: Self) -> Self::Output {
+        Gf2k(self.0 ^ rhs.0)
+    }
+}
+
+impl<const K: u32, const POLY: u64> AddAssign for Gf2k<K, POLY> {
+    #[allow(clippy::suspicious_op_assign_impl)]
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 ^= rhs.0;
+    }
+}
+
+impl<const K: u32, const POLY: u64> Sub for Gf2k<K, POLY> {
+    type Output = Gf2k<K, POLY>;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Gf2k(self.0 ^ rhs.0)
+    }
+}
+
+impl<const K: u32, const POLY: u64> SubAssign for Gf2k<K, POLY> {
+    #[allow(clippy::suspicious_op_assign_impl)]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0 ^= rhs.0;
+    }
+}
+
+impl<const K: u32, const POLY: u64> Neg for Gf2k<K, POLY> {
+    type Output = Gf2k<K, POLY>;
+
+    fn neg(self) -> Self::Output {
+        self
+    }
+}
+
+impl<const K: u32, const POLY: u64> Mul for Gf2k<K, POLY> {
+    type Output = Gf2k<K, POLY>;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, rhs: Self) -> Self::Output {
+        // carry-less shift-and-add, reducing on each step
+        let mut a = self.0;
+        let mut b = rhs.0;
+        let mut ans = 0;
+        while b != 0 {
+            if b & 1 == 1 {
+                ans ^= a;
+            }
+            b >>= 1;
+            a <<= 1;
+            if a >> K != 0 {
+                a ^= POLY;
+            }
+        }
+        Gf2k(ans)
+    }
+}
+
+impl<const K: u32, const POLY: u64> MulAssign for Gf2k<K, POLY> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<const K: u32, const POLY: u64> Div for Gf2k<K, POLY> {
+    type Output = Gf2k<K, POLY>;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        match rhs.inv() {
+            Some(inv) => self * inv,
+            None => panic!("Can't divide by zero in GF(2^{})", K),
+        }
+    }
+}
+
+impl<const K: u32, const POLY: u64> DivAssign for Gf2k<K, POLY> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl<const K: u32, const POLY: u64> Random for Gf2k<K, POLY> {
+    fn random(rng: &mut Xorshift64) -> Self {
+        Self::new(rng.next_u64() & ((1 << K) - 1))
+    }
+}
+
+impl<const K: u32, const POLY: u64> Display for Gf2k<K, POLY> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "<GF(2^{}) {:#x}>", K, self.0)
+    }
+}
+
+#[cfg(test)]
+mod gf2k_tests {
+    use super::{Gf256, Gf2k};
+    use crate::polynom::Polynomial;
+    use crate::{One, Zero};
+
+    #[test]
+    fn test_new() {
+        assert_eq!(Gf256::new(0x100).value(), 0x1B);
+        assert_eq!(Gf256::new(0x11B).value(), 0);
+        assert_eq!(Gf256::new(0x53).value(), 0x53);
+    }
+
+    #[test]
+    fn test_field() {
+        for a in 1..256 {
+            let a = Gf256::new(a);
+            assert!((a * a.inv().unwrap()).is_one());
+            assert_eq!(Gf256::one() / a, a.inv().unwrap());
+            assert!((a + a).is_zero());
+            assert_eq!(-a, a);
+        }
+        assert_eq!(Gf256::zero().inv(), None);
+    }
+
+    #[test]
+    fn test_mul_by_x() {
+        // x^8 + x^4 + x^3 + x^2 + 1 is primitive, so powers of x give all nonzero elements
+        type F = Gf2k<8, 0x11D>;
+        let x = F::new(2);
+        let mut seen = std::collections::HashSet::new();
+        for i in 0..255 {
+            seen.insert(x.pow(i));
+        }
+        assert_eq!(seen.len(), 255);
+        assert!(x.pow(255).is_one());
+    }
+
+    #[test]
+    fn test_large() {
+        type F = Gf2k<63, { (1 << 63) | 0b11 }>; // x^63 + x + 1
+        let a = F::new(0x1234_5678_9ABC_DEF0);
+        assert!((a / a).is_one());
+        assert_eq!((a * a.inv().unwrap()).value(), 1);
+    }
+
+    #[test]
+    fn test_poly() {
+        // (x + 3)(x + 5) = x^2 + 6x + 15 over GF(2^8), since 3 + 5 = 6 and 3 * 5 = 15
+        let a = Polynomial::<Gf256>::from_coefs(vec![Gf256::new(3), Gf256::one()]);
+        let b = Polynomial::<Gf256>::from_coefs(vec![Gf256::new(5), Gf256::one()]);
+        let v: Gf256 = (a * b).substitude(Gf256::new(3));
+        assert!(v.is_zero());
+    }
+}
@@ -0,0 +1,257 @@
+//! Defines type `Jet` - truncated Taylor series for higher order derivatives.
+
+use std::fmt::Display;
+use std::ops::{Add, Mul, Neg, Sub};
+
+use crate::{One, Zero};
+
+/// Truncated Taylor series a0 + a1 h + ... + aK h^K, where h^(K+1) = 0.
+///
+/// It generalizes `Dual` to higher orders: `Jet<T, 1>` holds the same
+/// value and derivative as `Dual<T>`, though they are different types.
+/// Substitution of `Jet::variable(x)` into polynomial p gives
+/// p(x), p'(x), ..., p^(K)(x) at once.
+///
+/// Taylor coefs are stored instead of derivatives, so integer
+/// polynomials are evaluated without division.
+///
+/// Example:
+/// ```
+/// # use polylib::custom_types::Jet;
+/// # use polylib::polynom::Polynomial;
+/// let p = Polynomial::<i32>::from_coefs(vec![0, 0, 0, 1]); // x^3
+/// let ans: Jet<i32, 3> = p.substitude(Jet::variable(2));
+/// assert_eq!(ans.derivatives(), vec![8, 12, 12, 6]);
+/// ```
+#[derive(PartialEq, Eq, Hash, Copy, Clone, Debug)]
+pub struct Jet<T, const K: usize> {
+    value: T,
+    // coefs of h^1 .. h^K
+    taylor: [T; K],
+}
+
+impl<T, const K: usize> Jet<T, K> {
+    /// Creates jet from value and Taylor coefs of h^1, ..., h^K.
+    /// Coef of h^i is i-th derivative divided by i!.
+    pub fn from_taylor(value: T, taylor: [T; K]) -> Jet<T, K> {
+        Jet { value, taylor }
+    }
+
+    /// Returns value part.
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Returns Taylor coef of h^index. Panics if index > K.
+    pub fn taylor(&self, index: usize) -> &T {
+        if index == 0 {
+            &self.value
+        } else {
+            &self.taylor[index - 1]
+        }
+    }
+}
+
+impl<T: Zero, const K: usize> Jet<T, K> {
+    /// Creates constant: all derivatives are zero.
+    pub fn constant(value: T) -> Jet<T, K> {
+        Jet::from_taylor(value, std::array::from_fn(|_| T::zero()))
+    }
+}
+
+impl<T: Zero + One, const K: usize> Jet<T, K> {
+    /// Creates independent variable: first derivative is one, others are zero.
+    pub fn variable(value: T) -> Jet<T, K> {
+        let taylor = std::array::from_fn(|i| if i == 0 { T::one() } else { T::zero() });
+        Jet::from_taylor(value, taylor)
+    }
+}
+
+impl<T, const K: usize> Jet<T, K>
+where
+    T: Clone + One + Add<Output = T> + Mul<Output = T>,
+{
+    /// Returns index-th derivative, that is Taylor coef multiplied by index!.
+    /// Panics if index > K.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::Jet;
+    /// let x = Jet::<i32, 2>::variable(5);
+    /// let sq = x * x; // x^2
+    /// assert_eq!(sq.derivative(2), 2);
+    /// ```
+    pub fn derivative(&self, index: usize) -> T {
+        let mut factorial = T::one();
+        let mut k = T::one();
+        for _ in 1..index {
+            k = k + T::one();
+            factorial = factorial * k.clone();
+        }
+        self.taylor(index).clone() * factorial
+    }
+
+    /// Returns all derivatives from 0-th (value) to K-th.
+    pub fn derivatives(&self) -> Vec<T> {
+        (0..=K).map(|i| self.derivative(i)).collect()
+    }
+}
+
+impl<T: Zero, const K: usize> From<T> for Jet<T, K> {
+    fn from(value: T) -> Self {
+        Self::constant(value)
+    }
+}
+
+impl<T: Zero, const K: usize> Zero for Jet<T, K> {
+    fn zero() -> Self {
+        Self::constant(T::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.value.is_zero() && self.taylor.iter().all(T::is_zero)
+    }
+}
+
+impl<T: Zero + One, const K: usize> One for Jet<T, K> {
+    fn one() -> Self {
+        Self::constant(T::one())
+    }
+
+    fn is_one(&self) -> bool {
+        self.value.is_one() && self.taylor.iter().all(T::is_zero)
+    }
+}
+
+impl<T: Add<Output = T>, const K: usize> Add for Jet<T, K> {
+    type Output = Jet<T, K>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let mut lhs = self.taylor.into_iter();
+        let mut rhs_taylor = rhs.taylor.into_iter();
+        Jet::from_taylor(
+            self.value + rhs.value,
+            std::array::from_fn(|_| {
+                lhs.next().expect("same len") + rhs_taylor.next().expect("same len")
+            }),
+        )
+    }
+}
+
+impl<T: Sub<Output = T>, const K: usize> Sub for Jet<T, K> {
+    type Output = Jet<T, K>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        let mut lhs = self.taylor.into_iter();
+        let mut rhs_taylor = rhs.taylor.into_iter();
+        Jet::from_taylor(
+            self.value - rhs.value,
+            std::array::from_fn(|_| {
+                lhs.next().expect("same len") - rhs_taylor.next().expect("same len")
+            }),
+        )
+    }
+}
+
+impl<T: Neg<Output = T>, const K: usize> Neg for Jet<T, K> {
+    type Output = Jet<T, K>;
+
+    fn neg(self) -> Self::Output {
+        Jet::from_taylor(-self.value, self.taylor.map(|c| -c))
+    }
+}
+
+impl<T, const K: usize> Mul for Jet<T, K>
+where
+    T: Clone + Add<Output = T> + Mul<Output = T>,
+{
+    type Output = Jet<T, K>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        // product of series, truncated after h^K
+        let coef = |n: usize| {
+            let mut ans = self.taylor(0).clone() * rhs.taylor(n).clone();
+            for i in 1..=n {
+                ans = ans + self.taylor(i).clone() * rhs.taylor(n - i).clone();
+            }
+            ans
+        };
+        Jet::from_taylor(coef(0), std::array::from_fn(|i| coef(i + 1)))
+    }
+}
+
+// coef * point, so polynomials with plain coefs accept jet points
+macro_rules! impl_scalar_mul {
+    ($($t:ty),*) => {
+        $(
+            impl<const K: usize> Mul<Jet<$t, K>> for $t {
+                type Output = Jet<$t, K>;
+
+                fn mul(self, rhs: Jet<$t, K>) -> Self::Output {
+                    Jet::from_taylor(self * rhs.value, rhs.taylor.map(|c| self * c))
+                }
+            }
+        )*
+    };
+}
+
+impl_scalar_mul!(i8, i16, i32, i64, i128, isize);
+impl_scalar_mul!(u8, u16, u32, u64, u128, usize);
+impl_scalar_mul!(f32, f64);
+
+impl<T: Display, const K: usize> Display for Jet<T, K> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}", self.value)?;
+        for (i, c) in self.taylor.iter().enumerate() {
+            if i == 0 {
+                write!(f, " + {}h", c)?;
+            } else {
+                write!(f, " + {}h^{}", c, i + 1)?;
+            }
+        }
+        write!(f, ")")
+    }
+}
+
+#[cfg(test)]
+mod jet_tests {
+    use super::Jet;
+    use crate::{One, Zero};
+
+    type J = Jet<i64, 3>;
+
+    #[test]
+    fn test_ops() {
+        let a = J::from_taylor(1, [2, 3, 4]);
+        let b = J::from_taylor(-1, [0, 1, 5]);
+        assert_eq!(a + b, J::from_taylor(0, [2, 4, 9]));
+        assert_eq!(a - b, J::from_taylor(2, [2, 2, -1]));
+        assert_eq!(-a, J::from_taylor(-1, [-2, -3, -4]));
+        // (1 + 2h + 3h^2 + 4h^3)(-1 + h^2 + 5h^3)
+        assert_eq!(a * b, J::from_taylor(-1, [-2, -2, 3]));
+        assert_eq!(2 * a, J::from_taylor(2, [4, 6, 8]));
+        assert_eq!(a * J::one(), a);
+        assert!((a * J::zero()).is_zero());
+    }
+
+    #[test]
+    fn test_derivatives() {
+        // x^4 at 2: 16, 32, 48, 48
+        let x = J::variable(2);
+        let p = x * x * x * x;
+        assert_eq!(p.derivatives(), vec![16, 32, 48, 48]);
+        assert_eq!(p.derivative(3), 48);
+        assert_eq!(*p.taylor(3), 8);
+    }
+
+    #[test]
+    fn test_zero_order() {
+        let x = Jet::<i32, 0>::variable(3);
+        assert_eq!((x * x).derivatives(), vec![9]);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(J::variable(4).to_string(), "(4 + 1h + 0h^2 + 0h^3)");
+    }
+}
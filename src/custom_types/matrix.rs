@@ -2,10 +2,19 @@
 
 use std::{
     cmp::min,
-    ops::{Add, AddAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign},
+    fmt::Display,
+    ops::{Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign},
 };
 
-use crate::{One, Zero};
+use super::Zn;
+use crate::polynom::Polynomial;
+use crate::random::{Random, Xorshift64};
+use crate::{CoefField, CoefRing, One, PolyError, Zero};
+
+pub mod decomp;
+mod poly;
+
+pub use decomp::{Lu, Qr};
 
 
 /// Type `Matrix`. N, M - sizes of matrix (N - count of rows).
@@ -72,6 +81,54 @@ impl<const N: usize, const M: usize, T> Matrix<N, M, T> {
         Matrix::<N, M, T>{data}
     }
 
+    /// Returns matrix<N, M> from array of rows
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::Matrix;
+    /// let m = Matrix::from_rows([[1, 2, 3], [4, 5, 6]]);
+    /// assert_eq!(m.get_data(), &vec![1, 2, 3, 4, 5, 6]);
+    /// ```
+    pub fn from_rows(rows: [[T; M]; N]) -> Matrix<N, M, T> {
+        Matrix::<N, M, T> {
+            data: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    /// Returns matrix<N, M>, where element [i, j] is f(i, j)
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::Matrix;
+    /// let m = Matrix::<2, 3, usize>::from_fn(|i, j| 10 * i + j);
+    /// assert_eq!(m.get_data(), &vec![0, 1, 2, 10, 11, 12]);
+    /// ```
+    pub fn from_fn<F>(mut f: F) -> Matrix<N, M, T>
+    where
+        F: FnMut(usize, usize) -> T,
+    {
+        Matrix::<N, M, T> {
+            data: (0..N * M).map(|k| f(k / M, k % M)).collect(),
+        }
+    }
+
+    /// Same as `from_data`, but returns error instead of panic
+    /// 
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::Matrix;
+    /// # use polylib::PolyError;
+    /// let m = Matrix::<2, 2, i32>::try_from_data(vec![1, 2, 3]);
+    /// assert_eq!(m, Err(PolyError::DimensionMismatch { rows: 2, cols: 2, len: 3 }));
+    /// ```
+    pub fn try_from_data(data: Vec<T>) -> Result<Matrix<N, M, T>, PolyError>
+    {
+        if data.len() != N * M {
+            return Err(PolyError::DimensionMismatch { rows: N, cols: M, len: data.len() });
+        }
+        Ok(Matrix::<N, M, T>{data})
+    }
+
     /// Returns matrix<N, M> elements in 1d vector
     /// 
     pub fn get_data(&self) -> &Vec<T>
@@ -79,6 +136,12 @@ impl<const N: usize, const M: usize, T> Matrix<N, M, T> {
         &self.data
     }
 
+    /// Returns matrix<N, M> elements in 1d vector, consuming matrix
+    ///
+    pub fn into_data(self) -> Vec<T> {
+        self.data
+    }
+
     /// Set matrix<N, M> elements from 1d vector
     /// 
     pub fn set_data(&mut self, data: Vec<T>) {
@@ -87,6 +150,590 @@ impl<const N: usize, const M: usize, T> Matrix<N, M, T> {
         }
         self.data = data
     }
+
+    /// Same as `set_data`, but returns error instead of panic.
+    /// Matrix is not changed on error.
+    /// 
+    pub fn try_set_data(&mut self, data: Vec<T>) -> Result<(), PolyError> {
+        if data.len() != N * M {
+            return Err(PolyError::DimensionMismatch { rows: N, cols: M, len: data.len() });
+        }
+        self.data = data;
+        Ok(())
+    }
+
+    /// Returns element [row, col] or error, if index is out of matrix
+    /// 
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::Matrix;
+    /// let m = Matrix::<2, 3, i32>::from_data(vec![1, 2, 3, 4, 5, 6]);
+    /// assert_eq!(m.try_get(1, 0), Ok(&4));
+    /// assert!(m.try_get(2, 0).is_err());
+    /// ```
+    pub fn try_get(&self, row: usize, col: usize) -> Result<&T, PolyError> {
+        if row >= N || col >= M {
+            return Err(PolyError::IndexOutOfBounds { row, col, rows: N, cols: M });
+        }
+        Ok(&self.data[row * M + col])
+    }
+
+    /// Returns element [row, col] or `None`, if index is out of matrix
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::Matrix;
+    /// let m = Matrix::from_rows([[1, 2], [3, 4]]);
+    /// assert_eq!(m.get(1, 1), Some(&4));
+    /// assert_eq!(m.get(0, 2), None);
+    /// ```
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        if row >= N || col >= M {
+            return None;
+        }
+        self.data.get(row * M + col)
+    }
+
+    /// Returns mutable element [row, col] or `None`, if index is out of matrix
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::Matrix;
+    /// let mut m = Matrix::from_rows([[1, 2], [3, 4]]);
+    /// if let Some(x) = m.get_mut(0, 1) {
+    ///     *x = 5;
+    /// }
+    /// assert!(m.get_mut(2, 0).is_none());
+    /// assert_eq!(m, Matrix::from_rows([[1, 5], [3, 4]]));
+    /// ```
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        if row >= N || col >= M {
+            return None;
+        }
+        self.data.get_mut(row * M + col)
+    }
+
+    /// Returns iterator over elements, row by row
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::Matrix;
+    /// let m = Matrix::from_rows([[1, 2], [3, 4]]);
+    /// assert_eq!(m.iter().sum::<i32>(), 10);
+    /// ```
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.data.iter()
+    }
+
+    /// Returns iterator over mutable elements, row by row
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::Matrix;
+    /// let mut m = Matrix::from_rows([[1, 2], [3, 4]]);
+    /// m.iter_mut().for_each(|x| *x *= *x);
+    /// assert_eq!(m, Matrix::from_rows([[1, 4], [9, 16]]));
+    /// ```
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.data.iter_mut()
+    }
+
+    /// Returns row as slice. Panics if row is out of matrix
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::Matrix;
+    /// let m = Matrix::from_rows([[1, 2], [3, 4]]);
+    /// assert_eq!(m.row(1), &[3, 4]);
+    /// ```
+    pub fn row(&self, row: usize) -> &[T] {
+        if row >= N {
+            panic!("try to get row {} from matrix<{}, {}>", row, N, M)
+        }
+        &self.data[row * M..(row + 1) * M]
+    }
+
+    /// Returns iterator over elements of column. Panics if col is out of matrix
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::Matrix;
+    /// let m = Matrix::from_rows([[1, 2], [3, 4]]);
+    /// assert_eq!(m.col(1).sum::<i32>(), 6);
+    /// ```
+    pub fn col(&self, col: usize) -> impl Iterator<Item = &T> {
+        if col >= M {
+            panic!("try to get col {} from matrix<{}, {}>", col, N, M)
+        }
+        (0..N).map(move |row| &self.data[row * M + col])
+    }
+
+    /// Returns iterator over rows
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::Matrix;
+    /// let m = Matrix::from_rows([[1, 2], [3, 4]]);
+    /// let sums: Vec<i32> = m.rows().map(|row| row.iter().sum()).collect();
+    /// assert_eq!(sums, vec![3, 7]);
+    /// ```
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..N).map(move |row| self.row(row))
+    }
+
+    /// Returns iterator over columns, each of them is iterator over elements
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::Matrix;
+    /// let m = Matrix::from_rows([[1, 2], [3, 4]]);
+    /// let sums: Vec<i32> = m.cols().map(|col| col.sum()).collect();
+    /// assert_eq!(sums, vec![4, 6]);
+    /// ```
+    pub fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..M).map(move |col| self.col(col))
+    }
+
+    /// Returns matrix, where f is applied to each element
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::{Matrix, Zn};
+    /// let m = Matrix::from_rows([[1, -2], [8, 4]]);
+    /// let z = m.map(Zn::<5>::from);
+    /// assert_eq!(z, Matrix::from_rows([[1, 3], [3, 4]].map(|row| row.map(Zn::new))));
+    /// ```
+    pub fn map<V, F>(self, f: F) -> Matrix<N, M, V>
+    where
+        F: FnMut(T) -> V,
+    {
+        Matrix::<N, M, V> {
+            data: self.data.into_iter().map(f).collect(),
+        }
+    }
+
+    /// Returns matrix, where each element is multiplied by scalar k.
+    /// Scalar can be of any type, that elements can be multiplied by,
+    /// while `*` accepts only scalars of element type.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::{Matrix, Zn};
+    /// let m = Matrix::from_rows([[Zn::<5>::new(1), Zn::new(2)]]);
+    /// assert_eq!(m.scale(3), Matrix::from_rows([[Zn::new(3), Zn::new(1)]]));
+    /// ```
+    pub fn scale<A>(mut self, k: A) -> Matrix<N, M, T>
+    where
+        A: Clone,
+        T: MulAssign<A>,
+    {
+        for elem in self.data.iter_mut() {
+            *elem *= k.clone();
+        }
+        self
+    }
+
+    /// Returns matrix, where element [i, j] is f(self[i, j], other[i, j])
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::Matrix;
+    /// let a = Matrix::from_rows([[1, 5], [3, 0]]);
+    /// let b = Matrix::from_rows([[4, 2], [3, -1]]);
+    /// assert_eq!(a.zip_with(b, i32::max), Matrix::from_rows([[4, 5], [3, 0]]));
+    /// ```
+    pub fn zip_with<U, V, F>(self, other: Matrix<N, M, U>, mut f: F) -> Matrix<N, M, V>
+    where
+        F: FnMut(T, U) -> V,
+    {
+        Matrix::<N, M, V> {
+            data: self
+                .data
+                .into_iter()
+                .zip(other.data)
+                .map(|(lhs, rhs)| f(lhs, rhs))
+                .collect(),
+        }
+    }
+
+    /// Returns elementwise (Hadamard) product
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::Matrix;
+    /// let a = Matrix::from_rows([[1, 2], [3, 4]]);
+    /// let b = Matrix::from_rows([[5, 6], [7, 8]]);
+    /// assert_eq!(a.hadamard(b), Matrix::from_rows([[5, 12], [21, 32]]));
+    /// ```
+    pub fn hadamard(self, rhs: Matrix<N, M, T>) -> Matrix<N, M, T>
+    where
+        T: Mul<T, Output = T>,
+    {
+        self.zip_with(rhs, |lhs, rhs| lhs * rhs)
+    }
+
+    /// Returns matrix<N, L> = [self | rhs], where L = M + K.
+    /// Wrong L fails to compile.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::Matrix;
+    /// let a = Matrix::from_rows([[1], [2]]);
+    /// let b = Matrix::from_rows([[3, 4], [5, 6]]);
+    /// let c: Matrix<2, 3, i32> = a.hconcat(b);
+    /// assert_eq!(c, Matrix::from_rows([[1, 3, 4], [2, 5, 6]]));
+    /// ```
+    ///
+    /// ```compile_fail
+    /// # use polylib::custom_types::Matrix;
+    /// let a = Matrix::from_rows([[1], [2]]);
+    /// let c: Matrix<2, 3, i32> = a.clone().hconcat(a);
+    /// ```
+    pub fn hconcat<const K: usize, const L: usize>(self, rhs: Matrix<N, K, T>) -> Matrix<N, L, T> {
+        const { assert!(L == M + K, "hconcat: result width must be sum of widths") };
+        let mut lhs = self.data.into_iter();
+        let mut rhs = rhs.data.into_iter();
+        let mut data = Vec::with_capacity(N * L);
+        for _ in 0..N {
+            data.extend(lhs.by_ref().take(M));
+            data.extend(rhs.by_ref().take(K));
+        }
+        Matrix::<N, L, T> { data }
+    }
+
+    /// Returns matrix<L, M> = [self / rhs] (self above rhs), where L = N + K.
+    /// Wrong L fails to compile.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::Matrix;
+    /// let a = Matrix::from_rows([[1, 2]]);
+    /// let b = Matrix::from_rows([[3, 4], [5, 6]]);
+    /// let c: Matrix<3, 2, i32> = a.vconcat(b);
+    /// assert_eq!(c, Matrix::from_rows([[1, 2], [3, 4], [5, 6]]));
+    /// ```
+    pub fn vconcat<const K: usize, const L: usize>(self, rhs: Matrix<K, M, T>) -> Matrix<L, M, T> {
+        const { assert!(L == N + K, "vconcat: result height must be sum of heights") };
+        let mut data = self.data;
+        data.extend(rhs.data);
+        Matrix::<L, M, T> { data }
+    }
+
+    /// Returns submatrix<R, C>, which top left element is [row, col].
+    /// Panics if submatrix is out of matrix.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::Matrix;
+    /// let m = Matrix::from_rows([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    /// assert_eq!(m.block::<2, 2>(1, 1), Matrix::from_rows([[5, 6], [8, 9]]));
+    /// ```
+    pub fn block<const R: usize, const C: usize>(&self, row: usize, col: usize) -> Matrix<R, C, T>
+    where
+        T: Clone,
+    {
+        if row + R > N || col + C > M {
+            panic!(
+                "block<{}, {}> at [{}, {}] is out of matrix<{}, {}>",
+                R, C, row, col, N, M
+            )
+        }
+        Matrix::<R, C, T>::from_fn(|i, j| self[(row + i, col + j)].clone())
+    }
+
+    /// Returns matrix<R, C> without given row and column, where R = N - 1 and C = M - 1.
+    /// Wrong R or C fails to compile. Panics if row or col is out of matrix.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::Matrix;
+    /// let m = Matrix::from_rows([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    /// let minor: Matrix<2, 2, i32> = m.minor(1, 0);
+    /// assert_eq!(minor, Matrix::from_rows([[2, 3], [8, 9]]));
+    /// ```
+    pub fn minor<const R: usize, const C: usize>(&self, row: usize, col: usize) -> Matrix<R, C, T>
+    where
+        T: Clone,
+    {
+        const { assert!(R + 1 == N && C + 1 == M, "minor: size must be one less") };
+        if row >= N || col >= M {
+            panic!("try to remove [{}, {}] from matrix<{}, {}>", row, col, N, M)
+        }
+        Matrix::<R, C, T>::from_fn(|i, j| {
+            let i = if i < row { i } else { i + 1 };
+            let j = if j < col { j } else { j + 1 };
+            self[(i, j)].clone()
+        })
+    }
+
+    /// Returns transposed matrix<M, N>
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::Matrix;
+    /// let m = Matrix::<2, 3, i32>::from_data(vec![1, 2, 3, 4, 5, 6]);
+    /// let t = m.transpose(); // is matrix [[1, 4], [2, 5], [3, 6]]
+    /// assert_eq!(t.get_data(), &vec![1, 4, 2, 5, 3, 6]);
+    /// ```
+    pub fn transpose(self) -> Matrix<M, N, T> {
+        // elements are moved, so T is not required to be Clone
+        let mut cells: Vec<Option<T>> = self.data.into_iter().map(Some).collect();
+        let data = (0..M * N)
+            .map(|k| {
+                let (row, col) = (k / N, k % N);
+                cells[col * M + row].take().expect("each cell is taken once")
+            })
+            .collect();
+        Matrix::<M, N, T> { data }
+    }
+}
+
+// coefs of det(λI - A) from λ^n down to λ^0, where A is n x n matrix in row-major data.
+// Berkowitz algorithm: O(n^4) and no division, so it works over any ring.
+pub(crate) fn charpoly_coefs<T: CoefRing>(data: &[T], n: usize) -> Vec<T> {
+    let at = |i: usize, j: usize| data[i * n + j].clone();
+    let mut poly = vec![T::one()];
+    for k in 0..n {
+        // A[k, k], row k and column k, cut by leading k x k submatrix A_k
+        let mut toeplitz = vec![T::one(), -at(k, k)];
+        let mut col: Vec<T> = (0..k).map(|i| at(i, k)).collect();
+        for _ in 0..k {
+            // -row * A_k^j * col
+            let row_dot = |i: usize| {
+                (0..k).fold(T::zero(), |acc, j| acc + at(i, j) * col[j].clone())
+            };
+            toeplitz.push(-row_dot(k));
+            col = (0..k).map(row_dot).collect();
+        }
+        poly = (0..=k + 1)
+            .map(|i| {
+                (0..=i.min(k)).fold(T::zero(), |acc, j| {
+                    acc + toeplitz[i - j].clone() * poly[j].clone()
+                })
+            })
+            .collect();
+    }
+    poly
+}
+
+impl<const N: usize, T: CoefRing> Matrix<N, N, T> {
+    fn charpoly_coefs(&self) -> Vec<T> {
+        charpoly_coefs(&self.data, N)
+    }
+
+    /// Returns characteristic polynomial det(xI - self).
+    /// It does not use division, so T may be any ring.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::Matrix;
+    /// let m = Matrix::<2, 2, i32>::from_data(vec![1, 2, 3, 4]);
+    /// let p = m.characteristic_polynomial(); // x^2 - 5x - 2
+    /// assert_eq!((p.coef(0), p.coef(1), p.coef(2)), (-2, -5, 1));
+    /// ```
+    pub fn characteristic_polynomial(&self) -> Polynomial<T> {
+        let mut coefs = self.charpoly_coefs();
+        coefs.reverse();
+        Polynomial::from_coefs(coefs)
+    }
+
+    /// Returns determinant. It does not use division, so T may be any ring.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::Matrix;
+    /// let m = Matrix::<2, 2, i32>::from_data(vec![1, 2, 3, 4]);
+    /// assert_eq!(m.determinant(), -2);
+    /// ```
+    pub fn determinant(&self) -> T {
+        let c0 = self.charpoly_coefs().pop().expect("charpoly is not empty");
+        if N.is_multiple_of(2) {
+            c0
+        } else {
+            -c0
+        }
+    }
+
+    /// Returns adjugate matrix (transposed matrix of cofactors),
+    /// so self * self.adjugate() is determinant on diagonal.
+    /// It does not use division, so T may be any ring.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::Matrix;
+    /// let m = Matrix::<2, 2, i32>::from_data(vec![1, 2, 3, 4]);
+    /// assert_eq!(m.adjugate().get_data(), &vec![4, -2, -3, 1]);
+    /// ```
+    pub fn adjugate(&self) -> Matrix<N, N, T> {
+        // Cayley–Hamilton: self * (self^(N-1) + c[1] self^(N-2) + ... + c[N-1]) = -c[N]
+        let coefs = self.charpoly_coefs();
+        let mut ans = Self::eye(T::one());
+        for coef in &coefs[1..N.max(1)] {
+            let mut next = Self::full(T::zero());
+            for i in 0..N {
+                for j in 0..N {
+                    next[(i, j)] = (0..N).fold(T::zero(), |acc, k| {
+                        acc + ans[(i, k)].clone() * self[(k, j)].clone()
+                    });
+                }
+                next[(i, i)] = next[(i, i)].clone() + coef.clone();
+            }
+            ans = next;
+        }
+        if N.is_multiple_of(2) {
+            -ans
+        } else {
+            ans
+        }
+    }
+}
+
+impl<const N: usize, T: CoefField> Matrix<N, N, T> {
+    /// Returns inverse matrix or `None`, if matrix is singular.
+    /// Uses Gauss–Jordan elimination, so T should be a field.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::Matrix;
+    /// let m = Matrix::<2, 2, f64>::from_data(vec![2.0, 1.0, 1.0, 1.0]);
+    /// assert_eq!(m.inverse().unwrap().get_data(), &vec![1.0, -1.0, -1.0, 2.0]);
+    ///
+    /// let m = Matrix::<2, 2, f64>::from_data(vec![1.0, 2.0, 2.0, 4.0]);
+    /// assert!(m.inverse().is_none());
+    /// ```
+    pub fn inverse(&self) -> Option<Matrix<N, N, T>> {
+        let mut lhs = self.clone();
+        let mut ans = Self::eye(T::one());
+        for col in 0..N {
+            let pivot = (col..N).find(|&row| !lhs[(row, col)].is_zero())?;
+            for j in 0..N {
+                lhs.data.swap(pivot * N + j, col * N + j);
+                ans.data.swap(pivot * N + j, col * N + j);
+            }
+            let value = lhs[(col, col)].clone();
+            for j in 0..N {
+                lhs[(col, j)] = lhs[(col, j)].clone() / value.clone();
+                ans[(col, j)] = ans[(col, j)].clone() / value.clone();
+            }
+            for row in 0..N {
+                if row == col || lhs[(row, col)].is_zero() {
+                    continue;
+                }
+                let factor = lhs[(row, col)].clone();
+                for j in 0..N {
+                    lhs[(row, j)] = lhs[(row, j)].clone() - factor.clone() * lhs[(col, j)].clone();
+                    ans[(row, j)] = ans[(row, j)].clone() - factor.clone() * ans[(col, j)].clone();
+                }
+            }
+        }
+        Some(ans)
+    }
+}
+
+impl<const N: usize, T> Matrix<N, N, T>
+where
+    T: Clone + Zero + One,
+    T: Mul<T, Output = T>,
+    T: AddAssign<T>,
+{
+    /// Returns self in power exp. Uses square-and-multiply,
+    /// so it takes O(log(exp)) matrix multiplications.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::Matrix;
+    /// let fib = Matrix::<2, 2, u64>::from_data(vec![1, 1, 1, 0]);
+    /// assert_eq!(fib.pow(10)[(0, 1)], 55);
+    /// ```
+    pub fn pow(&self, mut exp: u64) -> Matrix<N, N, T> {
+        let mut ans = Self::eye(T::one());
+        let mut base = self.clone();
+        while exp > 0 {
+            if exp & 1 == 1 {
+                ans *= base.clone();
+            }
+            exp >>= 1;
+            // last square is not needed and may overflow
+            if exp > 0 {
+                base = base.clone() * base;
+            }
+        }
+        ans
+    }
+}
+
+impl<const N: usize> Matrix<N, N, f64> {
+    /// Returns matrix exponential e^self.
+    ///
+    /// Matrix is scaled by 2^s, so its norm is at most 1/2, then first `terms` members
+    /// of Taylor series are summed, and result is squared s times.
+    /// About 15 terms give full f64 precision.
+    ///
+    /// If any entry is NaN or infinite (or norm overflows f64),
+    /// returns matrix filled with NaN.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::Matrix;
+    /// // e^(t * [[0, 1], [-1, 0]]) is rotation by t
+    /// let t = 2.0;
+    /// let m = Matrix::<2, 2, f64>::from_data(vec![0.0, t, -t, 0.0]);
+    /// let e = m.exp(15);
+    /// assert!((e[(0, 0)] - t.cos()).abs() < 1e-12);
+    /// assert!((e[(0, 1)] - t.sin()).abs() < 1e-12);
+    /// ```
+    pub fn exp(&self, terms: usize) -> Matrix<N, N, f64> {
+        // max of row sums of abs values
+        let mut norm = (0..N)
+            .map(|i| (0..N).map(|j| self[(i, j)].abs()).sum::<f64>())
+            .fold(0.0, f64::max);
+        if !norm.is_finite() || self.data.iter().any(|x| x.is_nan()) {
+            return Matrix::from_fn(|_, _| f64::NAN);
+        }
+        // finite norm is less than 2^1024, so it is halved at most 1025 times
+        const MAX_SQUARES: i32 = 1025;
+        let mut squares = 0;
+        while norm > 0.5 && squares < MAX_SQUARES {
+            norm /= 2.0;
+            squares += 1;
+        }
+        let scaled = self.clone() * (0.5_f64).powi(squares);
+
+        // 1 + x + x^2 / 2! + ...
+        let mut coefs = Vec::with_capacity(terms);
+        let mut coef = 1.0;
+        for k in 0..terms {
+            coefs.push(coef);
+            coef /= (k + 1) as f64;
+        }
+        let taylor = Polynomial::<f64>::from_coefs(coefs);
+        let mut ans: Matrix<N, N, f64> = taylor.rsubstitude(scaled);
+        for _ in 0..squares {
+            ans = ans.clone() * ans;
+        }
+        ans
+    }
+
+    /// Returns all N eigenvalues as pairs (re, im), sorted by re, then by im.
+    ///
+    /// They are roots of characteristic polynomial (see `Polynomial::complex_roots`).
+    /// Simple eigenvalues are precise, multiple ones lose about half of digits or more.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::Matrix;
+    /// // rotation by 90 degrees has eigenvalues -i and i
+    /// let m = Matrix::<2, 2, f64>::from_data(vec![0.0, -1.0, 1.0, 0.0]);
+    /// let values = m.eigenvalues();
+    /// assert!(values[0].0.abs() < 1e-12 && (values[0].1 + 1.0).abs() < 1e-12);
+    /// assert!(values[1].0.abs() < 1e-12 && (values[1].1 - 1.0).abs() < 1e-12);
+    /// ```
+    pub fn eigenvalues(&self) -> Vec<(f64, f64)> {
+        // monic, coefs from x^0 to x^N
+        let mut coefs = self.charpoly_coefs();
+        coefs.reverse();
+        Polynomial::<f64>::from_coefs(coefs).complex_roots()
+    }
 }
 
 impl<const N: usize, T> One for Matrix<N, N, T> 
@@ -98,7 +745,16 @@ where
     }
 
     fn is_one(&self) -> bool {
-        self.data == Self::one().data
+        (0..N).all(|i| {
+            (0..N).all(|j| {
+                let value = &self[(i, j)];
+                if i == j {
+                    value.is_one()
+                } else {
+                    value.is_zero()
+                }
+            })
+        })
     }
 }
 
@@ -111,10 +767,60 @@ where
     }
 
     fn is_zero(&self) -> bool {
-        self.data == Self::zero().data
+        self.data.iter().all(T::is_zero)
     }
 }
 
+/// Matrix is written by rows, as `[[1, 2], [3, 4]]`.
+/// Precision of formatter is applied to every element.
+///
+/// Example:
+/// ```
+/// # use polylib::custom_types::Matrix;
+/// # use polylib::polynom::Polynomial;
+/// let m = Matrix::<2, 2, f64>::from_data(vec![1.0, 0.5, 0.0, 2.0]);
+/// assert_eq!(format!("{:.1}", m), "[[1.0, 0.5], [0.0, 2.0]]");
+/// type M = Matrix<2, 2, i32>;
+/// let p = Polynomial::<M>::from_coefs(vec![M::eye(2), M::eye(1)]);
+/// assert_eq!(p.to_string(), "[[2, 0], [0, 2]] + x");
+/// ```
+impl<const N: usize, const M: usize, T: Display> Display for Matrix<N, M, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[")?;
+        for i in 0..N {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "[")?;
+            for j in 0..M {
+                if j > 0 {
+                    write!(f, ", ")?;
+                }
+                match f.precision() {
+                    Some(precision) => write!(f, "{:.*}", precision, self[(i, j)])?,
+                    None => write!(f, "{}", self[(i, j)])?,
+                }
+            }
+            write!(f, "]")?;
+        }
+        write!(f, "]")
+    }
+}
+
+
+impl<const N: usize, const M: usize, T> From<[[T; M]; N]> for Matrix<N, M, T> {
+    fn from(rows: [[T; M]; N]) -> Self {
+        Self::from_rows(rows)
+    }
+}
+
+impl<const N: usize, const M: usize, T: Random> Random for Matrix<N, M, T> {
+    fn random(rng: &mut Xorshift64) -> Self {
+        Matrix::<N, M, T> {
+            data: (0..N * M).map(|_| T::random(rng)).collect(),
+        }
+    }
+}
 
 impl<const N: usize, const M: usize, T> Index<(usize, usize)> for Matrix<N, M, T> {
     type Output = T;
@@ -241,42 +947,88 @@ where
     }
 }
 
-impl<const N: usize, const M: usize, T, A> MulAssign<A> for Matrix<N, M, T>
+impl<const N: usize, const M: usize, T> MulAssign<T> for Matrix<N, M, T>
 where
-    A: From<u8> + Clone,
-    T: MulAssign<A>,
+    T: MulAssign<T> + Clone,
 {
-    fn mul_assign(&mut self, rhs: A) {
-        for i in 0..N {
-            for j in 0..M {
-                self[(i, j)] *= rhs.clone();
-            }
+    fn mul_assign(&mut self, rhs: T) {
+        for elem in self.data.iter_mut() {
+            *elem *= rhs.clone();
         }
     }
 }
 
-impl<const N: usize, const M: usize, T, A> Mul<A> for Matrix<N, M, T>
+impl<const N: usize, const M: usize, T> Mul<T> for Matrix<N, M, T>
 where
-    A: From<u8> + Clone,
-    T: MulAssign<A>,
+    T: MulAssign<T> + Clone,
 {
     type Output = Matrix<N, M, T>;
 
-    fn mul(mut self, rhs: A) -> Self::Output {
+    fn mul(mut self, rhs: T) -> Self::Output {
         self *= rhs;
         self
     }
 }
 
+impl<const N: usize, const M: usize, T> DivAssign<T> for Matrix<N, M, T>
+where
+    T: DivAssign<T> + Clone,
+{
+    fn div_assign(&mut self, rhs: T) {
+        for elem in self.data.iter_mut() {
+            *elem /= rhs.clone();
+        }
+    }
+}
+
+impl<const N: usize, const M: usize, T> Div<T> for Matrix<N, M, T>
+where
+    T: DivAssign<T> + Clone,
+{
+    type Output = Matrix<N, M, T>;
+
+    fn div(mut self, rhs: T) -> Self::Output {
+        self /= rhs;
+        self
+    }
+}
+
+// scalar * matrix of same type
+macro_rules! impl_left_scalar_mul {
+    ($($t:ty),*) => {
+        $(
+            impl<const N: usize, const M: usize> Mul<Matrix<N, M, $t>> for $t {
+                type Output = Matrix<N, M, $t>;
+
+                fn mul(self, rhs: Matrix<N, M, $t>) -> Self::Output {
+                    rhs.scale(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_left_scalar_mul!(i8, i16, i64, i128, isize);
+impl_left_scalar_mul!(u8, u16, u32, u64, u128, usize);
+impl_left_scalar_mul!(f32, f64);
+
+// i32 scales any elems, so polynomials with i32 coefs accept any matrix points
 impl<const N: usize, const M: usize, T> Mul<Matrix<N, M, T>> for i32
 where
     T: MulAssign<i32>,
 {
     type Output = Matrix<N, M, T>;
 
-    fn mul(self, mut rhs: Matrix<N, M, T>) -> Self::Output {
-        rhs *= self;
-        rhs
+    fn mul(self, rhs: Matrix<N, M, T>) -> Self::Output {
+        rhs.scale(self)
+    }
+}
+
+impl<const N: usize, const M: usize, const P: u32> Mul<Matrix<N, M, Zn<P>>> for Zn<P> {
+    type Output = Matrix<N, M, Zn<P>>;
+
+    fn mul(self, rhs: Matrix<N, M, Zn<P>>) -> Self::Output {
+        rhs.scale(self)
     }
 }
 
@@ -284,9 +1036,10 @@ where
 mod matrix_test {
     use std::panic;
 
-    use crate::{One, Zero};
+    use crate::{One, PolyError, Zero};
 
     use super::Matrix;
+    use crate::custom_types::Zn;
 
     type M12 = Matrix<1, 2, i32>;
     type M21 = Matrix<2, 1, i32>;
@@ -347,6 +1100,130 @@ mod matrix_test {
         }).expect_err("incorrect size of m");
     }
 
+    #[test]
+    fn test_from_rows() {
+        let m = M22::from_rows([[1, 2], [3, 4]]);
+        assert_eq!(m.data, vec![1, 2, 3, 4]);
+
+        let m: M12 = [[5, 6]].into();
+        assert_eq!(m.data, vec![5, 6]);
+
+        let m = M21::from([[5], [6]]);
+        assert_eq!(m.data, vec![5, 6]);
+    }
+
+    #[test]
+    fn test_from_fn() {
+        let m = Matrix::<3, 2, i32>::from_fn(|i, j| i as i32 - j as i32);
+        assert_eq!(m.data, vec![0, -1, 1, 0, 2, 1]);
+
+        let mut count = 0;
+        let m = M22::from_fn(|_, _| {
+            count += 1;
+            count
+        });
+        assert_eq!(m.data, vec![1, 2, 3, 4]);
+        assert_eq!(Matrix::<3, 3, i32>::from_fn(|i, j| (i == j) as i32), Matrix::one());
+    }
+
+    #[test]
+    fn test_iterators() {
+        let mut m = Matrix::<2, 3, i32>::from_rows([[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(m.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(m.row(0), &[1, 2, 3]);
+        assert_eq!(m.col(2).copied().collect::<Vec<_>>(), vec![3, 6]);
+        assert_eq!(m.rows().count(), 2);
+        assert_eq!(m.cols().count(), 3);
+        let cols: Vec<Vec<i32>> = m.cols().map(|col| col.copied().collect()).collect();
+        assert_eq!(cols, vec![vec![1, 4], vec![2, 5], vec![3, 6]]);
+
+        for x in m.iter_mut() {
+            *x = -*x;
+        }
+        assert_eq!(m.data, vec![-1, -2, -3, -4, -5, -6]);
+
+        panic::catch_unwind(|| {
+            let m = M22::from_data(vec![1, 2, 3, 4]);
+            let _ = m.row(2);
+        }).expect_err("row out of bounds");
+
+        panic::catch_unwind(|| {
+            let m = M22::from_data(vec![1, 2, 3, 4]);
+            let _ = m.col(2).count();
+        }).expect_err("col out of bounds");
+
+        // empty rows are still rows
+        let m = Matrix::<2, 0, i32>::from_data(vec![]);
+        assert_eq!(m.rows().count(), 2);
+        assert_eq!(m.cols().count(), 0);
+    }
+
+    #[test]
+    fn test_map_zip() {
+        let m = M22::from_data(vec![1, 2, 3, 4]);
+        let f = m.clone().map(|x| x as f64 / 2.0);
+        assert_eq!(f.data, vec![0.5, 1.0, 1.5, 2.0]);
+
+        let strings = m.clone().map(|x| x.to_string());
+        assert_eq!(strings.data, vec!["1", "2", "3", "4"]);
+
+        let pairs = m.clone().zip_with(strings, |a, b| format!("{}{}", a, b));
+        assert_eq!(pairs.data, vec!["11", "22", "33", "44"]);
+
+        let b = M22::from_data(vec![-1, 0, 1, 2]);
+        assert_eq!(m.zip_with(b, |x, y| x * y).data, vec![-1, 0, 3, 8]);
+    }
+
+    #[test]
+    fn test_try_from_data() {
+        let m = M22::try_from_data(vec![1, 2, 3, 4]);
+        assert_eq!(m.map(|m| m.data), Ok(vec![1, 2, 3, 4]));
+
+        let m = M12::try_from_data(vec![1, 2, 3]);
+        assert_eq!(m, Err(PolyError::DimensionMismatch { rows: 1, cols: 2, len: 3 }));
+
+        let m = M21::try_from_data(vec![]);
+        assert_eq!(m, Err(PolyError::DimensionMismatch { rows: 2, cols: 1, len: 0 }));
+    }
+
+    #[test]
+    fn test_try_set_data() {
+        let mut m = M22::from_data(vec![1, 2, 3, 4]);
+        assert_eq!(m.try_set_data(vec![4, 3, 2, 1]), Ok(()));
+        assert_eq!(m.data, vec![4, 3, 2, 1]);
+
+        let err = m.try_set_data(vec![1, 2]);
+        assert_eq!(err, Err(PolyError::DimensionMismatch { rows: 2, cols: 2, len: 2 }));
+        assert_eq!(m.data, vec![4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn test_try_get() {
+        let m = M21::from_data(vec![1, 2]);
+        assert_eq!(m.try_get(0, 0), Ok(&1));
+        assert_eq!(m.try_get(1, 0), Ok(&2));
+        assert_eq!(
+            m.try_get(0, 1),
+            Err(PolyError::IndexOutOfBounds { row: 0, col: 1, rows: 2, cols: 1 })
+        );
+        assert_eq!(
+            m.try_get(2, 0),
+            Err(PolyError::IndexOutOfBounds { row: 2, col: 0, rows: 2, cols: 1 })
+        );
+    }
+
+    #[test]
+    fn test_get() {
+        let mut m = M12::from_data(vec![1, 2]);
+        assert_eq!(m.get(0, 1), Some(&2));
+        assert_eq!(m.get(1, 0), None);
+        assert_eq!(m.get(0, 2), None);
+
+        *m.get_mut(0, 0).unwrap() = 7;
+        assert_eq!(m.data, vec![7, 2]);
+        assert!(m.get_mut(1, 1).is_none());
+    }
+
     #[test]
     fn test_get_data() {
         let m = M22::from_data(vec![1, 2, 3, 4]);
@@ -579,6 +1456,226 @@ mod matrix_test {
         assert_eq!(a.data, vec![-3, -6]);
     }
 
+    #[test]
+    fn test_left_scalar_mul() {
+        assert_eq!((2 * M12::from_data(vec![1, -2])).data, vec![2, -4]);
+        let a = Matrix::<1, 2, u64>::from_data(vec![1, 2]);
+        assert_eq!((3u64 * a).data, vec![3, 6]);
+        let a = Matrix::<1, 2, f64>::from_data(vec![1.0, 2.0]);
+        assert_eq!((0.5 * a).data, vec![0.5, 1.0]);
+
+        type Z = Zn<7>;
+        let a = Matrix::<1, 2, Z>::from_data(vec![Z::new(1), Z::new(3)]);
+        assert_eq!((Z::new(3) * a.clone()).data, vec![Z::new(3), Z::new(2)]);
+        assert_eq!((a.clone() * Z::new(3)).data, vec![Z::new(3), Z::new(2)]);
+        // i32 scales any elems
+        assert_eq!((3 * a.clone()).data, vec![Z::new(3), Z::new(2)]);
+        assert_eq!(a.scale(3).data, vec![Z::new(3), Z::new(2)]);
+    }
+
+    #[test]
+    fn test_transpose() {
+        let a = M22::from_data(vec![1, 2, 3, 4]);
+        assert_eq!(a.transpose().data, vec![1, 3, 2, 4]);
+
+        let a = M12::from_data(vec![1, 2]);
+        let t: M21 = a.clone().transpose();
+        assert_eq!(t.data, vec![1, 2]);
+        assert_eq!(t.transpose(), a);
+
+        let a = Matrix::<2, 3, i32>::from_data(vec![1, 2, 3, 4, 5, 6]);
+        let b = a.clone() * a.clone().transpose();
+        assert_eq!(b.data, vec![14, 32, 32, 77]);
+        assert_eq!(b.clone().transpose(), b);
+    }
+
+    #[test]
+    fn test_determinant() {
+        assert_eq!(M22::from_data(vec![1, 2, 3, 4]).determinant(), -2);
+        assert_eq!(Matrix::<1, 1, i32>::from_data(vec![-7]).determinant(), -7);
+        assert_eq!(Matrix::<0, 0, i32>::from_data(vec![]).determinant(), 1);
+
+        let m = Matrix::<3, 3, i32>::from_data(vec![2, -1, 0, 1, 3, 4, 0, 5, -2]);
+        assert_eq!(m.determinant(), -54);
+
+        let m = Matrix::<4, 4, i64>::from_data(vec![
+            1, 2, 0, 1, 0, 1, 3, 0, 2, 0, 1, 1, 1, 1, 1, 2,
+        ]);
+        assert_eq!(m.determinant(), 17);
+    }
+
+    #[test]
+    fn test_adjugate() {
+        let m = Matrix::<3, 3, i32>::from_data(vec![2, -1, 0, 1, 3, 4, 0, 5, -2]);
+        let adj = m.adjugate();
+        assert_eq!(adj.data, vec![-26, -2, -4, 2, -4, -8, 5, -10, 7]);
+        assert_eq!(m * adj, Matrix::<3, 3, i32>::eye(-54));
+
+        assert_eq!(Matrix::<1, 1, i32>::from_data(vec![5]).adjugate().data, vec![1]);
+
+        // singular matrices have adjugate too
+        let m = M22::from_data(vec![1, 2, 2, 4]);
+        assert_eq!(m.adjugate().data, vec![4, -2, -2, 1]);
+    }
+
+    #[test]
+    fn test_inverse() {
+        use crate::custom_types::Fraction;
+        type Q = Fraction<i64>;
+        let q = |v: i64| Q::from(v);
+
+        let m = Matrix::<3, 3, Q>::from_data(
+            [0, 1, 2, 1, 0, 3, 4, -3, 8].into_iter().map(q).collect(),
+        );
+        let inv = m.inverse().expect("matrix is not singular");
+        assert_eq!(m.clone() * inv.clone(), Matrix::<3, 3, Q>::one());
+        assert_eq!(inv * m, Matrix::<3, 3, Q>::one());
+
+        let m = Matrix::<2, 2, Q>::from_data(vec![q(1), q(2), q(2), q(4)]);
+        assert_eq!(m.inverse(), None);
+    }
+
+    #[test]
+    fn test_characteristic_polynomial() {
+        let m = Matrix::<3, 3, i32>::from_data(vec![2, -1, 0, 1, 3, 4, 0, 5, -2]);
+        let p = m.characteristic_polynomial();
+        // trace is 3, determinant is -54
+        assert_eq!((p.coef(3), p.coef(2), p.coef(0)), (1, -3, 54));
+        // Cayley–Hamilton
+        let value: Matrix<3, 3, i32> = p.rsubstitude(m);
+        assert_eq!(value, Matrix::zero());
+    }
+
+    #[test]
+    fn test_eigenvalues() {
+        let close = |a: (f64, f64), b: (f64, f64), eps: f64| {
+            (a.0 - b.0).abs() < eps && (a.1 - b.1).abs() < eps
+        };
+
+        let m = Matrix::<2, 2, f64>::from_data(vec![2.0, 1.0, 1.0, 2.0]);
+        let values = m.eigenvalues();
+        assert!(close(values[0], (1.0, 0.0), 1e-12));
+        assert!(close(values[1], (3.0, 0.0), 1e-12));
+
+        let m = Matrix::<3, 3, f64>::from_data(vec![
+            1.0, 2.0, 0.0, -2.0, 1.0, 0.0, 0.0, 0.0, 5.0,
+        ]);
+        let values = m.eigenvalues();
+        assert!(close(values[0], (1.0, -2.0), 1e-10));
+        assert!(close(values[1], (1.0, 2.0), 1e-10));
+        assert!(close(values[2], (5.0, 0.0), 1e-10));
+
+        let values = Matrix::<3, 3, f64>::eye(2.0).eigenvalues();
+        for value in values {
+            assert!(close(value, (2.0, 0.0), 1e-4));
+        }
+    }
+
+    #[test]
+    fn test_pow() {
+        let a = M22::from_data(vec![1, 1, 1, 0]);
+        assert_eq!(a.pow(0), M22::one());
+        assert_eq!(a.pow(1), a);
+        assert_eq!(a.pow(5).data, vec![8, 5, 5, 3]);
+
+        let x = crate::polynom::X::<i32>::default();
+        assert_eq!(a.pow(13), (x ^ 13).substitude(a.clone()));
+
+        // only 60 squares for huge exp
+        type Z = crate::custom_types::Zn<1_000_000_007>;
+        let a = Matrix::<2, 2, Z>::from_data(vec![Z::new(1), Z::new(1), Z::new(1), Z::new(0)]);
+        let b = a.pow(1 << 60);
+        assert_eq!(b.clone() * a.clone(), a * b);
+    }
+
+    #[test]
+    fn test_exp() {
+        let close = |a: &Matrix<2, 2, f64>, b: Vec<f64>| {
+            a.data.iter().zip(b.iter()).all(|(x, y)| (x - y).abs() < 1e-10 * (1.0 + y.abs()))
+        };
+        let e = std::f64::consts::E;
+
+        let m = Matrix::<2, 2, f64>::zero();
+        assert!(close(&m.exp(15), vec![1.0, 0.0, 0.0, 1.0]));
+
+        let m = Matrix::<2, 2, f64>::from_data(vec![1.0, 0.0, 0.0, -3.0]);
+        assert!(close(&m.exp(15), vec![e, 0.0, 0.0, e.powi(-3)]));
+
+        // nilpotent: e^[[0, 5], [0, 0]] = [[1, 5], [0, 1]]
+        let m = Matrix::<2, 2, f64>::from_data(vec![0.0, 5.0, 0.0, 0.0]);
+        assert!(close(&m.exp(15), vec![1.0, 5.0, 0.0, 1.0]));
+
+        // e^[[a, b], [0, a]] = e^a [[1, b], [0, 1]], with big norm
+        let m = Matrix::<2, 2, f64>::from_data(vec![10.0, 20.0, 0.0, 10.0]);
+        let ea = 10.0_f64.exp();
+        assert!(close(&m.exp(15), vec![ea, 20.0 * ea, 0.0, ea]));
+
+        // non-finite entries give NaN instead of endless scaling
+        for bad in [f64::INFINITY, f64::NEG_INFINITY, f64::NAN] {
+            let m = Matrix::<2, 2, f64>::from_data(vec![1.0, bad, bad, 0.0]);
+            assert!(m.exp(15).data.iter().all(|x| x.is_nan()));
+        }
+    }
+
+    #[test]
+    fn test_hadamard() {
+        let a = M22::from_data(vec![1, 2, 3, 4]);
+        let b = M22::from_data(vec![3, -5, 2, 0]);
+        assert_eq!(a.clone().hadamard(b).data, vec![3, -10, 6, 0]);
+        assert_eq!(a.clone().hadamard(M22::full(1)), a);
+    }
+
+    #[test]
+    fn test_scalar_div() {
+        let a = M22::from_data(vec![2, 4, -6, 7]);
+        assert_eq!((a / 2).data, vec![1, 2, -3, 3]);
+
+        let mut a = Matrix::<1, 2, f64>::from_data(vec![1.0, 3.0]);
+        a /= 4.0;
+        assert_eq!(a.data, vec![0.25, 0.75]);
+    }
+
+    #[test]
+    fn test_concat() {
+        let a = M22::from_rows([[1, 2], [3, 4]]);
+        let b = M21::from_rows([[5], [6]]);
+        let c: Matrix<2, 3, i32> = a.clone().hconcat(b);
+        assert_eq!(c.data, vec![1, 2, 5, 3, 4, 6]);
+
+        let d: Matrix<3, 2, i32> = a.clone().vconcat(M12::from_rows([[7, 8]]));
+        assert_eq!(d.data, vec![1, 2, 3, 4, 7, 8]);
+
+        // block matrix [[A, 0], [0, A]]
+        let top: Matrix<2, 4, i32> = a.clone().hconcat(M22::zero());
+        let bottom: Matrix<2, 4, i32> = M22::zero().hconcat(a.clone());
+        let m: Matrix<4, 4, i32> = top.vconcat(bottom);
+        assert_eq!(m.determinant(), 4);
+        assert_eq!(m.block::<2, 2>(2, 2), a);
+        assert_eq!(m.block::<2, 2>(0, 2), M22::zero());
+    }
+
+    #[test]
+    fn test_block_minor() {
+        let m = Matrix::<3, 3, i32>::from_fn(|i, j| (3 * i + j) as i32);
+        assert_eq!(m.block::<1, 3>(2, 0).data, vec![6, 7, 8]);
+        assert_eq!(m.block::<0, 0>(3, 3).data, vec![]);
+
+        let minor: M22 = m.minor(0, 0);
+        assert_eq!(minor.data, vec![4, 5, 7, 8]);
+        let minor: M22 = m.minor(2, 1);
+        assert_eq!(minor.data, vec![0, 2, 3, 5]);
+
+        panic::catch_unwind(|| {
+            let m = M22::from_data(vec![1, 2, 3, 4]);
+            let _ = m.block::<2, 2>(1, 0);
+        }).expect_err("block out of bounds");
+
+        panic::catch_unwind(|| {
+            let m = M22::from_data(vec![1, 2, 3, 4]);
+            let _: Matrix<1, 1, i32> = m.minor(2, 0);
+        }).expect_err("minor out of bounds");
+    }
+
     #[test]
     fn test_zero() {
         let a = M22::zero();
@@ -0,0 +1,390 @@
+//! Defines LU, QR and Cholesky decompositions of `Matrix`.
+
+use std::cmp::min;
+
+use super::Matrix;
+use crate::CoefField;
+
+/// LU decomposition with partial pivoting: P * A = L * U.
+///
+/// `l` is lower triangular with ones on diagonal, `u` is upper triangular.
+/// Row i of P * A is row `perm[i]` of A.
+///
+/// Example:
+/// ```
+/// # use polylib::custom_types::Matrix;
+/// let a = Matrix::<2, 2, f64>::from_data(vec![1.0, 2.0, 3.0, 4.0]);
+/// let lu = a.lu();
+/// assert_eq!(lu.perm, vec![1, 0]); // 3 is bigger pivot than 1
+/// assert_eq!(lu.l.clone() * lu.u.clone(), Matrix::from_data(vec![3.0, 4.0, 1.0, 2.0]));
+/// assert_eq!(lu.determinant(), -2.0);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Lu<const N: usize, T> {
+    /// Lower triangular factor with unit diagonal.
+    pub l: Matrix<N, N, T>,
+    /// Upper triangular factor.
+    pub u: Matrix<N, N, T>,
+    /// Row permutation.
+    pub perm: Vec<usize>,
+    odd_swaps: bool,
+}
+
+/// QR decomposition: A = Q * R.
+///
+/// `q` is orthogonal, `r` is upper triangular.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Qr<const N: usize, const M: usize> {
+    /// Orthogonal factor.
+    pub q: Matrix<N, N, f64>,
+    /// Upper triangular factor.
+    pub r: Matrix<N, M, f64>,
+}
+
+// |value|, works for any ordered field like f64 or Fraction
+fn magnitude<T: CoefField + PartialOrd>(value: &T) -> T {
+    if *value < T::zero() {
+        -value.clone()
+    } else {
+        value.clone()
+    }
+}
+
+impl<const N: usize, T: CoefField + PartialOrd> Matrix<N, N, T> {
+    /// Returns LU decomposition with partial pivoting.
+    /// Pivot is the element with the biggest magnitude in column,
+    /// so it is stable for f64 and exact for `Fraction`.
+    ///
+    /// Singular matrices are decomposed too, then `u` has zero on diagonal.
+    pub fn lu(&self) -> Lu<N, T> {
+        let mut u = self.clone();
+        let mut l = Self::full(T::zero());
+        let mut perm: Vec<usize> = (0..N).collect();
+        let mut odd_swaps = false;
+        for k in 0..N {
+            let mut pivot = k;
+            for i in k + 1..N {
+                if magnitude(&u[(i, k)]) > magnitude(&u[(pivot, k)]) {
+                    pivot = i;
+                }
+            }
+            if u[(pivot, k)].is_zero() {
+                continue;
+            }
+            if pivot != k {
+                for j in 0..N {
+                    u.data.swap(pivot * N + j, k * N + j);
+                }
+                for j in 0..k {
+                    l.data.swap(pivot * N + j, k * N + j);
+                }
+                perm.swap(pivot, k);
+                odd_swaps = !odd_swaps;
+            }
+            for i in k + 1..N {
+                let factor = u[(i, k)].clone() / u[(k, k)].clone();
+                for j in k + 1..N {
+                    u[(i, j)] = u[(i, j)].clone() - factor.clone() * u[(k, j)].clone();
+                }
+                u[(i, k)] = T::zero();
+                l[(i, k)] = factor;
+            }
+        }
+        for i in 0..N {
+            l[(i, i)] = T::one();
+        }
+        Lu { l, u, perm, odd_swaps }
+    }
+}
+
+impl<const N: usize, T: CoefField + PartialOrd> Lu<N, T> {
+    /// Returns determinant of decomposed matrix.
+    pub fn determinant(&self) -> T {
+        let ans = (0..N).fold(T::one(), |acc, i| acc * self.u[(i, i)].clone());
+        if self.odd_swaps {
+            -ans
+        } else {
+            ans
+        }
+    }
+
+    /// Returns x, such that A * x = b, or `None` if A is singular.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::Matrix;
+    /// # use polylib::custom_types::Fraction;
+    /// type Q = Fraction<i64>;
+    /// let a = Matrix::<2, 2, Q>::from_data(vec![Q::from(2), Q::from(1), Q::from(1), Q::from(3)]);
+    /// let b = Matrix::<2, 1, Q>::from_data(vec![Q::from(1), Q::from(2)]);
+    /// let x = a.lu().solve(&b).unwrap();
+    /// assert_eq!(x.get_data(), &vec![Q::new(1, 5), Q::new(3, 5)]);
+    /// ```
+    pub fn solve(&self, b: &Matrix<N, 1, T>) -> Option<Matrix<N, 1, T>> {
+        if (0..N).any(|i| self.u[(i, i)].is_zero()) {
+            return None;
+        }
+        let mut x = Matrix::<N, 1, T>::full(T::zero());
+        for i in 0..N {
+            let sum = (0..i).fold(T::zero(), |acc, j| {
+                acc + self.l[(i, j)].clone() * x[(j, 0)].clone()
+            });
+            x[(i, 0)] = b[(self.perm[i], 0)].clone() - sum;
+        }
+        for i in (0..N).rev() {
+            let sum = (i + 1..N).fold(T::zero(), |acc, j| {
+                acc + self.u[(i, j)].clone() * x[(j, 0)].clone()
+            });
+            x[(i, 0)] = (x[(i, 0)].clone() - sum) / self.u[(i, i)].clone();
+        }
+        Some(x)
+    }
+}
+
+impl<const N: usize, const M: usize> Matrix<N, M, f64> {
+    /// Returns QR decomposition, made by Householder reflections.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::Matrix;
+    /// let a = Matrix::<2, 2, f64>::from_data(vec![3.0, 1.0, 4.0, 2.0]);
+    /// let qr = a.qr();
+    /// assert!((qr.r[(0, 0)].abs() - 5.0).abs() < 1e-12);
+    /// assert_eq!(qr.r[(1, 0)], 0.0);
+    /// ```
+    pub fn qr(&self) -> Qr<N, M> {
+        let mut r = self.clone();
+        let mut q = Matrix::<N, N, f64>::eye(1.0);
+        for k in 0..min(N.saturating_sub(1), M) {
+            let norm = (k..N).map(|i| r[(i, k)] * r[(i, k)]).sum::<f64>().sqrt();
+            if norm == 0.0 {
+                continue;
+            }
+            // reflect column to alpha * e_k, sign is chosen to avoid cancellation
+            let alpha = if r[(k, k)] > 0.0 { -norm } else { norm };
+            let mut v: Vec<f64> = (k..N).map(|i| r[(i, k)]).collect();
+            v[0] -= alpha;
+            let v_norm2: f64 = v.iter().map(|x| x * x).sum();
+            if v_norm2 == 0.0 {
+                continue;
+            }
+            for j in k..M {
+                let s: f64 = (0..v.len()).map(|t| v[t] * r[(k + t, j)]).sum();
+                for t in 0..v.len() {
+                    r[(k + t, j)] -= 2.0 * s * v[t] / v_norm2;
+                }
+            }
+            for i in 0..N {
+                let s: f64 = (0..v.len()).map(|t| q[(i, k + t)] * v[t]).sum();
+                for t in 0..v.len() {
+                    q[(i, k + t)] -= 2.0 * s * v[t] / v_norm2;
+                }
+            }
+            r[(k, k)] = alpha;
+            for i in k + 1..N {
+                r[(i, k)] = 0.0;
+            }
+        }
+        Qr { q, r }
+    }
+
+    /// Returns (Q, R), such that A = Q * R, made by modified Gram–Schmidt process.
+    ///
+    /// Columns of Q<N, M> are orthonormal and R<M, M> is upper triangular.
+    /// If column of A is (numerically) linear combination of previous ones,
+    /// corresponding column of Q and diagonal element of R are zero.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::Matrix;
+    /// let a = Matrix::<3, 2, f64>::from_data(vec![3.0, 1.0, 0.0, 1.0, 4.0, 2.0]);
+    /// let (q, r) = a.orthonormalize();
+    /// assert_eq!(r[(0, 0)], 5.0);
+    /// assert_eq!(q.col(0).copied().collect::<Vec<_>>(), vec![0.6, 0.0, 0.8]);
+    /// assert_eq!(r[(1, 0)], 0.0);
+    /// ```
+    pub fn orthonormalize(&self) -> (Matrix<N, M, f64>, Matrix<M, M, f64>) {
+        let mut q = self.clone();
+        let mut r = Matrix::<M, M, f64>::full(0.0);
+        for k in 0..M {
+            let source_norm = (0..N).map(|i| self[(i, k)] * self[(i, k)]).sum::<f64>().sqrt();
+            let norm = (0..N).map(|i| q[(i, k)] * q[(i, k)]).sum::<f64>().sqrt();
+            // what is left is rounding error of dependent column
+            if norm <= source_norm * f64::EPSILON * (N as f64) {
+                for i in 0..N {
+                    q[(i, k)] = 0.0;
+                }
+                continue;
+            }
+            r[(k, k)] = norm;
+            for i in 0..N {
+                q[(i, k)] /= norm;
+            }
+            for j in k + 1..M {
+                let dot: f64 = (0..N).map(|i| q[(i, k)] * q[(i, j)]).sum();
+                r[(k, j)] = dot;
+                for i in 0..N {
+                    q[(i, j)] -= dot * q[(i, k)];
+                }
+            }
+        }
+        (q, r)
+    }
+}
+
+impl<const N: usize> Matrix<N, N, f64> {
+    /// Returns lower triangular L, such that A = L * L^T,
+    /// or `None` if matrix is not positive definite.
+    ///
+    /// Only lower triangle of A is read, so A is assumed to be symmetric.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::Matrix;
+    /// let a = Matrix::<2, 2, f64>::from_data(vec![4.0, 2.0, 2.0, 10.0]);
+    /// let l = a.cholesky().unwrap();
+    /// assert_eq!(l.get_data(), &vec![2.0, 0.0, 1.0, 3.0]);
+    /// ```
+    pub fn cholesky(&self) -> Option<Matrix<N, N, f64>> {
+        let mut l = Self::full(0.0);
+        for j in 0..N {
+            let diag = self[(j, j)] - (0..j).map(|k| l[(j, k)] * l[(j, k)]).sum::<f64>();
+            if diag <= 0.0 || diag.is_nan() {
+                return None;
+            }
+            l[(j, j)] = diag.sqrt();
+            for i in j + 1..N {
+                let sum: f64 = (0..j).map(|k| l[(i, k)] * l[(j, k)]).sum();
+                l[(i, j)] = (self[(i, j)] - sum) / l[(j, j)];
+            }
+        }
+        Some(l)
+    }
+}
+
+#[cfg(test)]
+mod decomp_tests {
+    use super::super::Matrix;
+    use crate::custom_types::Fraction;
+    use crate::One;
+
+    fn approx<const N: usize, const M: usize>(
+        a: &Matrix<N, M, f64>,
+        b: &Matrix<N, M, f64>,
+    ) -> bool {
+        a.data.iter().zip(b.data.iter()).all(|(x, y)| (x - y).abs() < 1e-9)
+    }
+
+    fn permuted<const N: usize>(a: &Matrix<N, N, f64>, perm: &[usize]) -> Matrix<N, N, f64> {
+        let mut ans = a.clone();
+        for i in 0..N {
+            for j in 0..N {
+                ans[(i, j)] = a[(perm[i], j)];
+            }
+        }
+        ans
+    }
+
+    #[test]
+    fn test_lu() {
+        let a = Matrix::<3, 3, f64>::from_data(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 10.0]);
+        let lu = a.lu();
+        assert!(approx(&(lu.l.clone() * lu.u.clone()), &permuted(&a, &lu.perm)));
+        for i in 0..3 {
+            assert_eq!(lu.l[(i, i)], 1.0);
+            for j in i + 1..3 {
+                assert_eq!(lu.l[(i, j)], 0.0);
+                assert_eq!(lu.u[(j, i)], 0.0);
+            }
+        }
+        assert!((lu.determinant() + 3.0).abs() < 1e-9);
+
+        let b = Matrix::<3, 1, f64>::from_data(vec![6.0, 15.0, 25.0]);
+        let x = lu.solve(&b).unwrap();
+        assert!(approx(&x, &Matrix::from_data(vec![1.0, 1.0, 1.0])));
+    }
+
+    #[test]
+    fn test_lu_exact() {
+        type Q = Fraction<i64>;
+        let data = [0, 1, 2, 1, 0, 3, 4, -3, 8].into_iter().map(Q::from).collect();
+        let a = Matrix::<3, 3, Q>::from_data(data);
+        let lu = a.lu();
+        assert_eq!(lu.determinant(), a.determinant());
+        let b = Matrix::<3, 1, Q>::from_data(vec![Q::from(1), Q::from(2), Q::from(3)]);
+        let x = lu.solve(&b).unwrap();
+        assert_eq!(a * x, b);
+    }
+
+    #[test]
+    fn test_lu_singular() {
+        let a = Matrix::<2, 2, f64>::from_data(vec![1.0, 2.0, 2.0, 4.0]);
+        let lu = a.lu();
+        assert_eq!(lu.determinant(), 0.0);
+        assert_eq!(lu.solve(&Matrix::full(1.0)), None);
+    }
+
+    #[test]
+    fn test_qr() {
+        let a = Matrix::<3, 2, f64>::from_data(vec![12.0, -51.0, 6.0, 167.0, -4.0, 24.0]);
+        let qr = a.qr();
+        assert!(approx(&(qr.q.clone() * qr.r.clone()), &a));
+        assert!(approx(&(qr.q.clone().transpose() * qr.q.clone()), &Matrix::one()));
+        assert_eq!(qr.r[(1, 0)], 0.0);
+        assert_eq!(qr.r[(2, 0)], 0.0);
+        assert_eq!(qr.r[(2, 1)], 0.0);
+        assert!((qr.r[(0, 0)].abs() - 14.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_orthonormalize() {
+        let a = Matrix::<4, 3, f64>::from_data(vec![
+            1.0, 2.0, 0.0, //
+            1.0, -1.0, 3.0, //
+            1.0, 0.5, -2.0, //
+            1.0, 4.0, 1.0,
+        ]);
+        let (q, r) = a.orthonormalize();
+        assert!(approx(&(q.clone() * r.clone()), &a));
+        assert!(approx(&(q.clone().transpose() * q.clone()), &Matrix::one()));
+        for i in 0..3 {
+            for j in 0..i {
+                assert_eq!(r[(i, j)], 0.0);
+            }
+        }
+
+        // least squares fit of line c0 + c1 t through points (t, y): solve R c = Q^T y
+        let t = [0.0, 1.0, 2.0, 3.0];
+        let y = Matrix::<4, 1, f64>::from_data(vec![1.0, 3.1, 4.9, 7.0]);
+        let a = Matrix::<4, 2, f64>::from_fn(|i, j| if j == 0 { 1.0 } else { t[i] });
+        let (q, r) = a.orthonormalize();
+        let qty = q.transpose() * y;
+        let c1 = qty[(1, 0)] / r[(1, 1)];
+        let c0 = (qty[(0, 0)] - r[(0, 1)] * c1) / r[(0, 0)];
+        assert!((c0 - 1.03).abs() < 1e-12);
+        assert!((c1 - 1.98).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_orthonormalize_dependent() {
+        // second column is twice the first one
+        let a = Matrix::<3, 3, f64>::from_data(vec![1.0, 2.0, 0.0, 2.0, 4.0, 1.0, 2.0, 4.0, 5.0]);
+        let (q, r) = a.orthonormalize();
+        assert!(approx(&(q.clone() * r.clone()), &a));
+        assert_eq!(r[(1, 1)], 0.0);
+        assert!(q.col(1).all(|x| *x == 0.0));
+    }
+
+    #[test]
+    fn test_cholesky() {
+        let a = Matrix::<3, 3, f64>::from_data(vec![
+            4.0, 12.0, -16.0, 12.0, 37.0, -43.0, -16.0, -43.0, 98.0,
+        ]);
+        let l = a.cholesky().unwrap();
+        assert!(approx(&l, &Matrix::from_data(vec![2.0, 0.0, 0.0, 6.0, 1.0, 0.0, -8.0, 5.0, 3.0])));
+        assert!(approx(&(l.clone() * l.transpose()), &a));
+
+        let a = Matrix::<2, 2, f64>::from_data(vec![1.0, 2.0, 2.0, 1.0]);
+        assert_eq!(a.cholesky(), None);
+    }
+}
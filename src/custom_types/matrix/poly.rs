@@ -0,0 +1,232 @@
+//! Defines helpers for matrices of polynomials (λ-matrices).
+
+use std::cmp::min;
+use std::ops::Add;
+
+use super::Matrix;
+use crate::polynom::Polynomial;
+use crate::{CoefField, CoefRing, SubstitutablePoint, Zero};
+
+impl<const N: usize, T: CoefRing> Matrix<N, N, T> {
+    /// Returns characteristic matrix xI - self.
+    /// Its determinant is characteristic polynomial.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::Matrix;
+    /// let m = Matrix::<2, 2, i32>::from_data(vec![1, 2, 3, 4]);
+    /// let p = m.characteristic_matrix().determinant().reduce(); // x^2 - 5x - 2
+    /// assert_eq!((p.coef(0), p.coef(1), p.coef(2)), (-2, -5, 1));
+    /// ```
+    pub fn characteristic_matrix(&self) -> Matrix<N, N, Polynomial<T>> {
+        Matrix::from_fn(|i, j| {
+            let value = -self[(i, j)].clone();
+            if i == j {
+                Polynomial::from_coefs(vec![value, T::one()])
+            } else {
+                Polynomial::from_coefs(vec![value])
+            }
+        })
+    }
+}
+
+impl<const N: usize, const M: usize, T, U> Matrix<N, M, Polynomial<T, U>> {
+    /// Returns matrix of degrees of elements, `None` for zero polynomials.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::Matrix;
+    /// let m = Matrix::<2, 2, i32>::eye(3).characteristic_matrix(); // [[x - 3, 0], [0, x - 3]]
+    /// assert_eq!(m.degree_matrix(), Matrix::from_rows([[Some(1), None], [None, Some(1)]]));
+    /// ```
+    pub fn degree_matrix(&self) -> Matrix<N, M, Option<u32>>
+    where
+        T: Clone + Zero,
+        T: Add<T, Output = T>,
+    {
+        Matrix::from_fn(|i, j| self[(i, j)].degree())
+    }
+
+    /// Returns matrix of values of elements at point.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::Matrix;
+    /// let a = Matrix::<2, 2, i32>::from_data(vec![1, 2, 3, 4]);
+    /// let m = a.characteristic_matrix();
+    /// assert_eq!(m.substitude(5), Matrix::eye(5) - a);
+    /// ```
+    pub fn substitude<X, Y>(&self, point: X) -> Matrix<N, M, Y>
+    where
+        T: Clone,
+        X: SubstitutablePoint<T, Y>,
+    {
+        Matrix::from_fn(|i, j| self[(i, j)].substitude(point.clone()))
+    }
+
+    /// Returns matrix with reduced elements (see `Polynomial::reduce`).
+    pub fn reduce(self) -> Matrix<N, M, Polynomial<T, U>>
+    where
+        T: Clone + Zero,
+        T: Add<T, Output = T>,
+    {
+        self.map(Polynomial::reduce)
+    }
+
+    /// Returns Smith normal form: diagonal matrix diag(d1, d2, ..., dk, 0, ..., 0),
+    /// equivalent to self, where each di is monic and divides d(i+1).
+    /// Polynomials di are invariant factors of self.
+    ///
+    /// Coefs should form a field.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::{Matrix, Zp};
+    /// type F = Zp<7>;
+    /// // Jordan block with eigenvalue 2
+    /// let a = Matrix::from_rows([[F::new(2), F::new(1)], [F::new(0), F::new(2)]]);
+    /// let s = a.characteristic_matrix().smith_normal_form();
+    /// assert_eq!(s.degree_matrix(), Matrix::from_rows([[Some(0), None], [None, Some(2)]]));
+    /// assert_eq!(s[(1, 1)].substitude(F::new(2)), F::new(0)); // (x - 2)^2
+    /// ```
+    pub fn smith_normal_form(&self) -> Matrix<N, M, Polynomial<T, U>>
+    where
+        T: CoefField,
+    {
+        let mut a: Vec<Polynomial<T, U>> = self.data.iter().map(|p| p.clone().reduce()).collect();
+        let at = |i: usize, j: usize| i * M + j;
+
+        for t in 0..min(N, M) {
+            loop {
+                // nonzero element of least degree goes to [t, t]
+                let pivot = (t..N)
+                    .flat_map(|i| (t..M).map(move |j| (i, j)))
+                    .filter_map(|(i, j)| a[at(i, j)].degree().map(|deg| (deg, i, j)))
+                    .min();
+                let (_, pi, pj) = match pivot {
+                    Some(pivot) => pivot,
+                    // rest of matrix is zero
+                    None => return Matrix { data: a },
+                };
+                for j in 0..M {
+                    a.swap(at(t, j), at(pi, j));
+                }
+                for i in 0..N {
+                    a.swap(at(i, t), at(i, pj));
+                }
+
+                // eliminate column t and row t, remainders have less degree than pivot
+                let mut done = true;
+                for i in t + 1..N {
+                    let (q, r) = a[at(i, t)].div_rem(&a[at(t, t)]);
+                    for j in t..M {
+                        let value = a[at(i, j)].clone() - q.clone() * a[at(t, j)].clone();
+                        a[at(i, j)] = value.reduce();
+                    }
+                    done &= r.is_empty();
+                }
+                for j in t + 1..M {
+                    let (q, r) = a[at(t, j)].div_rem(&a[at(t, t)]);
+                    for i in t..N {
+                        let value = a[at(i, j)].clone() - a[at(i, t)].clone() * q.clone();
+                        a[at(i, j)] = value.reduce();
+                    }
+                    done &= r.is_empty();
+                }
+                if !done {
+                    continue;
+                }
+
+                // pivot should divide the rest, otherwise add bad row and repeat
+                let bad_row = (t + 1..N).find(|&i| {
+                    (t + 1..M).any(|j| !a[at(i, j)].div_rem(&a[at(t, t)]).1.is_empty())
+                });
+                match bad_row {
+                    Some(i) => {
+                        for j in t..M {
+                            let value = a[at(t, j)].clone() + a[at(i, j)].clone();
+                            a[at(t, j)] = value.reduce();
+                        }
+                    }
+                    None => break,
+                }
+            }
+
+            let pivot = &a[at(t, t)];
+            let lead = pivot.coef(pivot.degree().expect("pivot is not zero"));
+            a[at(t, t)] = pivot.clone() * (T::one() / lead);
+        }
+        Matrix { data: a }
+    }
+}
+
+impl<const N: usize, T: CoefField> Matrix<N, N, Polynomial<T>> {
+    /// Returns determinant, computed by evaluation and interpolation:
+    /// matrix is evaluated at points 0, 1, ..., d, where d bounds degree
+    /// of determinant (by sums of maximal degrees in rows and in columns),
+    /// numeric determinants are taken, and result is restored
+    /// by Newton's interpolation. It takes O(d * N^4) operations on coefs
+    /// instead of operations on polynomials.
+    ///
+    /// Coefs should form a field with more than d elements,
+    /// otherwise points repeat and it panics.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::{Matrix, Zp};
+    /// type F = Zp<101>;
+    /// let a = Matrix::from_rows([[F::new(1), F::new(2)], [F::new(3), F::new(4)]]);
+    /// let m = a.characteristic_matrix();
+    /// let p = m.determinant_by_interpolation(); // x^2 - 5x - 2
+    /// assert_eq!((p.coef(0), p.coef(1), p.coef(2)), (F::from(-2), F::from(-5), F::new(1)));
+    /// ```
+    pub fn determinant_by_interpolation(&self) -> Polynomial<T> {
+        let degrees = self.degree_matrix();
+        let mut row_bound = 0;
+        let mut column_bound = 0;
+        for i in 0..N {
+            let row = (0..N).filter_map(|j| degrees[(i, j)]).max();
+            let column = (0..N).filter_map(|j| degrees[(j, i)]).max();
+            match (row, column) {
+                (Some(row), Some(column)) => {
+                    row_bound += row;
+                    column_bound += column;
+                }
+                // zero row or column
+                _ => return Polynomial::from_coefs(vec![]),
+            }
+        }
+        let bound = min(row_bound, column_bound) as usize;
+
+        let mut points = Vec::with_capacity(bound + 1);
+        let mut values = Vec::with_capacity(bound + 1);
+        let mut point = T::zero();
+        for i in 0..=bound {
+            if i > 0 && point.is_zero() {
+                panic!("field has too few elements to interpolate degree {}", bound);
+            }
+            values.push(self.substitude(point.clone()).determinant());
+            points.push(point.clone());
+            point = point + T::one();
+        }
+
+        // divided differences in place: values[k] = f[x0, ..., xk]
+        for k in 1..=bound {
+            for j in (k..=bound).rev() {
+                let diff = values[j].clone() - values[j - 1].clone();
+                values[j] = diff / (points[j].clone() - points[j - k].clone());
+            }
+        }
+        // Horner's scheme for f0 + (x - x0)(f1 + (x - x1)(f2 + ...))
+        let mut coefs: Vec<T> = Vec::with_capacity(bound + 1);
+        for k in (0..=bound).rev() {
+            coefs.insert(0, T::zero());
+            for j in 0..coefs.len() - 1 {
+                let next = coefs[j + 1].clone();
+                coefs[j] = coefs[j].clone() - next * points[k].clone();
+            }
+            coefs[0] = coefs[0].clone() + values[k].clone();
+        }
+        Polynomial::from_coefs(coefs)
+    }
+}
@@ -0,0 +1,190 @@
+//! Defines type `Tropical` - element of tropical (min, +) semiring.
+
+use std::fmt::Display;
+use std::ops::{Add, AddAssign, Mul, MulAssign};
+
+use crate::{One, Zero};
+
+/// Element of tropical semiring.
+///
+/// Addition is `min`, multiplication is usual `+`.
+/// So `zero` is +∞ and `one` is `0`.
+/// There is no negation, so it is a good example of
+/// polynomial coefficients, that form only a semiring.
+///
+/// Example:
+/// ```
+/// # use polylib::custom_types::Tropical;
+/// let a = Tropical::new(3);
+/// let b = Tropical::new(5);
+/// assert_eq!(a + b, Tropical::new(3)); // min(3, 5)
+/// assert_eq!(a * b, Tropical::new(8)); // 3 + 5
+/// ```
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub struct Tropical<T>(Option<T>);
+
+impl<T> Tropical<T> {
+    /// Creates finite element.
+    pub fn new(value: T) -> Tropical<T> {
+        Tropical(Some(value))
+    }
+
+    /// Creates +∞, which is `zero` of semiring.
+    pub fn infinity() -> Tropical<T> {
+        Tropical(None)
+    }
+
+    /// Returns holding value or `None` for +∞.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::Tropical;
+    /// assert_eq!(Tropical::new(7).value(), Some(&7));
+    /// assert_eq!(Tropical::<i32>::infinity().value(), None);
+    /// ```
+    pub fn value(&self) -> Option<&T> {
+        self.0.as_ref()
+    }
+
+    /// Checks if element is +∞.
+    pub fn is_infinity(&self) -> bool {
+        self.0.is_none()
+    }
+}
+
+impl<T> From<T> for Tropical<T> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T> Zero for Tropical<T> {
+    fn zero() -> Self {
+        Self::infinity()
+    }
+
+    fn is_zero(&self) -> bool {
+        self.is_infinity()
+    }
+}
+
+impl<T: Zero> One for Tropical<T> {
+    fn one() -> Self {
+        Self::new(T::zero())
+    }
+
+    fn is_one(&self) -> bool {
+        self.0.as_ref().is_some_and(T::is_zero)
+    }
+}
+
+impl<T: PartialOrd> Add for Tropical<T> {
+    type Output = Tropical<T>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        match (self.0, rhs.0) {
+            (None, rhs) => Tropical(rhs),
+            (lhs, None) => Tropical(lhs),
+            (Some(lhs), Some(rhs)) => {
+                if rhs < lhs {
+                    Self::new(rhs)
+                } else {
+                    Self::new(lhs)
+                }
+            }
+        }
+    }
+}
+
+impl<T: Add<Output = T>> Mul for Tropical<T> {
+    type Output = Tropical<T>;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, rhs: Self) -> Self::Output {
+        match (self.0, rhs.0) {
+            (Some(lhs), Some(rhs)) => Self::new(lhs + rhs),
+            _ => Self::infinity(),
+        }
+    }
+}
+
+// needed by `Matrix` multiplication, so matrix powers give shortest paths
+impl<T: PartialOrd> AddAssign for Tropical<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        let lhs = Tropical(self.0.take());
+        *self = lhs + rhs;
+    }
+}
+
+impl<T: Add<Output = T>> MulAssign for Tropical<T> {
+    fn mul_assign(&mut self, rhs: Self) {
+        let lhs = Tropical(self.0.take());
+        *self = lhs * rhs;
+    }
+}
+
+impl<T: Display> Display for Tropical<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.0 {
+            Some(value) => write!(f, "{}", value),
+            None => write!(f, "inf"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tropical_tests {
+    use super::Tropical;
+    use crate::{One, Zero};
+
+    type T = Tropical<i32>;
+
+    #[test]
+    fn test_add() {
+        assert_eq!(T::new(1) + T::new(2), T::new(1));
+        assert_eq!(T::new(2) + T::new(-3), T::new(-3));
+        assert_eq!(T::new(2) + T::infinity(), T::new(2));
+        assert_eq!(T::infinity() + T::new(2), T::new(2));
+        assert_eq!(T::infinity() + T::infinity(), T::infinity());
+    }
+
+    #[test]
+    fn test_mul() {
+        assert_eq!(T::new(1) * T::new(2), T::new(3));
+        assert_eq!(T::new(2) * T::new(-3), T::new(-1));
+        assert_eq!(T::new(2) * T::infinity(), T::infinity());
+        assert_eq!(T::infinity() * T::new(2), T::infinity());
+    }
+
+    #[test]
+    fn test_zero_one() {
+        assert!(T::zero().is_zero());
+        assert!(T::one().is_one());
+        assert_eq!(T::new(5) + T::zero(), T::new(5));
+        assert_eq!(T::new(5) * T::one(), T::new(5));
+        assert_eq!(T::new(5) * T::zero(), T::zero());
+        assert!(!T::new(5).is_one());
+        assert!(!T::one().is_zero());
+    }
+
+    #[test]
+    fn test_assign() {
+        let mut a = T::from(4);
+        a += T::new(6);
+        assert_eq!(a, T::new(4));
+        a += T::new(-1);
+        assert_eq!(a, T::new(-1));
+        a *= T::new(3);
+        assert_eq!(a, T::new(2));
+        a *= T::infinity();
+        assert!(a.is_infinity());
+    }
+
+    #[test]
+    fn test_float() {
+        let a = Tropical::new(0.5);
+        let b = Tropical::new(1.25);
+        assert_eq!(a + b, a);
+        assert_eq!(a * b, Tropical::new(1.75));
+    }
+}
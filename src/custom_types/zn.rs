@@ -4,10 +4,19 @@ use std::cmp::{Eq, PartialEq};
 use std::fmt::Display;
 use std::ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign};
 
+use super::Zp;
+use crate::random::{Random, Xorshift64};
 use crate::{One, Zero};
 
 /// Struct, that hold remain of n.
-#[derive(PartialEq, Eq, Copy, Clone, Debug, Default)]
+///
+/// `Zn<0>` has no elements, so it is rejected at compile time:
+/// ```compile_fail
+/// # use polylib::custom_types::Zn;
+/// # use polylib::One;
+/// let one = Zn::<0>::one();
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone, Debug)]
 pub struct Zn<const N: u32>(u32);
 
 impl<const N: u32> Zn<N> {
@@ -19,9 +28,23 @@ impl<const N: u32> Zn<N> {
     /// let val = Zn::<5>::new(7); // 7 > 5, so val is 2
     /// ```
     pub fn new(value: u32) -> Zn<N> {
+        const { assert!(N > 0, "Zn<0> has no elements") };
         Zn::<N>(value % N)
     }
 
+    /// Creates Zn from signed value. Negative values are wrapped into [0, N).
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::Zn;
+    /// let val = Zn::<5>::from_i64(-7); // -7 = 3 - 2 * 5
+    /// assert_eq!(val.value(), 3);
+    /// ```
+    pub fn from_i64(value: i64) -> Zn<N> {
+        const { assert!(N > 0, "Zn<0> has no elements") };
+        Zn::<N>(value.rem_euclid(N as i64) as u32)
+    }
+
     /// Returns holding value.
     /// 
     /// Example:
@@ -33,6 +56,185 @@ impl<const N: u32> Zn<N> {
     pub fn value(&self) -> u32 {
         self.0
     }
+
+    /// Returns self in power exp. Uses square-and-multiply.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::Zn;
+    /// let val = Zn::<7>::new(3);
+    /// assert_eq!(val.pow(6).value(), 1); // Fermat's little theorem
+    /// ```
+    pub fn pow(self, mut exp: u64) -> Zn<N> {
+        let mut ans = Self::new(1);
+        let mut base = self;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                ans *= base;
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base *= base;
+            }
+        }
+        ans
+    }
+
+    /// Returns least k > 0, such that self^k = 1,
+    /// or `None` if self is not invertible.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::Zn;
+    /// assert_eq!(Zn::<7>::new(2).multiplicative_order(), Some(3));
+    /// assert_eq!(Zn::<9>::new(3).multiplicative_order(), None);
+    /// ```
+    pub fn multiplicative_order(&self) -> Option<u64> {
+        if gcd(self.0 as u64, N as u64) != 1 {
+            return None;
+        }
+        let phi = totient(N as u64);
+        // order divides phi, so remove extra prime factors
+        let mut order = phi;
+        for p in prime_factors(phi) {
+            while order.is_multiple_of(p) && self.pow(order / p).0 % N == 1 % N {
+                order /= p;
+            }
+        }
+        Some(order)
+    }
+
+    /// Returns least primitive root (generator of all invertible elements),
+    /// or `None` if N has no primitive roots.
+    /// They exist for N = 1, 2, 4, p^k and 2p^k, where p is odd prime.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::Zn;
+    /// assert_eq!(Zn::<7>::primitive_root().map(|g| g.value()), Some(3));
+    /// assert_eq!(Zn::<8>::primitive_root(), None);
+    /// ```
+    pub fn primitive_root() -> Option<Zn<N>> {
+        let n = N as u64;
+        let mut odd = n;
+        if odd.is_multiple_of(4) {
+            odd /= 4;
+            if odd != 1 {
+                return None;
+            }
+        } else if odd.is_multiple_of(2) {
+            odd /= 2;
+        }
+        if prime_factors(odd).len() > 1 {
+            return None;
+        }
+        let phi = totient(n);
+        let factors = prime_factors(phi);
+        (0..N)
+            .map(Self::new)
+            .filter(|g| gcd(g.0 as u64, n) == 1)
+            .find(|g| factors.iter().all(|q| g.pow(phi / q).0 % N != 1 % N))
+    }
+
+    /// Returns Legendre symbol (self / N): 0 for zero, 1 for quadratic
+    /// residue and -1 for non-residue. Same as `Zp::legendre`.
+    ///
+    /// N must be prime, composite N is rejected at compile time.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::Zn;
+    /// assert_eq!(Zn::<7>::new(2).legendre(), 1); // 3 * 3 = 2
+    /// assert_eq!(Zn::<7>::new(3).legendre(), -1);
+    /// ```
+    pub fn legendre(&self) -> i32 {
+        Zp::from(*self).legendre()
+    }
+
+    /// Returns both square roots of self (the less one goes first),
+    /// or `None` if there are no roots. Same as `Zp::sqrt`.
+    ///
+    /// N must be prime, composite N is rejected at compile time:
+    /// ```compile_fail
+    /// # use polylib::custom_types::Zn;
+    /// let roots = Zn::<9>::new(8).sqrt();
+    /// ```
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::Zn;
+    /// let (a, b) = Zn::<13>::new(10).sqrt().unwrap();
+    /// assert_eq!((a.value(), b.value()), (6, 7));
+    /// assert_eq!(Zn::<13>::new(5).sqrt(), None);
+    /// ```
+    pub fn sqrt(&self) -> Option<(Zn<N>, Zn<N>)> {
+        Zp::from(*self).sqrt().map(|(a, b)| (a.to_zn(), b.to_zn()))
+    }
+
+    /// Returns least x, such that base^x = target, or `None` if there is no such x.
+    /// Same as `Zp::discrete_log`: baby-step giant-step with O(sqrt(N)) memory.
+    ///
+    /// N must be prime, composite N is rejected at compile time.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::Zn;
+    /// type Z = Zn<17>;
+    /// assert_eq!(Z::discrete_log(Z::new(3), Z::new(13)), Some(4)); // 3^4 = 81 = 13
+    /// assert_eq!(Z::discrete_log(Z::new(4), Z::new(3)), None);
+    /// ```
+    pub fn discrete_log(base: Zn<N>, target: Zn<N>) -> Option<u64> {
+        Zp::discrete_log(Zp::from(base), Zp::from(target))
+    }
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+// distinct prime factors by trial division
+fn prime_factors(mut n: u64) -> Vec<u64> {
+    let mut ans = Vec::new();
+    let mut p = 2;
+    while p * p <= n {
+        if n.is_multiple_of(p) {
+            ans.push(p);
+            while n.is_multiple_of(p) {
+                n /= p;
+            }
+        }
+        p += 1;
+    }
+    if n > 1 {
+        ans.push(n);
+    }
+    ans
+}
+
+fn totient(n: u64) -> u64 {
+    prime_factors(n).iter().fold(n, |acc, p| acc / p * (p - 1))
+}
+
+impl<const N: u32> Default for Zn<N> {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl<const N: u32> From<i32> for Zn<N> {
+    fn from(value: i32) -> Self {
+        Self::from_i64(value as i64)
+    }
+}
+
+impl<const N: u32> From<i64> for Zn<N> {
+    fn from(value: i64) -> Self {
+        Self::from_i64(value)
+    }
 }
 
 impl<const N: u32> Zero for Zn<N> {
@@ -47,9 +249,6 @@ impl<const N: u32> Zero for Zn<N> {
 
 impl<const N: u32> One for Zn<N> {
     fn one() -> Self {
-        if N == 0 {
-            panic!("can't create one for Z0");
-        }
         Self::new(1)
     }
 
@@ -63,14 +262,14 @@ impl<const N: u32> Add for Zn<N> {
 
     fn add(self, rhs: Self) -> Self::Output {
         Self::Output {
-            0: (self.0 + rhs.0) % N,
+            0: ((self.0 as u64 + rhs.0 as u64) % N as u64) as u32,
         }
     }
 }
 
 impl<const N: u32> AddAssign for Zn<N> {
     fn add_assign(&mut self, rhs: Self) {
-        self.0 = (self.0 + rhs.0) % N;
+        self.0 = ((self.0 as u64 + rhs.0 as u64) % N as u64) as u32;
     }
 }
 
@@ -79,14 +278,14 @@ impl<const N: u32> Sub for Zn<N> {
 
     fn sub(self, rhs: Self) -> Self::Output {
         Self::Output {
-            0: (self.0 + N - rhs.0) % N,
+            0: ((self.0 as u64 + N as u64 - rhs.0 as u64) % N as u64) as u32,
         }
     }
 }
 
 impl<const N: u32> SubAssign for Zn<N> {
     fn sub_assign(&mut self, rhs: Self) {
-        self.0 = (self.0 + N - rhs.0) % N;
+        self.0 = ((self.0 as u64 + N as u64 - rhs.0 as u64) % N as u64) as u32;
     }
 }
 
@@ -94,24 +293,29 @@ impl<const N: u32> Mul for Zn<N> {
     type Output = Zn<N>;
 
     fn mul(self, rhs: Self) -> Self::Output {
+        // multiply in u64, so any u32 modulus is fine
         Self::Output {
-            0: (self.0 * rhs.0) % N,
+            0: ((self.0 as u64 * rhs.0 as u64) % N as u64) as u32,
         }
     }
 }
 
 impl<const N: u32> MulAssign for Zn<N> {
     fn mul_assign(&mut self, rhs: Self) {
-        self.0 = (self.0 * rhs.0) % N;
+        self.0 = ((self.0 as u64 * rhs.0 as u64) % N as u64) as u32;
     }
 }
 
 impl<const N: u32> MulAssign<i32> for Zn<N> {
-    fn mul_assign(&mut self, mut rhs: i32) {
-        if rhs < 0  {
-            rhs = N as i32 + (rhs % N as i32);
-        }
-        self.0 = (self.0 * (rhs as u32)) % N;
+    fn mul_assign(&mut self, rhs: i32) {
+        let rhs = (rhs as i64).rem_euclid(N as i64) as u64;
+        self.0 = ((self.0 as u64 * rhs) % N as u64) as u32;
+    }
+}
+
+impl<const N: u32> Random for Zn<N> {
+    fn random(rng: &mut Xorshift64) -> Self {
+        Self::new(rng.below(N as u64) as u32)
     }
 }
 
@@ -319,6 +523,116 @@ mod zn_tests {
         );
     }
 
+    #[test]
+    fn test_pow() {
+        assert_eq!(Z5::new(2).pow(0).value(), 1);
+        assert_eq!(Z5::new(0).pow(0).value(), 1);
+        assert_eq!(Z5::new(2).pow(3).value(), 3);
+        assert_eq!(Z100::new(3).pow(5).value(), 43);
+        assert_eq!(Zn::<13>::new(6).pow(12).value(), 1);
+        assert_eq!(Zn::<13>::new(6).pow(u64::MAX).value(), 6u32.pow(3) % 13);
+        assert_eq!(Zn::<1>::new(0).pow(0).value(), 0);
+    }
+
+    #[test]
+    fn test_large_modulus() {
+        type Big = Zn<4_294_967_291>; // largest u32 prime
+        let a = Big::new(4_294_967_290); // -1
+        assert_eq!((a * a).value(), 1);
+        assert_eq!((a + a).value(), 4_294_967_289);
+        assert_eq!((Big::new(0) - a).value(), 1);
+        let mut b = a;
+        b *= a;
+        assert_eq!(b.value(), 1);
+        b += a;
+        assert_eq!(b.value(), 0);
+        b -= a;
+        assert_eq!(b.value(), 1);
+        let mut c = Big::new(100_000);
+        c *= -1;
+        assert_eq!(c.value(), 4_294_967_291 - 100_000);
+        assert_eq!(Big::new(123_456).pow(4_294_967_290).value(), 1);
+    }
+
+    #[test]
+    fn test_from_signed() {
+        assert_eq!(Z5::from_i64(-1).value(), 4);
+        assert_eq!(Z5::from_i64(-5).value(), 0);
+        assert_eq!(Z5::from_i64(12).value(), 2);
+        assert_eq!(Z100::from(-201).value(), 99);
+        assert_eq!(Z100::from(i64::MIN).value(), 92);
+        assert_eq!(Zn::<4_294_967_295>::from(-1i64).value(), 4_294_967_294);
+    }
+
+    fn brute_order<const N: u32>(v: Zn<N>) -> Option<u64> {
+        (1..=N as u64).find(|k| v.pow(*k).value() == 1 % N)
+    }
+
+    #[test]
+    fn test_multiplicative_order() {
+        for v in 0..36 {
+            let v = Zn::<36>::new(v);
+            assert_eq!(v.multiplicative_order(), brute_order(v));
+        }
+        for v in 0..101 {
+            let v = Zn::<101>::new(v);
+            assert_eq!(v.multiplicative_order(), brute_order(v));
+        }
+        assert_eq!(Zn::<1>::new(0).multiplicative_order(), Some(1));
+        assert_eq!(super::totient(36), 12);
+        assert_eq!(Zn::<4_294_967_291>::new(1).multiplicative_order(), Some(1));
+    }
+
+    fn check_primitive_root<const N: u32>(expected: Option<u32>) {
+        let root = Zn::<N>::primitive_root();
+        assert_eq!(root.map(|g| g.value()), expected, "bad root for Z{}", N);
+        if let Some(g) = root {
+            assert_eq!(g.multiplicative_order(), Some(super::totient(N as u64)));
+        }
+    }
+
+    #[test]
+    fn test_legendre_sqrt() {
+        assert_eq!(Zn::<13>::new(0).legendre(), 0);
+        assert_eq!(Zn::<13>::new(4).legendre(), 1);
+        assert_eq!(Zn::<13>::new(2).legendre(), -1);
+        assert_eq!(Zn::<2>::new(1).sqrt(), Some((Zn::new(1), Zn::new(1))));
+        for x in 0..41 {
+            let value = Zn::<41>::new(x);
+            match value.sqrt() {
+                Some((a, b)) => {
+                    assert_eq!(a * a, value);
+                    assert_eq!(b * b, value);
+                    assert!(a <= b);
+                }
+                None => assert_eq!(value.legendre(), -1),
+            }
+        }
+    }
+
+    #[test]
+    fn test_discrete_log() {
+        type Z = Zn<101>;
+        let g = Z::new(2);
+        for x in 0..100 {
+            assert_eq!(Z::discrete_log(g, g.pow(x)), Some(x));
+        }
+        assert_eq!(Z::discrete_log(Z::new(0), Z::new(0)), Some(1));
+        assert_eq!(Z::discrete_log(Z::new(0), Z::new(5)), None);
+    }
+
+    #[test]
+    fn test_primitive_root() {
+        check_primitive_root::<2>(Some(1));
+        check_primitive_root::<4>(Some(3));
+        check_primitive_root::<7>(Some(3));
+        check_primitive_root::<8>(None);
+        check_primitive_root::<9>(Some(2));
+        check_primitive_root::<18>(Some(5));
+        check_primitive_root::<15>(None);
+        check_primitive_root::<998_244_353>(Some(3));
+    }
+
     #[test]
     fn test_create() {
         type Z5 = Zn<5>;
@@ -432,12 +746,4 @@ mod zn_tests {
         assert_eq!(Z10::zero() + Z10::zero(), Z10::zero());
         assert_eq!(Z100::zero() + Z100::zero(), Z100::zero());
     }
-
-    #[test]
-    #[should_panic]
-    fn test_z0_one() {
-        type Z0 = Zn<0>;
-
-        Z0::one();
-    }
 }
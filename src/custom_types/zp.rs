@@ -0,0 +1,491 @@
+//! Defines type `Zp` is remains of prime p. It is a field.
+
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use super::Zn;
+use crate::random::{Random, Xorshift64};
+use crate::{One, Zero};
+
+/// Checks, if n is prime. Can be used in const context.
+///
+/// Example:
+/// ```
+/// # use polylib::custom_types::zp::is_prime;
+/// const SEVEN: bool = is_prime(7);
+/// assert!(SEVEN);
+/// assert!(!is_prime(91));
+/// ```
+pub const fn is_prime(n: u32) -> bool {
+    if n < 2 {
+        return false;
+    }
+    let n = n as u64;
+    let mut i = 2;
+    while i * i <= n {
+        if n.is_multiple_of(i) {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Struct, that hold remain of prime P.
+///
+/// Primality of P is checked at compile time, so `Zp` always
+/// is a field and implements `Div`.
+///
+/// Example:
+/// ```
+/// # use polylib::custom_types::Zp;
+/// let a = Zp::<7>::new(3);
+/// assert_eq!((a / Zp::new(5)).value(), 2); // 5 * 2 = 10 = 3 (mod 7)
+/// ```
+///
+/// Non prime modulus fails to compile:
+/// ```compile_fail
+/// # use polylib::custom_types::Zp;
+/// let a = Zp::<8>::new(3);
+/// ```
+///
+/// Every value is made through the check, `Default` too:
+/// ```compile_fail
+/// # use polylib::custom_types::Zp;
+/// let a = Zp::<8>::default();
+/// ```
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub struct Zp<const P: u32>(Zn<P>);
+
+impl<const P: u32> Zp<P> {
+    // all values are made here, so check is done for each used P
+    fn wrap(value: Zn<P>) -> Zp<P> {
+        const { assert!(is_prime(P), "Zp modulus must be prime") };
+        Zp(value)
+    }
+
+    /// Creates Zp. If value is equal to or more than P, takes only remain.
+    pub fn new(value: u32) -> Zp<P> {
+        Self::wrap(Zn::new(value))
+    }
+
+    /// Creates Zp from signed value. Negative values are wrapped into [0, P).
+    pub fn from_i64(value: i64) -> Zp<P> {
+        Self::wrap(Zn::from_i64(value))
+    }
+
+    /// Returns holding value.
+    pub fn value(&self) -> u32 {
+        self.0.value()
+    }
+
+    /// Returns self in power exp.
+    pub fn pow(self, exp: u64) -> Zp<P> {
+        Self::wrap(self.0.pow(exp))
+    }
+
+    /// Returns inverse element, or `None` for zero.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::Zp;
+    /// assert_eq!(Zp::<7>::new(3).inv().map(|v| v.value()), Some(5));
+    /// assert_eq!(Zp::<7>::new(0).inv(), None);
+    /// ```
+    pub fn inv(self) -> Option<Zp<P>> {
+        if self.is_zero() {
+            return None;
+        }
+        // Fermat's little theorem: a^(p-1) = 1
+        Some(self.pow(P as u64 - 2))
+    }
+
+    /// Returns binomial coef C(n, k) modulo P, zero for k > n.
+    ///
+    /// Digits of n and k in base P are taken by Lucas' theorem, and C(a, b)
+    /// for digits is a(a - 1)...(a - b + 1) / b!, where b! is invertible modulo P.
+    /// So it takes O(P log n) operations for any n. For `Zn` with prime modulus
+    /// use `Zp::binomial(n, k).to_zn()`.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::Zp;
+    /// assert_eq!(Zp::<7>::binomial(10, 3).value(), 120 % 7);
+    /// // 1000 = 2626 and 49 = 100 in base 7: C(2, 0) * C(6, 1) * C(2, 0) * C(6, 0)
+    /// assert_eq!(Zp::<7>::binomial(1000, 49).value(), 6);
+    /// ```
+    pub fn binomial(mut n: u64, mut k: u64) -> Zp<P> {
+        let p = P as u64;
+        let mut ans = Self::new(1);
+        while k > 0 {
+            let (a, b) = (n % p, k % p);
+            if b > a {
+                return Self::new(0);
+            }
+            let mut num = Self::new(1);
+            let mut den = Self::new(1);
+            for i in 0..b {
+                num *= Self::new((a - i) as u32);
+                den *= Self::new((i + 1) as u32);
+            }
+            // b < P, so b! is not zero
+            ans *= num / den;
+            n /= p;
+            k /= p;
+        }
+        ans
+    }
+
+    /// Returns Legendre symbol (self / P): 0 for zero, 1 for quadratic
+    /// residue and -1 for non-residue.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::Zp;
+    /// assert_eq!(Zp::<7>::new(2).legendre(), 1); // 3 * 3 = 2
+    /// assert_eq!(Zp::<7>::new(3).legendre(), -1);
+    /// ```
+    pub fn legendre(&self) -> i32 {
+        if self.is_zero() || P == 2 {
+            return self.value() as i32;
+        }
+        // Euler's criterion
+        if self.pow((P as u64 - 1) / 2).is_one() {
+            1
+        } else {
+            -1
+        }
+    }
+
+    /// Returns both square roots of self (the less one goes first),
+    /// or `None` if there are no roots. Uses Tonelli–Shanks algorithm.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::Zp;
+    /// let (a, b) = Zp::<13>::new(10).sqrt().unwrap();
+    /// assert_eq!((a.value(), b.value()), (6, 7));
+    /// assert_eq!(Zp::<13>::new(5).sqrt(), None);
+    /// ```
+    ///
+    /// Euler's criterion and Tonelli–Shanks need prime modulus,
+    /// so composite one is rejected at compile time:
+    /// ```compile_fail
+    /// # use polylib::custom_types::Zp;
+    /// let roots = Zp::<9>::new(8).sqrt();
+    /// ```
+    pub fn sqrt(&self) -> Option<(Zp<P>, Zp<P>)> {
+        if self.is_zero() || P == 2 {
+            return Some((*self, *self));
+        }
+        if self.legendre() != 1 {
+            return None;
+        }
+        // P - 1 = q * 2^s, q is odd
+        let mut q = P as u64 - 1;
+        let mut s = 0;
+        while q.is_multiple_of(2) {
+            q /= 2;
+            s += 1;
+        }
+        let mut z = Self::new(2);
+        while z.legendre() != -1 {
+            z += Self::one();
+        }
+        let mut m = s;
+        let mut c = z.pow(q);
+        let mut t = self.pow(q);
+        let mut r = self.pow(q.div_ceil(2));
+        // t^(2^(m - 1)) = 1, so i < m below
+        while !t.is_one() {
+            // least i, such that t^(2^i) = 1
+            let mut i = 0;
+            let mut t2 = t;
+            while !t2.is_one() {
+                t2 = t2 * t2;
+                i += 1;
+            }
+            let b = c.pow(1 << (m - i - 1));
+            m = i;
+            c = b * b;
+            t *= c;
+            r *= b;
+        }
+        let other = -r;
+        if r.value() < other.value() {
+            Some((r, other))
+        } else {
+            Some((other, r))
+        }
+    }
+
+    /// Returns least x, such that base^x = target, or `None` if there is no such x.
+    /// Uses baby-step giant-step algorithm with O(sqrt(P)) memory.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::Zp;
+    /// type Z = Zp<17>;
+    /// assert_eq!(Z::discrete_log(Z::new(3), Z::new(13)), Some(4)); // 3^4 = 81 = 13
+    /// assert_eq!(Z::discrete_log(Z::new(4), Z::new(3)), None);
+    /// ```
+    pub fn discrete_log(base: Zp<P>, target: Zp<P>) -> Option<u64> {
+        if target.is_one() {
+            return Some(0);
+        }
+        let Some(inv) = base.inv() else {
+            return if target.is_zero() { Some(1) } else { None };
+        };
+        let m = (P as f64).sqrt().ceil() as u64;
+        let mut baby = HashMap::new();
+        let mut value = Self::one();
+        for j in 0..m {
+            baby.entry(value.0).or_insert(j);
+            value *= base;
+        }
+        let giant = inv.pow(m);
+        let mut gamma = target;
+        for i in 0..m {
+            if let Some(j) = baby.get(&gamma.0) {
+                return Some(i * m + j);
+            }
+            gamma *= giant;
+        }
+        None
+    }
+
+    /// Returns same value as `Zn`.
+    pub fn to_zn(self) -> Zn<P> {
+        self.0
+    }
+}
+
+impl<const P: u32> Default for Zp<P> {
+    fn default() -> Self {
+        Self::wrap(Zn::default())
+    }
+}
+
+impl<const P: u32> From<Zn<P>> for Zp<P> {
+    fn from(value: Zn<P>) -> Self {
+        Self::wrap(value)
+    }
+}
+
+impl<const P: u32> From<i32> for Zp<P> {
+    fn from(value: i32) -> Self {
+        Self::from_i64(value as i64)
+    }
+}
+
+impl<const P: u32> From<i64> for Zp<P> {
+    fn from(value: i64) -> Self {
+        Self::from_i64(value)
+    }
+}
+
+impl<const P: u32> Zero for Zp<P> {
+    fn zero() -> Self {
+        Self::new(0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+}
+
+impl<const P: u32> One for Zp<P> {
+    fn one() -> Self {
+        Self::new(1)
+    }
+
+    fn is_one(&self) -> bool {
+        self.0.is_one()
+    }
+}
+
+impl<const P: u32> Add for Zp<P> {
+    type Output = Zp<P>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::wrap(self.0 + rhs.0)
+    }
+}
+
+impl<const P: u32> AddAssign for Zp<P> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 += rhs.0;
+    }
+}
+
+impl<const P: u32> Neg for Zp<P> {
+    type Output = Zp<P>;
+
+    fn neg(self) -> Self::Output {
+        Self::wrap(Zn::zero() - self.0)
+    }
+}
+
+impl<const P: u32> Sub for Zp<P> {
+    type Output = Zp<P>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::wrap(self.0 - rhs.0)
+    }
+}
+
+impl<const P: u32> SubAssign for Zp<P> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0 -= rhs.0;
+    }
+}
+
+impl<const P: u32> Mul for Zp<P> {
+    type Output = Zp<P>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::wrap(self.0 * rhs.0)
+    }
+}
+
+impl<const P: u32> MulAssign for Zp<P> {
+    fn mul_assign(&mut self, rhs: Self) {
+        self.0 *= rhs.0;
+    }
+}
+
+impl<const P: u32> MulAssign<i32> for Zp<P> {
+    fn mul_assign(&mut self, rhs: i32) {
+        self.0 *= rhs;
+    }
+}
+
+impl<const P: u32> Div for Zp<P> {
+    type Output = Zp<P>;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        match rhs.inv() {
+            Some(inv) => self * inv,
+            None => panic!("Can't divide by zero in Z{}", P),
+        }
+    }
+}
+
+impl<const P: u32> DivAssign for Zp<P> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl<const P: u32> Random for Zp<P> {
+    fn random(rng: &mut Xorshift64) -> Self {
+        Self::wrap(Zn::random(rng))
+    }
+}
+
+impl<const P: u32> Display for Zp<P> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "<Z{} {}>", P, self.value())
+    }
+}
+
+#[cfg(test)]
+mod zp_tests {
+    use super::{is_prime, Zp};
+    use crate::{One, Zero};
+
+    type Z7 = Zp<7>;
+
+    #[test]
+    fn test_is_prime() {
+        let primes: Vec<u32> = (0..30).filter(|n| is_prime(*n)).collect();
+        assert_eq!(primes, vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+        assert!(is_prime(4_294_967_291));
+        assert!(!is_prime(4_294_967_295));
+    }
+
+    #[test]
+    fn test_field() {
+        for a in 1..7 {
+            let a = Z7::new(a);
+            assert!((a * a.inv().unwrap()).is_one());
+            assert_eq!(Z7::one() / a, a.inv().unwrap());
+            assert!((a + -a).is_zero());
+        }
+        let mut v = Z7::new(6);
+        v /= Z7::new(3);
+        assert_eq!(v.value(), 2);
+        assert_eq!(Z7::from(-1).value(), 6);
+    }
+
+    #[test]
+    fn test_large() {
+        type Big = Zp<4_294_967_291>;
+        let a = Big::new(123_456_789);
+        assert!((a / a).is_one());
+        assert_eq!((a * a.inv().unwrap()).value(), 1);
+    }
+
+    #[test]
+    fn test_legendre() {
+        type Z11 = Zp<11>;
+        let residues: Vec<u32> = (1..11).filter(|v| Z11::new(*v).legendre() == 1).collect();
+        assert_eq!(residues, vec![1, 3, 4, 5, 9]);
+        assert_eq!(Z11::new(0).legendre(), 0);
+        assert_eq!(Z11::new(2).legendre(), -1);
+        assert_eq!(Zp::<2>::new(1).legendre(), 1);
+    }
+
+    fn check_sqrt<const P: u32>() {
+        for v in 0..P.min(2000) {
+            let v = Zp::<P>::new(v);
+            match v.sqrt() {
+                Some((a, b)) => {
+                    assert_eq!(a * a, v);
+                    assert_eq!(b * b, v);
+                    assert!((a + b).is_zero());
+                    assert!(a.value() <= b.value());
+                }
+                None => assert_eq!(v.legendre(), -1),
+            }
+        }
+    }
+
+    #[test]
+    fn test_sqrt() {
+        check_sqrt::<2>();
+        check_sqrt::<3>();
+        check_sqrt::<13>();
+        check_sqrt::<17>(); // 17 - 1 = 2^4
+        check_sqrt::<97>();
+        check_sqrt::<7681>(); // 7681 - 1 = 15 * 2^9
+        check_sqrt::<4_294_967_291>();
+    }
+
+    #[test]
+    fn test_discrete_log() {
+        type Z = Zp<101>;
+        for base in [2u32, 3, 10, 100] {
+            let base = Z::new(base);
+            for target in 0..101 {
+                let target = Z::new(target);
+                let brute = (0..101u64).find(|x| base.pow(*x) == target);
+                assert_eq!(Z::discrete_log(base, target), brute);
+            }
+        }
+        assert_eq!(Z::discrete_log(Z::new(0), Z::new(0)), Some(1));
+        assert_eq!(Z::discrete_log(Z::new(0), Z::new(5)), None);
+
+        type Big = Zp<4_294_967_291>;
+        let x = Big::discrete_log(Big::new(2), Big::new(2).pow(123_456_789)).unwrap();
+        assert_eq!(Big::new(2).pow(x), Big::new(2).pow(123_456_789));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_div_by_zero() {
+        let _ = Z7::one() / Z7::zero();
+    }
+}
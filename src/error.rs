@@ -0,0 +1,68 @@
+//! Defines crate-wide error type `PolyError`.
+
+use std::fmt::Display;
+
+/// Error, returned by fallible (`try_*`, `checked_*`) functions of the crate.
+///
+/// Panicking versions of these functions panic with the same message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PolyError {
+    /// Data with `len` elements can't fill matrix<`rows`, `cols`>.
+    DimensionMismatch { rows: usize, cols: usize, len: usize },
+    /// Index [`row`, `col`] is out of matrix<`rows`, `cols`>.
+    IndexOutOfBounds {
+        row: usize,
+        col: usize,
+        rows: usize,
+        cols: usize,
+    },
+    /// Operation can't be done with matrices of sizes `lhs` and `rhs` (rows, cols).
+    ShapeMismatch {
+        lhs: (usize, usize),
+        rhs: (usize, usize),
+    },
+    /// Remains can't be taken by this modulus.
+    InvalidModulus(u32),
+    /// Division by zero.
+    DivisionByZero,
+    /// Received word has more errors, than code can correct.
+    TooManyErrors,
+    /// Power of x doesn't fit into exponent type.
+    ExponentOverflow(u64),
+    /// Polynomial of `degree` exceeds bound `max` of its type.
+    DegreeOverflow { degree: u32, max: u32 },
+}
+
+impl Display for PolyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PolyError::DimensionMismatch { rows, cols, len } => {
+                write!(f, "matrix<{}, {}> can't hold {} elems", rows, cols, len)
+            }
+            PolyError::IndexOutOfBounds {
+                row,
+                col,
+                rows,
+                cols,
+            } => write!(
+                f,
+                "index [{}, {}] is out of matrix<{}, {}>",
+                row, col, rows, cols
+            ),
+            PolyError::ShapeMismatch { lhs, rhs } => write!(
+                f,
+                "matrix<{}, {}> doesn't match matrix<{}, {}>",
+                lhs.0, lhs.1, rhs.0, rhs.1
+            ),
+            PolyError::InvalidModulus(n) => write!(f, "can't use Z{}", n),
+            PolyError::DivisionByZero => write!(f, "division by zero"),
+            PolyError::TooManyErrors => write!(f, "too many errors to correct"),
+            PolyError::ExponentOverflow(power) => write!(f, "x^{} is out of exponent type", power),
+            PolyError::DegreeOverflow { degree, max } => {
+                write!(f, "degree {} exceeds bound {}", degree, max)
+            }
+        }
+    }
+}
+
+impl std::error::Error for PolyError {}
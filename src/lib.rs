@@ -5,57 +5,292 @@
 //! * Any type substitution (even polynomial to polynomial substitution)
 //! * Some common types, like `zn` and `matrix`
 //! * No dependencies at all. No need to monitor deep library modifications.
+//! * Optional `#[derive(Zero, One)]` for custom types (feature `derive`)
+//! * Reproducible random polynomials, `zn` and `matrix` for tests
+//! * Applications on top of polynomials, like Reed–Solomon codes
 //! 
 
+use std::num::{Saturating, Wrapping};
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+pub mod applications;
 pub mod custom_types;
+pub mod error;
 pub mod polynom;
+pub mod random;
+
+pub use error::PolyError;
 
 /**
  * Means, that numeric type has `zero`
  * (Add neutral element).
  *
+ * Implemented for all primitive numeric types,
+ * `std::num::Wrapping` and `std::num::Saturating`.
+ * Custom types opt in by implementing it directly:
+ * ```
+ * # use polylib::Zero;
+ * #[derive(PartialEq)]
+ * struct Meters(f64);
+ *
+ * impl Zero for Meters {
+ *     fn zero() -> Self {
+ *         Meters(0.0)
+ *     }
+ *
+ *     fn is_zero(&self) -> bool {
+ *         self.0 == 0.0
+ *     }
+ * }
+ * ```
 */
 pub trait Zero {
     /// Return `zero` item of numeric group.
     fn zero() -> Self;
     /// Check if self is `zero`.
     fn is_zero(&self) -> bool;
+    /// Check if self is `zero`, or `None`, if it is too hard to find out
+    /// (as for `Polynomial` with unsummed members). Unlike `is_zero` it never panics,
+    /// so `Display` of polynomials uses it. By default calls `is_zero`.
+    fn try_is_zero(&self) -> Option<bool> {
+        Some(self.is_zero())
+    }
 }
 
 /**
  * Means, that numeric type has `one`
  * (Mul neutral element).
  *
+ * Implemented for all primitive numeric types,
+ * `std::num::Wrapping` and `std::num::Saturating`.
+ * Custom types opt in by implementing it directly, same as `Zero`.
+ *
 */
 pub trait One {
     /// Return `one` item of numeric group.
     fn one() -> Self;
     /// Check if self is `one`.
     fn is_one(&self) -> bool;
+    /// Check if self is `one`, or `None`, if it is too hard to find out,
+    /// same as `Zero::try_is_zero`. By default calls `is_one`.
+    fn try_is_one(&self) -> Option<bool> {
+        Some(self.is_one())
+    }
 }
 
-impl<T> Zero for T
-where
-    T: From<u8> + PartialEq,
-{
+macro_rules! impl_zero_one {
+    ($($t:ty),*) => {
+        $(
+            impl Zero for $t {
+                fn zero() -> Self {
+                    0 as $t
+                }
+
+                fn is_zero(&self) -> bool {
+                    *self == Self::zero()
+                }
+            }
+
+            impl One for $t {
+                fn one() -> Self {
+                    1 as $t
+                }
+
+                fn is_one(&self) -> bool {
+                    *self == Self::one()
+                }
+            }
+        )*
+    };
+}
+
+impl_zero_one!(i8, i16, i32, i64, i128, isize);
+impl_zero_one!(u8, u16, u32, u64, u128, usize);
+impl_zero_one!(f32, f64);
+
+// std wrappers make overflow explicit, so integer polynomials
+// can wrap or saturate in release builds too
+impl<T: Zero> Zero for Wrapping<T> {
     fn zero() -> Self {
-        T::from(0)
+        Wrapping(T::zero())
     }
 
     fn is_zero(&self) -> bool {
-        self == &Self::zero()
+        self.0.is_zero()
     }
 }
 
-impl<T> One for T
-where
-    T: From<u8> + PartialEq,
-{
+impl<T: One> One for Wrapping<T> {
     fn one() -> Self {
-        T::from(1)
+        Wrapping(T::one())
     }
 
     fn is_one(&self) -> bool {
-        self == &Self::one()
+        self.0.is_one()
     }
 }
+
+impl<T: Zero> Zero for Saturating<T> {
+    fn zero() -> Self {
+        Saturating(T::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+}
+
+impl<T: One> One for Saturating<T> {
+    fn one() -> Self {
+        Saturating(T::one())
+    }
+
+    fn is_one(&self) -> bool {
+        self.0.is_one()
+    }
+}
+
+/**
+ * Umbrella trait for coefficients, that form a semiring:
+ * `Clone + Zero + One + Add + Mul`.
+ *
+ * Implemented automatically, so generic code can write `T: CoefSemiring`
+ * instead of listing all the traits.
+ *
+*/
+pub trait CoefSemiring: Sized + Clone + Zero + One + Add<Output = Self> + Mul<Output = Self> {}
+
+impl<T> CoefSemiring for T where T: Clone + Zero + One + Add<Output = T> + Mul<Output = T> {}
+
+/**
+ * Umbrella trait for coefficients, that form a ring:
+ * `CoefSemiring + Neg + Sub`.
+ *
+ * Example:
+ * ```
+ * # use polylib::CoefRing;
+ * # use polylib::polynom::Polynomial;
+ * fn square_at<T: CoefRing>(poly: &Polynomial<T>, point: T) -> T {
+ *     let value: T = poly.substitude(point);
+ *     value.clone() * value
+ * }
+ *
+ * let p = Polynomial::<i32>::from_coefs(vec![-1, 1]); // x - 1
+ * assert_eq!(square_at(&p, 4), 9);
+ * ```
+*/
+pub trait CoefRing: CoefSemiring + Neg<Output = Self> + Sub<Output = Self> {}
+
+impl<T> CoefRing for T where T: CoefSemiring + Neg<Output = T> + Sub<Output = T> {}
+
+/**
+ * Umbrella trait for coefficients, that form a field:
+ * `CoefRing + Div`.
+ *
+*/
+pub trait CoefField: CoefRing + Div<Output = Self> {}
+
+impl<T> CoefField for T where T: CoefRing + Div<Output = T> {}
+
+/**
+ * Means, that value of type `Self` can be substituded
+ * into polynomial with coefficients `T`, and result has type `Y`.
+ *
+ * It is implemented automatically for each `X`, such that
+ * `X: Clone + One + Mul<X, Output = X>`,
+ * `T: Mul<X, Output = Y>` and `Y: Zero + Add<Y, Output = Y>`.
+ * So generic code can write `X: SubstitutablePoint<T, Y>`
+ * instead of listing all the traits for three types.
+ *
+ * Example:
+ * ```
+ * # use polylib::SubstitutablePoint;
+ * # use polylib::polynom::Polynomial;
+ * fn values<T, U, X, Y>(poly: &Polynomial<T, U>, points: Vec<X>) -> Vec<Y>
+ * where
+ *     T: Clone,
+ *     X: SubstitutablePoint<T, Y>,
+ * {
+ *     points.into_iter().map(|x| poly.substitude(x)).collect()
+ * }
+ *
+ * let p = Polynomial::<i32>::from_coefs(vec![1, 0, 1]); // x^2 + 1
+ * assert_eq!(values(&p, vec![0, 1, 2]), vec![1, 2, 5]);
+ * ```
+*/
+pub trait SubstitutablePoint<T, Y>: Clone + One + Mul<Self, Output = Self> {
+    /// Returns `zero` of result type.
+    fn zero_value() -> Y;
+    /// Returns `coef * power`, where `power` is powered point.
+    fn term(coef: T, power: Self) -> Y;
+    /// Returns `lhs + rhs` in result type.
+    fn accumulate(lhs: Y, rhs: Y) -> Y;
+}
+
+impl<X, T, Y> SubstitutablePoint<T, Y> for X
+where
+    X: Clone + One + Mul<X, Output = X>,
+    T: Mul<X, Output = Y>,
+    Y: Zero + Add<Y, Output = Y>,
+{
+    fn zero_value() -> Y {
+        Y::zero()
+    }
+
+    fn term(coef: T, power: Self) -> Y {
+        coef * power
+    }
+
+    fn accumulate(lhs: Y, rhs: Y) -> Y {
+        lhs + rhs
+    }
+}
+
+/**
+ * Means, that value of type `Y` can be got from `Self` at point of type `X`.
+ *
+ * Implemented by `Polynomial`, `DensePolynomial`, `SparsePolynomial`,
+ * `FixedPolynomial` and `SharedPolynomial` for every `X: SubstitutablePoint<T, Y>`,
+ * by `ChebyshevSeries` for `f64`, and by closures `Fn(X) -> Y`,
+ * such as ones returned by `Polynomial::compile`.
+ * So numerical code can be generic over anything, that can be evaluated.
+ *
+ * Example:
+ * ```
+ * # use polylib::Evaluate;
+ * # use polylib::polynom::Polynomial;
+ * // root of f in [a, b] by bisection, f(a) and f(b) have different signs
+ * fn bisect<F: Evaluate<f64, f64>>(f: &F, mut a: f64, mut b: f64) -> f64 {
+ *     for _ in 0..60 {
+ *         let m = (a + b) / 2.0;
+ *         if (f.evaluate(a) < 0.0) == (f.evaluate(m) < 0.0) {
+ *             a = m;
+ *         } else {
+ *             b = m;
+ *         }
+ *     }
+ *     a
+ * }
+ *
+ * let p = Polynomial::<f64>::from_coefs(vec![-2.0, 0.0, 1.0]); // x^2 - 2
+ * assert!((bisect(&p, 0.0, 2.0) - 2f64.sqrt()).abs() < 1e-12);
+ * assert!((bisect(&p.compile::<f64, f64>(), 0.0, 2.0) - 2f64.sqrt()).abs() < 1e-12);
+ * assert!((bisect(&|x: f64| x.cos(), 0.0, 2.0) - std::f64::consts::FRAC_PI_2).abs() < 1e-12);
+ * ```
+*/
+pub trait Evaluate<X, Y> {
+    /// Returns value at point.
+    fn evaluate(&self, point: X) -> Y;
+}
+
+impl<F, X, Y> Evaluate<X, Y> for F
+where
+    F: Fn(X) -> Y,
+{
+    fn evaluate(&self, point: X) -> Y {
+        self(point)
+    }
+}
+
+#[cfg(feature = "derive")]
+pub use polylib_derive::{One, Zero};
@@ -1,13 +1,50 @@
 //! Module, where entire logic of polynomials is presented.
 
 use std::{
+    cmp::Ordering,
     fmt::{Debug, Display},
-    ops::{Add, BitXor, Mul, Neg, Sub},
+    iter::{Product, Sum},
+    collections::BTreeMap,
+    ops::{Add, BitXor, Div, Mul, MulAssign, Neg, Rem, Sub},
 };
 
 use std::marker::PhantomData;
 
-use super::{One, Zero};
+use super::{Evaluate, One, PolyError, SubstitutablePoint, Zero};
+use super::random::{Random, Xorshift64};
+
+pub mod bounded;
+pub mod chebyshev;
+pub mod dense;
+pub mod dump;
+pub mod fixed;
+mod float;
+mod modular;
+pub mod multimodular;
+mod nested;
+mod norm;
+pub mod numbers;
+pub mod ops;
+mod parallel;
+pub mod prepared;
+pub mod range;
+mod roots;
+pub mod shared;
+pub mod sparse;
+pub mod subresultant;
+mod terms;
+
+pub use bounded::Bounded;
+pub use chebyshev::ChebyshevSeries;
+pub use dense::DensePolynomial;
+pub use dump::Dump;
+pub use fixed::FixedPolynomial;
+pub use prepared::PreparedPoint;
+pub use range::EvalRange;
+pub use shared::SharedPolynomial;
+pub use sparse::SparsePolynomial;
+
+use terms::Terms;
 
 /// One of polynomial variable.
 ///
@@ -16,10 +53,10 @@ use super::{One, Zero};
 /// T is type of polynomial coefficients.
 /// It is not stored anywhere and used
 /// for function `pow` to create
-/// polynomial with correct type.
+/// polynomial with correct type,
+/// so only `pow` needs `T: One`.
 ///
-#[derive(Default, Debug, Clone, Copy)]
-pub struct X<T: One>(PhantomData<T>);
+pub struct X<T>(PhantomData<T>);
 
 impl<T: One> X<T> {
     /// Returns polynomial with only one argument.
@@ -48,12 +85,33 @@ impl<T: One> BitXor<u32> for X<T> {
     }
 }
 
-impl<T: One> Display for X<T> {
+impl<T> Display for X<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "x")
     }
 }
 
+// manual impls, so T is not required to implement these traits
+impl<T> Default for X<T> {
+    fn default() -> Self {
+        X(PhantomData)
+    }
+}
+
+impl<T> Clone for X<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for X<T> {}
+
+impl<T> Debug for X<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "X")
+    }
+}
+
 /// One of polynomial variable.
 ///
 /// This one represents letter 'y' in polynomial expressions.
@@ -66,8 +124,7 @@ impl<T: One> Display for X<T> {
 /// Different letters are used for type safety of polynomials.
 /// For example, you can't add x^2 to y^3
 ///
-#[derive(Default, Debug, Clone, Copy)]
-pub struct Y<T: One>(PhantomData<T>);
+pub struct Y<T>(PhantomData<T>);
 
 impl<T: One> Y<T> {
     /// Returns polynomial with only one argument.
@@ -105,17 +162,39 @@ impl<T: One> BitXor<u32> for Y<T> {
     }
 }
 
-impl<T: One> Display for Y<T> {
+impl<T> Display for Y<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "y")
     }
 }
 
+// manual impls, so T is not required to implement these traits
+impl<T> Default for Y<T> {
+    fn default() -> Self {
+        Y(PhantomData)
+    }
+}
+
+impl<T> Clone for Y<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Y<T> {}
+
+impl<T> Debug for Y<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Y")
+    }
+}
+
 // private structure represents polynomial variable T (wich is X<i32> for example)
 // that is powered to power.
+// T is only a marker, so `fn() -> T` keeps Powered `Send` and `Sync`.
 struct Powered<T> {
     power: u32,
-    value: PhantomData<T>,
+    value: PhantomData<fn() -> T>,
 }
 
 impl<T> Powered<T> {
@@ -142,22 +221,53 @@ impl<T> Powered<T> {
             if pow & 1 == 1 {
                 ans = ans * to_mul.clone();
             }
-            to_mul = to_mul.clone() * to_mul;
             pow >>= 1;
+            // last square is not needed and may overflow
+            if pow > 0 {
+                to_mul = to_mul.clone() * to_mul;
+            }
         }
 
         ans
     }
 }
 
+// Powers are added, when members are multiplied.
+// Overflow would silently give wrong low power in release build, so it panics.
 impl<T> Add for Powered<T> {
     type Output = Powered<T>;
 
     fn add(self, rhs: Self) -> Self::Output {
-        Self::Output::new(self.power + rhs.power)
+        match self.power.checked_add(rhs.power) {
+            Some(power) => Self::Output::new(power),
+            None => panic!(
+                "power of x^{} * x^{} overflows u32, use SparsePolynomial for such powers",
+                self.power, rhs.power
+            ),
+        }
     }
 }
 
+// value + value + ... (n times) by doubling, so no conversion from integer is needed
+pub(crate) fn times<T>(value: T, mut n: u32) -> T
+where
+    T: Clone + Zero,
+    T: Add<T, Output = T>,
+{
+    let mut ans = T::zero();
+    let mut to_add = value;
+    while n > 0 {
+        if n & 1 == 1 {
+            ans = ans + to_add.clone();
+        }
+        n >>= 1;
+        if n > 0 {
+            to_add = to_add.clone() + to_add;
+        }
+    }
+    ans
+}
+
 impl<T> Default for Powered<T> {
     fn default() -> Self {
         Powered::<T> {
@@ -176,6 +286,34 @@ impl<T> Clone for Powered<T> {
     }
 }
 
+// writes member coef * var, var is empty for constant member.
+// Coef is dropped, if `try_is_one` is sure, so coefs, that can't be checked
+// (as polynomials), are kept. Precision of formatter (as in `{:.3}`) is passed to coef,
+// and coef, that is sum itself (as nested polynomial), is put in brackets.
+fn write_member<T, V>(
+    f: &mut std::fmt::Formatter<'_>,
+    coef: &T,
+    var: V,
+    constant: bool,
+) -> std::fmt::Result
+where
+    T: Display + One,
+    V: Display,
+{
+    if !constant && coef.try_is_one() == Some(true) {
+        return write!(f, "{}", var);
+    }
+    let coef = match f.precision() {
+        Some(precision) => format!("{:.*}", precision, coef),
+        None => coef.to_string(),
+    };
+    if !constant && coef.contains(" + ") && !coef.starts_with('(') {
+        write!(f, "({}){}", coef, var)
+    } else {
+        write!(f, "{}{}", coef, var)
+    }
+}
+
 impl<T> Debug for Powered<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("Powered")
@@ -208,23 +346,177 @@ where
 /// implement traits: `Clone`, `One`, `Zero`, and corresponded math operators.
 /// Also it might implement trait `Display`, so it was possible to display polynomial.
 ///
+/// Only `+` and `*` are required for construction, `reduce`, `substitude` and `Display`,
+/// so coefficients may form a semiring (see `custom_types::Tropical`).
+/// `Neg` is required only by `-` operators.
+///
 /// #### `U`: type of variable
 /// There is no special requirements to it since it is used to differenciate different polynomials.
 /// But if you would like to use `Display` trait, `U` should implement `Defaul` and `Display`.
 ///
-#[derive(Debug, Default)]
+/// # Memory
+/// First few members are stored inside the structure, so creating and cloning
+/// short polynomials does not allocate.
+///
+/// `+`, `-` and `*` don't sum up members with the same power, so long expressions
+/// should be reduced from time to time, or automatically (see `with_auto_reduce`).
+///
 pub struct Polynomial<T, U = X<T>> {
-    members: Vec<(T, Powered<U>)>,
+    members: Terms<(T, Powered<U>)>,
+    auto_reduce: Option<AutoReduce<T, U>>,
+    // powers of members strictly increase (coefs may be zero),
+    // so `get` and `degree` need no reduction
+    sorted: bool,
+}
+
+// Policy of `with_auto_reduce`. Operators have no bounds on coefs,
+// so `reduce_in_place` is captured as function pointer, where bounds are known.
+struct AutoReduce<T, U> {
+    threshold: usize,
+    reduce: fn(&mut Polynomial<T, U>),
+}
+
+impl<T, U> Clone for AutoReduce<T, U> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, U> Copy for AutoReduce<T, U> {}
+
+impl<T, U> Default for Polynomial<T, U> {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl<T, U> Polynomial<T, U> {
     fn new() -> Polynomial<T, U> {
-        let v = Vec::<(T, Powered<U>)>::new();
-        Polynomial::<T, U> { members: v }
+        let v = Terms::<(T, Powered<U>)>::new();
+        Polynomial::<T, U> {
+            members: v,
+            auto_reduce: None,
+            sorted: true,
+        }
     }
     fn push(&mut self, coef: T, var: Powered<U>) {
+        if let Some((_, last)) = self.members.last() {
+            self.sorted &= last.power < var.power;
+        }
         self.members.push((coef, var));
     }
+    // reduces polynomial, if it has auto-reduce policy and too many members
+    fn auto_reduce(&mut self) {
+        if let Some(policy) = self.auto_reduce {
+            if self.members.len() > policy.threshold {
+                (policy.reduce)(self);
+            }
+        }
+    }
+
+    /// Returns same polynomial, that is reduced automatically,
+    /// whenever it has more than `threshold` members after `+`, `-`, `*=`,
+    /// `pow` or `product`. Products are also reduced while they are computed.
+    ///
+    /// Result of operation keeps policy of the left operand, or of the right one,
+    /// if the left has no policy. Polynomial is reduced at once,
+    /// if it already has more members.
+    ///
+    /// Note, that `*` DROPS policy: it may change type of coefs, and policy
+    /// can't be moved to other type. So `p.with_auto_reduce(n) * q` is neither
+    /// reduced, nor keeps policy. Use `*=` or `mul_keeping_policy` instead.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::polynom::X;
+    /// # let x = X::<i32>::default();
+    /// let mut p = (x.pow(1) + 1).with_auto_reduce(8);
+    /// for _ in 0..100 {
+    ///     p = p + x.pow(1) - x.pow(1);
+    /// }
+    /// assert!(p.len() <= 8);
+    /// let q = p.clone().pow(10); // (x + 1)^10 is reduced after each multiplication
+    /// assert!(q.len() <= 11);
+    /// assert_eq!(q.substitude(-1), 0);
+    ///
+    /// // `*` drops policy, `*=` keeps it
+    /// assert_eq!((p.clone() * p.clone()).auto_reduce_threshold(), None);
+    /// let mut q = p.clone();
+    /// q *= p;
+    /// assert_eq!(q.auto_reduce_threshold(), Some(8));
+    /// ```
+    pub fn with_auto_reduce(mut self, threshold: usize) -> Polynomial<T, U>
+    where
+        T: Zero,
+        T: Add<T, Output = T>,
+    {
+        self.auto_reduce = Some(AutoReduce {
+            threshold,
+            reduce: Self::reduce_in_place,
+        });
+        self.auto_reduce();
+        self
+    }
+
+    /// Returns same polynomial without auto-reduce policy.
+    pub fn without_auto_reduce(mut self) -> Polynomial<T, U> {
+        self.auto_reduce = None;
+        self
+    }
+
+    /// Returns threshold of auto-reduce policy, if polynomial has it.
+    pub fn auto_reduce_threshold(&self) -> Option<usize> {
+        self.auto_reduce.map(|policy| policy.threshold)
+    }
+
+    /// Returns product, that keeps auto-reduce policy of operands
+    /// (see `with_auto_reduce`) and is reduced while it is computed.
+    /// Same as `*=`, while `*` drops policy.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::polynom::Polynomial;
+    /// let p = Polynomial::<i32>::from_coefs(vec![1, 1]).with_auto_reduce(4);
+    /// let q = Polynomial::<i32>::from_coefs(vec![1, 1, 1, 1]);
+    /// let product = p.clone().mul_keeping_policy(q.clone());
+    /// assert_eq!(product.auto_reduce_threshold(), Some(4));
+    /// assert_eq!(product.len(), 5);
+    /// assert_eq!((p * q).len(), 8);
+    /// ```
+    pub fn mul_keeping_policy(self, rhs: Polynomial<T, U>) -> Polynomial<T, U>
+    where
+        T: Clone,
+        T: Mul<T, Output = T>,
+    {
+        let policy = self.auto_reduce.or(rhs.auto_reduce);
+        let Some(policy) = policy.filter(|policy| policy.threshold > 0) else {
+            let mut ans = self * rhs;
+            ans.auto_reduce = policy;
+            ans.auto_reduce();
+            return ans;
+        };
+        let mut ans = Polynomial::new();
+        ans.auto_reduce = Some(policy);
+        let len = self.members.len() * rhs.members.len();
+        ans.members.reserve(len.min(2 * policy.threshold));
+        // product has len(self) * len(rhs) members, so it is reduced on the way,
+        // but only when it doubles since the last reduce, so the work stays linear
+        let mut reduced_len = 0;
+        for memb1 in self.members {
+            for memb2 in &rhs.members {
+                ans.push(
+                    memb1.0.clone() * memb2.0.clone(),
+                    memb1.1.clone() + memb2.1.clone(),
+                );
+                if ans.members.len() >= 2 * policy.threshold.max(reduced_len) {
+                    (policy.reduce)(&mut ans);
+                    reduced_len = ans.members.len();
+                }
+            }
+        }
+        ans.auto_reduce();
+        ans
+    }
 
     /// Returns polynomial with coefs.
     /// 
@@ -250,6 +542,127 @@ impl<T, U> Polynomial<T, U> {
         v
     }
 
+    /// Same as `from_coefs`, but zero coefs are kept as members,
+    /// so coefs need no `Zero` (as functions or intervals, that can't tell zero).
+    /// Zero members are dropped later by `reduce`, if coefs have `Zero`.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::polynom::Polynomial;
+    /// let p = Polynomial::<i32>::from_coefs_with_zeros(vec![1, 0, 0, 1]); // is x^3 + 1
+    /// assert_eq!(p.len(), 4);
+    /// assert_eq!(p.reduce().len(), 2);
+    /// ```
+    pub fn from_coefs_with_zeros(coefs: Vec<T>) -> Polynomial<T, U> {
+        let mut v = Polynomial::<T, U>::new();
+        v.reserve(coefs.len());
+        for (power, c) in coefs.into_iter().enumerate() {
+            v.push(c, Powered::<U>::new(power as u32));
+        }
+        v
+    }
+
+    /// Returns polynomial with members (power, coef) in given order.
+    /// Members are kept as is, so coefs need neither `Zero` nor `Add`,
+    /// members with the same power are summed up by `reduce`.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::polynom::Polynomial;
+    /// let p = Polynomial::<i32>::from_terms(vec![(3, 2), (0, 5), (3, 1)]);
+    /// assert_eq!(p.len(), 3);
+    /// assert_eq!(p, Polynomial::from_coefs(vec![5, 0, 0, 3]));
+    /// ```
+    pub fn from_terms<I>(terms: I) -> Polynomial<T, U>
+    where
+        I: IntoIterator<Item = (u32, T)>,
+    {
+        let mut v = Polynomial::<T, U>::new();
+        for (power, c) in terms {
+            v.push(c, Powered::<U>::new(power));
+        }
+        v
+    }
+
+    /// Returns polynomial with coefs from dense slice.
+    ///
+    /// Dense layout is the same for `from_dense_slice` and `as_dense_slice_with`:
+    /// element with index `i` is coef of x^i, zero coefs are stored explicitly,
+    /// so `&[T]` can be passed from/to C (`const T*` with length) or numpy
+    /// (`numpy.polynomial` order, reversed to `numpy.polyval` order) as is.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::polynom::Polynomial;
+    /// let data = [1.0, 0.0, -2.0];
+    /// let p = Polynomial::<f64>::from_dense_slice(&data); // -2x^2 + 1
+    /// assert_eq!(p.len(), 2);
+    /// assert_eq!(p.as_dense_slice_with(4), vec![1.0, 0.0, -2.0, 0.0]);
+    /// ```
+    pub fn from_dense_slice(coefs: &[T]) -> Polynomial<T, U>
+    where
+        T: Clone + Zero,
+    {
+        let mut v = Polynomial::<T, U>::new();
+        v.reserve(coefs.iter().filter(|c| !c.is_zero()).count());
+        for (power, c) in coefs.iter().enumerate() {
+            if !c.is_zero() {
+                v.push(c.clone(), Powered::<U>::new(power as u32));
+            }
+        }
+        v
+    }
+
+    /// Returns `len` coefs of x^0, x^1, ..., x^(len-1) in dense layout
+    /// (see `from_dense_slice`). Members with the same power are summed up,
+    /// missing coefs are zeros.
+    ///
+    /// Panics, if degree of polynomial is not less than `len`,
+    /// so coefs are never silently dropped.
+    pub fn as_dense_slice_with(&self, len: usize) -> Vec<T>
+    where
+        T: Clone + Zero,
+        T: Add<T, Output = T>,
+    {
+        let reduced = self.clone().reduce();
+        if let Some((_, pow)) = reduced.members.last() {
+            if pow.power as usize >= len {
+                panic!("polynomial of degree {} doesn't fit into {} coefs", pow.power, len);
+            }
+        }
+        let mut coefs: Vec<T> = (0..len).map(|_| T::zero()).collect();
+        for (coef, pow) in reduced.members {
+            coefs[pow.power as usize] = coef;
+        }
+        coefs
+    }
+
+    /// Returns random polynomial of given degree.
+    /// Leading coef is never zero.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::polynom::Polynomial;
+    /// # use polylib::custom_types::Zn;
+    /// # use polylib::random::Xorshift64;
+    /// let mut rng = Xorshift64::new(1);
+    /// let p = Polynomial::<Zn<7>>::random(3, &mut rng);
+    /// assert!(!p.coef(3).is_zero());
+    /// # use polylib::Zero;
+    /// ```
+    pub fn random(degree: u32, rng: &mut Xorshift64) -> Polynomial<T, U>
+    where
+        T: Random + Zero,
+    {
+        let mut coefs: Vec<T> = (0..degree).map(|_| T::random(rng)).collect();
+        let mut leading = T::random(rng);
+        while leading.is_zero() {
+            leading = T::random(rng);
+        }
+        coefs.push(leading);
+        Self::from_coefs(coefs)
+    }
+
     /// Returns const polynomial.
     /// 
     /// Example:
@@ -280,8 +693,27 @@ impl<T, U> Polynomial<T, U> {
         T: Mul<T, Output = T>,
         T: One,
     {
-        let powered = Powered::<U>::new(power);
-        powered.substitude(self)
+        // square-and-multiply, that keeps auto-reduce policy
+        let mut ans: Option<Polynomial<T, U>> = None;
+        let mut to_mul = self;
+        let mut pow = power;
+        while pow > 0 {
+            if pow & 1 == 1 {
+                ans = Some(match ans {
+                    Some(ans) => ans.mul_keeping_policy(to_mul.clone()),
+                    None => to_mul.clone(),
+                });
+            }
+            pow >>= 1;
+            if pow > 0 {
+                to_mul = to_mul.clone().mul_keeping_policy(to_mul);
+            }
+        }
+        ans.unwrap_or_else(|| {
+            let mut one = Polynomial::one();
+            one.auto_reduce = to_mul.auto_reduce;
+            one
+        })
     }
 
     /// Calculate value of polynom at point 
@@ -300,17 +732,13 @@ impl<T, U> Polynomial<T, U> {
     /// ```
     pub fn substitude<X, Y>(&self, point: X) -> Y
     where
-        X: Clone + One,
-        Y: Zero,
         T: Clone,
-        X: Mul<X, Output = X>,
-        Y: Add<Y, Output = Y>,
-        T: Mul<X, Output = Y>,
+        X: SubstitutablePoint<T, Y>,
     {
-        let mut ans = Y::zero();
+        let mut ans = X::zero_value();
         for (coef, var) in self.members.iter() {
             let rhs = var.substitude(point.clone());
-            ans = ans + coef.clone() * rhs;
+            ans = X::accumulate(ans, X::term(coef.clone(), rhs));
         }
         ans
     }
@@ -348,6 +776,56 @@ impl<T, U> Polynomial<T, U> {
         ans
     }
 
+    /// Returns function, that calculates value of polynom at point,
+    /// same as `substitude`.
+    ///
+    /// Polynomial is reduced and its coefs are stored from the highest power,
+    /// so each call is Horner's method: one multiplication per coef
+    /// (and fast powering of point over gaps of missing powers).
+    /// Use it to evaluate one polynomial at many points.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::polynom::X;
+    /// # let x = X::<f64>::default();
+    /// let p = x.pow(3) * 2.0 - x.pow(1) + 0.5;
+    /// let f = p.compile::<f64, f64>();
+    /// let values: Vec<f64> = (0..4).map(|i| f(i as f64)).collect();
+    /// assert_eq!(values, vec![0.5, 1.5, 14.5, 51.5]);
+    /// ```
+    pub fn compile<X, Y>(&self) -> impl Fn(X) -> Y
+    where
+        T: Clone + Zero,
+        T: Add<T, Output = T>,
+        X: SubstitutablePoint<T, Y>,
+        Y: Mul<X, Output = Y>,
+    {
+        let members: Vec<(T, u32)> = self
+            .clone()
+            .reduce()
+            .members
+            .into_iter()
+            .map(|(coef, pow)| (coef, pow.power))
+            .collect();
+        // (coef, power - next lower power), from the highest power
+        let mut horner: Vec<(T, u32)> = Vec::with_capacity(members.len());
+        let mut iter = members.into_iter().rev().peekable();
+        while let Some((coef, power)) = iter.next() {
+            let lower = iter.peek().map_or(0, |(_, lower)| *lower);
+            horner.push((coef, power - lower));
+        }
+        move |point: X| {
+            let mut ans = X::zero_value();
+            for (coef, gap) in &horner {
+                ans = X::accumulate(ans, X::term(coef.clone(), X::one()));
+                if *gap > 0 {
+                    ans = ans * Powered::<U>::new(*gap).substitude(point.clone());
+                }
+            }
+            ans
+        }
+    }
+
     /// Return polynomial in shortest form possible
     /// 
     /// For exmaple, we make this polynomial:
@@ -379,32 +857,254 @@ impl<T, U> Polynomial<T, U> {
     /// ```
     pub fn reduce(mut self) -> Polynomial<T, U>
     where
-        T: Clone + Zero,
+        T: Zero,
+        T: Add<T, Output = T>,
+    {
+        self.reduce_in_place();
+        self
+    }
+
+    /// Same as `reduce`, but works in place: members are sorted by power
+    /// (unless they already are), merged and zeros are dropped
+    /// without cloning coefs or allocating. Capacity is kept, see `compact`.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::polynom::X;
+    /// # let x = X::<i32>::default();
+    /// let mut p = x.pow(2) * 2 + 1 - 1 - x.pow(2) - x.pow(2) + 1;
+    /// p.reduce_in_place();
+    /// assert_eq!(p.len(), 1);
+    /// assert_eq!(p.coef(0), 1);
+    /// ```
+    pub fn reduce_in_place(&mut self)
+    where
+        T: Zero,
         T: Add<T, Output = T>,
     {
         if self.members.is_empty() {
-            return self;
+            self.sorted = true;
+            return;
         }
-        self.members.sort_by_key(|(_, power)| power.power);
-        let mut ans = Polynomial::new();
-        let (mut coef, mut pow) = self.members[0].clone();
-        for i in 1..self.members.len() {
-            if self.members[i].1.power == pow.power {
-                coef = coef + self.members[i].0.clone();
+        // stable sort keeps order of summands with the same power
+        if !self.sorted && !self.members.is_sorted_by_key(|(_, power)| power.power) {
+            self.members.sort_by_key(|(_, power)| power.power);
+        }
+        let members = &mut *self.members;
+        // members[..last] are merged, members[last] is being summed up
+        let mut last = 0;
+        for i in 1..members.len() {
+            if members[i].1.power == members[last].1.power {
+                let coef = std::mem::replace(&mut members[i].0, T::zero());
+                let sum = std::mem::replace(&mut members[last].0, T::zero());
+                members[last].0 = sum + coef;
                 continue;
             }
-            if !coef.is_zero() {
-                ans.push(coef, pow);
+            if !members[last].0.is_zero() {
+                last += 1;
             }
-            coef = self.members[i].0.clone();
-            pow = self.members[i].1.clone();
+            members.swap(last, i);
         }
-        if !coef.is_zero() {
-            ans.push(coef, pow);
+        if !members[last].0.is_zero() {
+            last += 1;
         }
+        self.members.truncate(last);
+        self.sorted = true;
+    }
+
+    /// Reserves capacity for at least `additional` more members,
+    /// so following `+` and `-` do not reallocate.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::polynom::Polynomial;
+    /// let mut p = Polynomial::<i32>::new_const(1);
+    /// p.reserve(10);
+    /// assert!(p.capacity() >= 11);
+    /// ```
+    pub fn reserve(&mut self, additional: usize) {
+        self.members.reserve(additional);
+    }
+
+    /// Shrinks capacity of data vector as much as possible.
+    ///
+    /// Few first members are stored without allocation,
+    /// so capacity never gets less than that count.
+    pub fn shrink_to_fit(&mut self) {
+        self.members.shrink_to_fit();
+    }
+
+    /// Returns count of members, that polynomial can hold without reallocation.
+    pub fn capacity(&self) -> usize {
+        self.members.capacity()
+    }
+
+    /// Returns reduced polynomial, that holds no extra memory.
+    ///
+    /// It is `reduce` followed by `shrink_to_fit`.
+    /// Use it after long chains of `+` and `-`, which only append members.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::polynom::X;
+    /// # let x = X::<i32>::default();
+    /// let mut p = x.pow(1) * 0;
+    /// for _ in 0..100 {
+    ///     p = p + x.pow(1) - x.pow(1);
+    /// }
+    /// assert_eq!(p.len(), 201);
+    /// let p = p.compact();
+    /// assert_eq!(p.len(), 0);
+    /// assert!(p.capacity() < 201);
+    /// ```
+    pub fn compact(self) -> Polynomial<T, U>
+    where
+        T: Clone + Zero,
+        T: Add<T, Output = T>,
+    {
+        let mut ans = self.reduce();
+        ans.shrink_to_fit();
         ans
     }
 
+    // long division, returns None for zero rhs, or if `exact(q, rhs_lead, lead)`
+    // rejects some step with q = lead / rhs_lead, so truncated division may be detected
+    fn long_div_rem(
+        &self,
+        rhs: &Polynomial<T, U>,
+        exact: impl Fn(&T, &T, &T) -> bool,
+    ) -> Option<(Self, Self)>
+    where
+        T: Clone + Zero,
+        T: Add<T, Output = T>,
+        T: Sub<T, Output = T>,
+        T: Mul<T, Output = T>,
+        T: Div<T, Output = T>,
+    {
+        let divisor = rhs.clone().reduce();
+        let (lead_coef, lead_pow) = divisor
+            .members
+            .last()
+            .map(|(coef, pow)| (coef.clone(), pow.power))?;
+
+        let mut rem = BTreeMap::new();
+        for (coef, pow) in self.clone().reduce().members {
+            rem.insert(pow.power, coef);
+        }
+        let mut quotient = Polynomial::new();
+        while let Some((&pow, _)) = rem.last_key_value() {
+            if pow < lead_pow {
+                break;
+            }
+            let lead = rem.remove(&pow).expect("key exists");
+            let coef = lead.clone() / lead_coef.clone();
+            if !exact(&coef, &lead_coef, &lead) {
+                return None;
+            }
+            let shift = pow - lead_pow;
+            // leading member is already removed
+            for (d_coef, d_pow) in &divisor.members[..divisor.members.len() - 1] {
+                let entry = rem.entry(d_pow.power + shift).or_insert_with(T::zero);
+                *entry = entry.clone() - coef.clone() * d_coef.clone();
+                if entry.is_zero() {
+                    rem.remove(&(d_pow.power + shift));
+                }
+            }
+            quotient.push(coef, Powered::new(shift));
+        }
+
+        let mut remainder = Polynomial::new();
+        for (pow, coef) in rem {
+            remainder.push(coef, Powered::new(pow));
+        }
+        // quotient powers strictly decrease
+        quotient.members.reverse();
+        quotient.sorted = true;
+        Some((quotient, remainder))
+    }
+
+    /// Divides polynomial by rhs with remainder: self = q * rhs + r,
+    /// where degree of r is less than degree of rhs.
+    ///
+    /// Returns `PolyError::DivisionByZero` if rhs is zero polynomial.
+    /// Coefficients should form a field (or at least leading coef of rhs
+    /// should divide all the coefs, that it meets), otherwise quotient is truncated
+    /// as coefs division is. Use `try_div_exact` to check divisibility in rings.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::polynom::Polynomial;
+    /// # use polylib::PolyError;
+    /// let a = Polynomial::<f64>::from_coefs(vec![-1.0, 0.0, 1.0]); // x^2 - 1
+    /// let b = Polynomial::<f64>::from_coefs(vec![1.0, 1.0]);       // x + 1
+    /// let (q, r) = a.checked_div_rem(&b).unwrap();
+    /// assert_eq!(q.substitude(3.0), 2.0); // q is x - 1
+    /// assert!(r.is_empty());
+    ///
+    /// assert_eq!(b.checked_div_rem(&Polynomial::default()).unwrap_err(), PolyError::DivisionByZero);
+    /// ```
+    pub fn checked_div_rem(
+        &self,
+        rhs: &Polynomial<T, U>,
+    ) -> Result<(Self, Self), PolyError>
+    where
+        T: Clone + Zero,
+        T: Add<T, Output = T>,
+        T: Sub<T, Output = T>,
+        T: Mul<T, Output = T>,
+        T: Div<T, Output = T>,
+    {
+        self.long_div_rem(rhs, |_, _, _| true).ok_or(PolyError::DivisionByZero)
+    }
+
+    /// Same as `checked_div_rem`, but panics on division by zero.
+    pub fn div_rem(&self, rhs: &Polynomial<T, U>) -> (Polynomial<T, U>, Polynomial<T, U>)
+    where
+        T: Clone + Zero,
+        T: Add<T, Output = T>,
+        T: Sub<T, Output = T>,
+        T: Mul<T, Output = T>,
+        T: Div<T, Output = T>,
+    {
+        match self.checked_div_rem(rhs) {
+            Ok(ans) => ans,
+            Err(err) => panic!("can't divide polynomials: {}", err),
+        }
+    }
+
+    /// Returns quotient of self and rhs, if rhs divides self exactly,
+    /// otherwise (or if rhs is zero polynomial) returns None.
+    ///
+    /// Unlike `checked_div_rem`, coefficients only need to form a ring:
+    /// every division by leading coef of rhs is checked to be exact,
+    /// so integer coefs are never truncated.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::polynom::Polynomial;
+    /// let a = Polynomial::<i32>::from_coefs(vec![6, 8, 2]); // 2x^2 + 8x + 6
+    /// let b = Polynomial::<i32>::from_coefs(vec![2, 2]);    // 2x + 2
+    /// let q = a.try_div_exact(&b).unwrap();
+    /// assert_eq!(q, Polynomial::from_coefs(vec![3, 1]));
+    ///
+    /// // 2x + 2 = 2 * (x + 1), but 1/2 is not an integer
+    /// let c = Polynomial::<i32>::from_coefs(vec![1, 1]);
+    /// assert!(c.try_div_exact(&b).is_none());
+    /// assert!(b.try_div_exact(&c).is_some());
+    /// ```
+    pub fn try_div_exact(&self, rhs: &Polynomial<T, U>) -> Option<Polynomial<T, U>>
+    where
+        T: Clone + Zero + PartialEq,
+        T: Add<T, Output = T>,
+        T: Sub<T, Output = T>,
+        T: Mul<T, Output = T>,
+        T: Div<T, Output = T>,
+    {
+        let (quotient, remainder) =
+            self.long_div_rem(rhs, |q, rhs_lead, lead| q.clone() * rhs_lead.clone() == *lead)?;
+        remainder.members.is_empty().then_some(quotient)
+    }
+
     /// Returns coeff of x^index
     /// 
     /// Example:
@@ -417,13 +1117,75 @@ impl<T, U> Polynomial<T, U> {
     /// assert!(p.get(2).is_none());                // there is no x^2, so get(2) returns none
     /// ```
     pub fn get(&self, index: u32) -> Option<&T> {
+        if self.sorted {
+            return self
+                .members
+                .binary_search_by_key(&index, |(_, pow)| pow.power)
+                .ok()
+                .map(|i| &self.members[i].0);
+        }
         for memb in &self.members {
             if memb.1.power != index {
                 continue;
             }
             return Some(&memb.0);
         }
-        return None;
+        None
+    }
+
+    /// Returns coef of x^index by value: zero for missing power,
+    /// members with the same power are summed up.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::polynom::X;
+    /// # let x = X::<i32>::default();
+    /// let p = x.pow(3) * 2 + 1 + x.pow(3); // is 3x^3 + 1
+    /// assert_eq!(p.coef(3), 3);
+    /// assert_eq!(p.coef(2), 0);
+    /// ```
+    pub fn coef(&self, index: u32) -> T
+    where
+        T: Clone + Zero,
+        T: Add<T, Output = T>,
+    {
+        if self.sorted {
+            return self.get(index).cloned().unwrap_or_else(T::zero);
+        }
+        self.members
+            .iter()
+            .filter(|(_, pow)| pow.power == index)
+            .fold(T::zero(), |acc, (coef, _)| acc + coef.clone())
+    }
+
+    /// Returns degree of polynomial, or `None` for zero polynomial.
+    /// Members with the same power are summed up first, unless powers
+    /// of members already increase (as after `from_coefs` or `reduce`),
+    /// then the highest member with nonzero coef is taken without copying.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::polynom::X;
+    /// # let x = X::<i32>::default();
+    /// let p = x.pow(3) * 2 + x.pow(1) - x.pow(3) * 2; // is x
+    /// assert_eq!(p.degree(), Some(1));
+    /// assert_eq!((p.clone() - p).degree(), None);
+    /// ```
+    pub fn degree(&self) -> Option<u32>
+    where
+        T: Clone + Zero,
+        T: Add<T, Output = T>,
+    {
+        if self.sorted {
+            return self
+                .members
+                .iter()
+                .rev()
+                .find(|(coef, _)| !coef.is_zero())
+                .map(|(_, pow)| pow.power);
+        }
+        let reduced = self.clone().reduce();
+        reduced.members.last().map(|(_, pow)| pow.power)
     }
 
     /// Returns len of data vector
@@ -439,15 +1201,150 @@ impl<T, U> Polynomial<T, U> {
     pub fn len(&self) -> usize {
         self.members.len()
     }
+
+    /// Returns true if data vector is empty
+    /// 
+    /// Example:
+    /// ```
+    /// # use polylib::polynom::Polynomial;
+    /// let p = Polynomial::<i32>::from_coefs(vec![0, 0]);
+    /// assert!(p.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.members.is_empty()
+    }
+
+    /// Folds members in order of storage: `f(acc, power, &coef)` is called
+    /// for every member. Members with the same power are not summed up,
+    /// so reduce polynomial first, if it matters.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::polynom::Polynomial;
+    /// let p = Polynomial::<i32>::from_coefs(vec![1, 2, 0, -3]);
+    /// assert_eq!(p.fold_terms(0, |acc, _, coef| acc + coef), 0); // p(1)
+    /// assert_eq!(p.fold_terms(0, |acc, power, _| acc + power % 2), 2); // odd powers
+    /// ```
+    pub fn fold_terms<A, F>(&self, init: A, mut f: F) -> A
+    where
+        F: FnMut(A, u32, &T) -> A,
+    {
+        let mut acc = init;
+        for (coef, pow) in self.members.iter() {
+            acc = f(acc, pow.power, coef);
+        }
+        acc
+    }
+
+    /// Calls `f(power, &coef)` for every member in order of storage,
+    /// same as `fold_terms` without accumulator.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::polynom::Polynomial;
+    /// let p = Polynomial::<i32>::from_coefs(vec![5, 0, 7]);
+    /// let mut out = String::new();
+    /// p.visit_terms(|power, coef| out += &format!("{}:{};", power, coef));
+    /// assert_eq!(out, "0:5;2:7;");
+    /// ```
+    pub fn visit_terms<F>(&self, mut f: F)
+    where
+        F: FnMut(u32, &T),
+    {
+        for (coef, pow) in self.members.iter() {
+            f(pow.power, coef);
+        }
+    }
+
+    /// Keeps only members, for which `f(power, &coef)` returns true,
+    /// in place and in the same order. Members with the same power
+    /// are not summed up, so reduce polynomial first, if it matters.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::polynom::Polynomial;
+    /// let mut p = Polynomial::<f64>::from_coefs(vec![1.0, 2.0, 0.001, 4.0, 5.0]);
+    /// p.retain_terms(|power, _| power % 2 == 0);
+    /// assert_eq!(p.to_string(), "1 + 0.001x^2 + 5x^4");
+    /// p.retain_terms(|_, coef| coef.abs() > 0.01);
+    /// assert_eq!(p.to_string(), "1 + 5x^4");
+    /// ```
+    pub fn retain_terms<F>(&mut self, mut f: F)
+    where
+        F: FnMut(u32, &T) -> bool,
+    {
+        // kept members are moved to the front, order is preserved
+        let mut kept = 0;
+        for i in 0..self.members.len() {
+            let (coef, pow) = &self.members[i];
+            if f(pow.power, coef) {
+                self.members.swap(kept, i);
+                kept += 1;
+            }
+        }
+        self.members.truncate(kept);
+    }
+}
+
+impl<T, U, X, Y> Evaluate<X, Y> for Polynomial<T, U>
+where
+    T: Clone,
+    X: SubstitutablePoint<T, Y>,
+{
+    fn evaluate(&self, point: X) -> Y {
+        self.substitude(point)
+    }
+}
+
+/// Constant polynomial, same as `new_const`.
+///
+/// Example:
+/// ```
+/// # use polylib::polynom::Polynomial;
+/// fn add_const<T: polylib::CoefRing>(p: Polynomial<T>, c: T) -> Polynomial<T> {
+///     p + Polynomial::from(c)
+/// }
+/// let p = add_const(Polynomial::from_coefs(vec![1, 1]), 2);
+/// assert_eq!(p, Polynomial::from_coefs(vec![3, 1]));
+/// ```
+impl<T, U> From<T> for Polynomial<T, U> {
+    fn from(value: T) -> Self {
+        Polynomial::new_const(value)
+    }
+}
+
+/// Single term `coef * x^power` from pair (power, coef), as in `from_terms`.
+///
+/// Example:
+/// ```
+/// # use polylib::polynom::Polynomial;
+/// let p: Polynomial<i32> = (3, 2).into(); // 2x^3
+/// assert_eq!(p, Polynomial::from_coefs(vec![0, 0, 0, 2]));
+/// ```
+impl<T, U> From<(u32, T)> for Polynomial<T, U> {
+    fn from((power, coef): (u32, T)) -> Self {
+        let mut ans = Polynomial::new();
+        ans.push(coef, Powered::new(power));
+        ans
+    }
+}
+
+/// Polynomial with coefs, same as `from_coefs`.
+impl<T: Zero, U> From<Vec<T>> for Polynomial<T, U> {
+    fn from(coefs: Vec<T>) -> Self {
+        Polynomial::from_coefs(coefs)
+    }
 }
 
 impl<T, U> Add for Polynomial<T, U> {
     type Output = Polynomial<T, U>;
 
     fn add(mut self, rhs: Self) -> Self::Output {
+        self.auto_reduce = self.auto_reduce.or(rhs.auto_reduce);
         for memb in rhs.members {
             self.push(memb.0, memb.1);
         }
+        self.auto_reduce();
         self
     }
 }
@@ -457,6 +1354,7 @@ impl<T, U> Add<T> for Polynomial<T, U> {
 
     fn add(mut self, rhs: T) -> Self::Output {
         self.push(rhs, Powered::<U>::default());
+        self.auto_reduce();
         self
     }
 }
@@ -469,6 +1367,7 @@ where
 
     fn neg(self) -> Self::Output {
         let mut ans = Self::Output::new();
+        ans.auto_reduce = self.auto_reduce;
         ans.members.reserve(self.members.len());
         for memb in self.members {
             ans.push(-memb.0, memb.1);
@@ -485,6 +1384,7 @@ where
 
     fn sub(mut self, rhs: A) -> Self::Output {
         self.push(-rhs, Powered::<U>::default());
+        self.auto_reduce();
         self
     }
 }
@@ -509,6 +1409,7 @@ where
 
     fn mul(self, rhs: T) -> Self::Output {
         let mut ans = Self::Output::new();
+        ans.auto_reduce = self.auto_reduce;
         ans.members.reserve(self.members.len());
         for memb in self.members {
             ans.push(memb.0 * rhs.clone(), memb.1);
@@ -517,6 +1418,8 @@ where
     }
 }
 
+/// Product has no auto-reduce policy, as type of coefs may change.
+/// Use `*=` or `Polynomial::mul_keeping_policy` to keep it.
 impl<T, U> Mul for Polynomial<T, U>
 where
     T: Clone,
@@ -539,6 +1442,86 @@ where
     }
 }
 
+/// Same as `Polynomial::mul_keeping_policy`: unlike `*`, keeps auto-reduce
+/// policy of operands (see `Polynomial::with_auto_reduce`).
+impl<T, U> MulAssign for Polynomial<T, U>
+where
+    T: Clone,
+    T: Mul<T, Output = T>,
+{
+    fn mul_assign(&mut self, rhs: Polynomial<T, U>) {
+        let lhs = std::mem::take(self);
+        *self = lhs.mul_keeping_policy(rhs);
+    }
+}
+
+impl<T, U> Div for Polynomial<T, U>
+where
+    T: Clone + Zero,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Mul<T, Output = T>,
+    T: Div<T, Output = T>,
+{
+    type Output = Polynomial<T, U>;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        self.div_rem(&rhs).0
+    }
+}
+
+impl<T, U> Rem for Polynomial<T, U>
+where
+    T: Clone + Zero,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Mul<T, Output = T>,
+    T: Div<T, Output = T>,
+{
+    type Output = Polynomial<T, U>;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        self.div_rem(&rhs).1
+    }
+}
+
+impl<T, U> Sum for Polynomial<T, U> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::new(), |acc, poly| acc + poly)
+    }
+}
+
+impl<'a, T, U> Sum<&'a Polynomial<T, U>> for Polynomial<T, U>
+where
+    T: Clone + 'a,
+    U: 'a,
+{
+    fn sum<I: Iterator<Item = &'a Polynomial<T, U>>>(iter: I) -> Self {
+        iter.fold(Self::new(), |acc, poly| acc + poly.clone())
+    }
+}
+
+impl<T, U> Product for Polynomial<T, U>
+where
+    T: Clone + One,
+    T: Mul<T, Output = T>,
+{
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::one(), |acc, poly| acc.mul_keeping_policy(poly))
+    }
+}
+
+impl<'a, T, U> Product<&'a Polynomial<T, U>> for Polynomial<T, U>
+where
+    T: Clone + One + 'a,
+    T: Mul<T, Output = T>,
+    U: 'a,
+{
+    fn product<I: Iterator<Item = &'a Polynomial<T, U>>>(iter: I) -> Self {
+        iter.fold(Self::one(), |acc, poly| acc.mul_keeping_policy(poly.clone()))
+    }
+}
+
 impl<T, U> One for Polynomial<T, U>
 where
     T: One,
@@ -550,6 +1533,16 @@ where
     fn is_one(&self) -> bool {
         panic!("is_one - hard operation for polynom");
     }
+
+    // members with different powers can't cancel, so single member is enough
+    fn try_is_one(&self) -> Option<bool> {
+        match &self.members[..] {
+            [] => Some(false),
+            [(coef, pow)] if pow.power == 0 => coef.try_is_one(),
+            [_] => Some(false),
+            _ => None,
+        }
+    }
 }
 
 impl<T, U> Zero for Polynomial<T, U>
@@ -563,6 +1556,22 @@ where
     fn is_zero(&self) -> bool {
         panic!("is_zero - hard operation for polynom");
     }
+
+    // powers of sorted members are different, so coefs can be checked one by one
+    fn try_is_zero(&self) -> Option<bool> {
+        if !self.sorted {
+            return if self.members.is_empty() { Some(true) } else { None };
+        }
+        let mut ans = Some(true);
+        for (coef, _) in self.members.iter() {
+            match coef.try_is_zero() {
+                Some(true) => {}
+                Some(false) => return Some(false),
+                None => ans = None,
+            }
+        }
+        ans
+    }
 }
 
 impl<T, U> Clone for Polynomial<T, U>
@@ -572,37 +1581,173 @@ where
     fn clone(&self) -> Self {
         Self {
             members: self.members.clone(),
+            auto_reduce: self.auto_reduce,
+            sorted: self.sorted,
         }
     }
 }
 
+/// Alternate form `{:#?}` shows terms sorted by power as (power, coef)
+/// and summary of storage (see `Polynomial::dump`).
+impl<T: Debug, U> Debug for Polynomial<T, U> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if f.alternate() {
+            return dump::debug_alternate(self, f);
+        }
+        f.debug_struct("Polynomial")
+            .field("members", &self.members)
+            .finish()
+    }
+}
+
+impl<T, U> Polynomial<T, U> {
+    // graded-lexicographic comparison of reduced forms: degree first,
+    // then coefs from the highest power, missing coefs are zero
+    fn graded_cmp<F>(&self, other: &Self, cmp: F) -> Option<Ordering>
+    where
+        T: Clone + Zero,
+        T: Add<T, Output = T>,
+        F: Fn(&T, &T) -> Option<Ordering>,
+    {
+        let lhs = self.clone().reduce();
+        let rhs = other.clone().reduce();
+        let degree = |p: &Self| p.members.last().map(|(_, pow)| pow.power);
+        match degree(&lhs).cmp(&degree(&rhs)) {
+            Ordering::Equal => {}
+            ord => return Some(ord),
+        }
+        let zero = T::zero();
+        let mut lhs = lhs.members.iter().rev().peekable();
+        let mut rhs = rhs.members.iter().rev().peekable();
+        loop {
+            let ord = match (lhs.peek(), rhs.peek()) {
+                (None, None) => return Some(Ordering::Equal),
+                (Some((a, a_pow)), Some((b, b_pow))) if a_pow.power == b_pow.power => {
+                    lhs.next();
+                    rhs.next();
+                    cmp(a, b)
+                }
+                (Some((a, a_pow)), Some((_, b_pow))) if a_pow.power > b_pow.power => {
+                    lhs.next();
+                    cmp(a, &zero)
+                }
+                (Some((a, _)), None) => {
+                    lhs.next();
+                    cmp(a, &zero)
+                }
+                (_, Some((b, _))) => {
+                    rhs.next();
+                    cmp(&zero, b)
+                }
+            };
+            if ord != Some(Ordering::Equal) {
+                return ord;
+            }
+        }
+    }
+}
+
+/// Polynomials are equal, if their reduced forms are equal.
+///
+/// Example:
+/// ```
+/// # use polylib::polynom::X;
+/// # let x = X::<i32>::default();
+/// assert_eq!(x.pow(2) + x.pow(1) - x.pow(2), x.pow(1) + 0);
+/// assert_ne!(x.pow(2) + 0, x.pow(1) + 0);
+/// ```
+impl<T, U> PartialEq for Polynomial<T, U>
+where
+    T: Clone + Zero + PartialEq,
+    T: Add<T, Output = T>,
+{
+    fn eq(&self, other: &Self) -> bool {
+        let lhs = self.clone().reduce();
+        let rhs = other.clone().reduce();
+        lhs.members.len() == rhs.members.len()
+            && lhs
+                .members
+                .iter()
+                .zip(rhs.members.iter())
+                .all(|((a, a_pow), (b, b_pow))| a_pow.power == b_pow.power && a == b)
+    }
+}
+
+impl<T, U> Eq for Polynomial<T, U>
+where
+    T: Clone + Zero + Eq,
+    T: Add<T, Output = T>,
+{
+}
+
+/// Polynomials are compared graded-lexicographically: by degree first
+/// (zero polynomial is the least), then by coefs from the highest power.
+///
+/// Example:
+/// ```
+/// # use polylib::polynom::X;
+/// # let x = X::<i32>::default();
+/// let mut v = vec![x.pow(1) * 5, x.pow(2) - 3, x.pow(2) + x.pow(1), x.pow(1) - x.pow(1)];
+/// v.sort();
+/// let degrees: Vec<Option<u32>> = v.iter().map(|p| p.degree()).collect();
+/// assert_eq!(degrees, vec![None, Some(1), Some(2), Some(2)]);
+/// assert_eq!(v[2].coef(0), -3); // x^2 - 3 < x^2 + x
+/// ```
+impl<T, U> PartialOrd for Polynomial<T, U>
+where
+    T: Clone + Zero + PartialOrd,
+    T: Add<T, Output = T>,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.graded_cmp(other, T::partial_cmp)
+    }
+}
+
+impl<T, U> Ord for Polynomial<T, U>
+where
+    T: Clone + Zero + Ord,
+    T: Add<T, Output = T>,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.graded_cmp(other, |a, b| Some(a.cmp(b)))
+            .expect("Ord coefs are always comparable")
+    }
+}
+
+/// Terms are written in increasing order of powers, duplicate powers are not summed.
+/// Precision of formatter is applied to every coef.
+///
+/// Example:
+/// ```
+/// # use polylib::polynom::X;
+/// # let x = X::<f64>::default();
+/// let p = x.pow(2) * (2.0 / 3.0) + 0.25;
+/// assert_eq!(format!("{:.2}", p), "0.25 + 0.67x^2");
+/// ```
 impl<T, U> Display for Polynomial<T, U>
 where
     T: Display + Zero + One,
     U: Default + Display,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut members: Vec<_> = self.members.iter().collect();
+        if !self.sorted {
+            // stable, so equal powers keep their order
+            members.sort_by_key(|(_, var)| var.power);
+        }
         let mut first = true;
-        for item in self.members.iter() {
-            if item.0.is_zero() {
+        for (coef, var) in members {
+            if coef.try_is_zero() == Some(true) {
                 continue;
             }
             if !first {
                 write!(f, " + ")?;
             }
             first = false;
-            if item.0.is_one() && item.1.power == 0 {
-                write!(f, "{}", item.0)?;
-                continue;
-            }
-            if item.0.is_one() {
-                write!(f, "{}", item.1)?;
-                continue;
-            }
-            write!(f, "{}{}", item.0, item.1)?;
+            write_member(f, coef, var, var.power == 0)?;
         }
         if first {
-            write!(f, "{}", T::zero())?;
+            write_member(f, &T::zero(), "", true)?;
         }
         std::fmt::Result::Ok(())
     }
@@ -0,0 +1,282 @@
+//! Defines type `Bounded` - polynomial, whose degree never exceeds `MAX_DEG`.
+
+use std::fmt::{Debug, Display};
+use std::ops::{Add, Mul, Neg, Sub};
+
+use super::{Polynomial, X};
+use crate::{Evaluate, PolyError, SubstitutablePoint, Zero};
+
+/// Type `Bounded`. Reduced polynomial of degree at most `MAX_DEG`,
+/// for example, transfer function of filter of fixed order.
+/// T - type of coefs, U - type of variable.
+///
+/// Sum and difference can't exceed the bound. Product of bounded polynomials
+/// is checked at compile time by `mul_widen` into type with greater bound,
+/// or at runtime by `checked_mul` and `*`, that keep the bound
+/// (`*` panics, `checked_mul` returns `PolyError::DegreeOverflow`).
+///
+/// Example:
+/// ```
+/// # use polylib::polynom::{Bounded, Polynomial};
+/// # use polylib::PolyError;
+/// type Filter = Bounded<i32, 2>;
+/// let a = Filter::new(Polynomial::from_coefs(vec![1, 1])); // x + 1
+/// let b = Filter::new(Polynomial::from_coefs(vec![-1, 1])); // x - 1
+/// assert_eq!((a.clone() * b.clone()).get(), &Polynomial::from_coefs(vec![-1, 0, 1]));
+///
+/// let square = a.clone() * a.clone();
+/// let err = square.checked_mul(&b).unwrap_err();
+/// assert_eq!(err, PolyError::DegreeOverflow { degree: 3, max: 2 });
+///
+/// let cube: Bounded<i32, 4> = square.mul_widen(&a); // bound 2 + 2 fits into 4
+/// assert_eq!(cube.get().degree(), Some(3));
+/// ```
+pub struct Bounded<T, const MAX_DEG: u32, U = X<T>> {
+    // reduced, degree is at most MAX_DEG
+    poly: Polynomial<T, U>,
+}
+
+impl<T, const MAX_DEG: u32, U> Bounded<T, MAX_DEG, U>
+where
+    T: Clone + Zero,
+    T: Add<T, Output = T>,
+{
+    /// Returns reduced polynomial with bound, or `PolyError::DegreeOverflow`,
+    /// if its degree exceeds `MAX_DEG`.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::polynom::{Bounded, Polynomial};
+    /// # use polylib::PolyError;
+    /// let p = Polynomial::<i32>::from_coefs(vec![1, 2, 3]);
+    /// let err = Bounded::<i32, 1>::try_new(p).unwrap_err();
+    /// assert_eq!(err, PolyError::DegreeOverflow { degree: 2, max: 1 });
+    /// ```
+    pub fn try_new(poly: Polynomial<T, U>) -> Result<Bounded<T, MAX_DEG, U>, PolyError> {
+        let poly = poly.reduce();
+        match poly.degree() {
+            Some(degree) if degree > MAX_DEG => {
+                Err(PolyError::DegreeOverflow { degree, max: MAX_DEG })
+            }
+            _ => Ok(Bounded { poly }),
+        }
+    }
+
+    /// Same as `try_new`, but panics, if degree exceeds `MAX_DEG`.
+    pub fn new(poly: Polynomial<T, U>) -> Bounded<T, MAX_DEG, U> {
+        match Self::try_new(poly) {
+            Ok(ans) => ans,
+            Err(err) => panic!("Can't create Bounded: {}", err),
+        }
+    }
+
+    /// Returns same polynomial with bound `M`, which is checked at compile time:
+    /// `M` should be at least `MAX_DEG`.
+    ///
+    /// ```compile_fail
+    /// # use polylib::polynom::{Bounded, Polynomial};
+    /// let p = Bounded::<i32, 3>::new(Polynomial::from_coefs(vec![1, 2]));
+    /// let q: Bounded<i32, 2> = p.widen();
+    /// ```
+    pub fn widen<const M: u32>(self) -> Bounded<T, M, U> {
+        const { assert!(M >= MAX_DEG, "Bounded can't be widened to less bound") };
+        Bounded { poly: self.poly }
+    }
+
+    /// Multiplies polynomials into type with bound `E`, which is checked
+    /// at compile time: `E` should be at least `MAX_DEG + M`.
+    ///
+    /// ```compile_fail
+    /// # use polylib::polynom::{Bounded, Polynomial};
+    /// let p = Bounded::<i32, 2>::new(Polynomial::from_coefs(vec![1, 2]));
+    /// let q: Bounded<i32, 3> = p.mul_widen(&p);
+    /// ```
+    pub fn mul_widen<const M: u32, const E: u32>(
+        &self,
+        rhs: &Bounded<T, M, U>,
+    ) -> Bounded<T, E, U>
+    where
+        T: Mul<T, Output = T>,
+    {
+        const {
+            assert!(
+                E as u64 >= MAX_DEG as u64 + M as u64,
+                "Bounded product does not fit into result"
+            )
+        };
+        Bounded {
+            poly: (self.poly.clone() * rhs.poly.clone()).reduce(),
+        }
+    }
+
+    /// Multiplies polynomials with the same bound,
+    /// returns `PolyError::DegreeOverflow`, if product exceeds it.
+    pub fn checked_mul(&self, rhs: &Self) -> Result<Bounded<T, MAX_DEG, U>, PolyError>
+    where
+        T: Mul<T, Output = T>,
+    {
+        Self::try_new(self.poly.clone() * rhs.poly.clone())
+    }
+}
+
+impl<T, const MAX_DEG: u32, U> Bounded<T, MAX_DEG, U> {
+    /// Returns bound of degree.
+    pub const fn max_degree(&self) -> u32 {
+        MAX_DEG
+    }
+
+    /// Returns polynomial.
+    pub fn get(&self) -> &Polynomial<T, U> {
+        &self.poly
+    }
+
+    /// Returns polynomial without bound.
+    pub fn into_inner(self) -> Polynomial<T, U> {
+        self.poly
+    }
+
+    /// Calculate value of polynom at point (see `Polynomial::substitude`).
+    pub fn substitude<A, B>(&self, point: A) -> B
+    where
+        T: Clone,
+        A: SubstitutablePoint<T, B>,
+    {
+        self.poly.substitude(point)
+    }
+}
+
+impl<T, const MAX_DEG: u32, U> TryFrom<Polynomial<T, U>> for Bounded<T, MAX_DEG, U>
+where
+    T: Clone + Zero,
+    T: Add<T, Output = T>,
+{
+    type Error = PolyError;
+
+    fn try_from(poly: Polynomial<T, U>) -> Result<Self, Self::Error> {
+        Self::try_new(poly)
+    }
+}
+
+impl<T, const MAX_DEG: u32, U> From<Bounded<T, MAX_DEG, U>> for Polynomial<T, U> {
+    fn from(value: Bounded<T, MAX_DEG, U>) -> Self {
+        value.poly
+    }
+}
+
+impl<T, const MAX_DEG: u32, U, X, Y> Evaluate<X, Y> for Bounded<T, MAX_DEG, U>
+where
+    T: Clone,
+    X: SubstitutablePoint<T, Y>,
+{
+    fn evaluate(&self, point: X) -> Y {
+        self.substitude(point)
+    }
+}
+
+impl<T, const MAX_DEG: u32, U> Add for Bounded<T, MAX_DEG, U>
+where
+    T: Clone + Zero,
+    T: Add<T, Output = T>,
+{
+    type Output = Bounded<T, MAX_DEG, U>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Bounded {
+            poly: (self.poly + rhs.poly).reduce(),
+        }
+    }
+}
+
+impl<T, const MAX_DEG: u32, U> Neg for Bounded<T, MAX_DEG, U>
+where
+    T: Neg<Output = T>,
+{
+    type Output = Bounded<T, MAX_DEG, U>;
+
+    fn neg(self) -> Self::Output {
+        Bounded { poly: -self.poly }
+    }
+}
+
+impl<T, const MAX_DEG: u32, U> Sub for Bounded<T, MAX_DEG, U>
+where
+    T: Clone + Zero,
+    T: Add<T, Output = T>,
+    T: Neg<Output = T>,
+{
+    type Output = Bounded<T, MAX_DEG, U>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Bounded {
+            poly: (self.poly - rhs.poly).reduce(),
+        }
+    }
+}
+
+impl<T, const MAX_DEG: u32, U> Mul<T> for Bounded<T, MAX_DEG, U>
+where
+    T: Clone + Zero,
+    T: Add<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Bounded<T, MAX_DEG, U>;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Bounded {
+            poly: (self.poly * rhs).reduce(),
+        }
+    }
+}
+
+/// Panics, if product exceeds bound, use `checked_mul` to get error instead.
+impl<T, const MAX_DEG: u32, U> Mul for Bounded<T, MAX_DEG, U>
+where
+    T: Clone + Zero,
+    T: Add<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = Bounded<T, MAX_DEG, U>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        match self.checked_mul(&rhs) {
+            Ok(ans) => ans,
+            Err(err) => panic!("Can't multiply Bounded: {}", err),
+        }
+    }
+}
+
+impl<T: Clone, const MAX_DEG: u32, U> Clone for Bounded<T, MAX_DEG, U> {
+    fn clone(&self) -> Self {
+        Bounded {
+            poly: self.poly.clone(),
+        }
+    }
+}
+
+impl<T, const MAX_DEG: u32, U> PartialEq for Bounded<T, MAX_DEG, U>
+where
+    T: Clone + Zero + PartialEq,
+    T: Add<T, Output = T>,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.poly == other.poly
+    }
+}
+
+impl<T: Debug, const MAX_DEG: u32, U> Debug for Bounded<T, MAX_DEG, U> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Bounded")
+            .field("max_degree", &MAX_DEG)
+            .field("poly", &self.poly)
+            .finish()
+    }
+}
+
+impl<T, const MAX_DEG: u32, U> Display for Bounded<T, MAX_DEG, U>
+where
+    Polynomial<T, U>: Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.poly, f)
+    }
+}
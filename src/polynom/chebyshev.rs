@@ -0,0 +1,204 @@
+//! Chebyshev nodes and series for stable approximation on interval.
+//!
+//! Series c0 T0(t) + c1 T1(t) + ... is kept in variable t in [-1, 1],
+//! which is linear map of x in [a, b].
+//! Since |Tk(t)| <= 1, dropping tail of series changes value
+//! at most by sum of magnitudes of dropped coefs.
+
+use std::cmp::Ordering;
+use std::f64::consts::PI;
+
+use super::Polynomial;
+use crate::Evaluate;
+
+// maps [a, b] to [-1, 1]
+fn to_unit(x: f64, a: f64, b: f64) -> f64 {
+    (2.0 * x - a - b) / (b - a)
+}
+
+// maps [-1, 1] to [a, b]
+fn from_unit(t: f64, a: f64, b: f64) -> f64 {
+    (a + b) / 2.0 + (b - a) / 2.0 * t
+}
+
+fn check_interval(a: f64, b: f64) {
+    // NaN bounds are rejected too
+    if a.partial_cmp(&b) != Some(Ordering::Less) {
+        panic!("Chebyshev interval [{}, {}] is empty", a, b)
+    }
+}
+
+/// Returns n Chebyshev nodes (of the first kind) on [a, b] in ascending order.
+/// They are roots of Tn mapped to [a, b].
+///
+/// Panics if a >= b.
+///
+/// Example:
+/// ```
+/// # use polylib::polynom::chebyshev::nodes;
+/// let x = nodes(2, 0.0, 2.0);
+/// assert!((x[0] - (1.0 - 0.5f64.sqrt())).abs() < 1e-15);
+/// assert!((x[1] - (1.0 + 0.5f64.sqrt())).abs() < 1e-15);
+/// ```
+pub fn nodes(n: usize, a: f64, b: f64) -> Vec<f64> {
+    check_interval(a, b);
+    (0..n)
+        .rev()
+        .map(|j| from_unit((PI * (j as f64 + 0.5) / n as f64).cos(), a, b))
+        .collect()
+}
+
+/// Chebyshev series c0 T0(t) + c1 T1(t) + ... + cd Td(t) on [a, b],
+/// where t = (2x - a - b) / (b - a).
+///
+/// Example:
+/// ```
+/// # use polylib::polynom::chebyshev::ChebyshevSeries;
+/// let s = ChebyshevSeries::fit(f64::exp, 14, -1.0, 1.0);
+/// assert!((s.evaluate(0.5) - 0.5f64.exp()).abs() < 1e-12);
+///
+/// // drop terms, while error is at most 1e-4
+/// let (short, bound) = s.economize(1e-4);
+/// assert_eq!(short.coefs().len(), 6);
+/// assert!((short.evaluate(0.5) - 0.5f64.exp()).abs() <= bound + 1e-12);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChebyshevSeries {
+    coefs: Vec<f64>,
+    a: f64,
+    b: f64,
+}
+
+impl ChebyshevSeries {
+    /// Creates series from coefs c0, c1, ... on [a, b].
+    ///
+    /// Panics if a >= b.
+    pub fn new(coefs: Vec<f64>, a: f64, b: f64) -> ChebyshevSeries {
+        check_interval(a, b);
+        ChebyshevSeries { coefs, a, b }
+    }
+
+    /// Returns series with n terms, that interpolates f at n Chebyshev nodes on [a, b].
+    ///
+    /// Panics if a >= b.
+    pub fn fit<F: Fn(f64) -> f64>(f: F, n: usize, a: f64, b: f64) -> ChebyshevSeries {
+        check_interval(a, b);
+        let angles: Vec<f64> = (0..n).map(|j| PI * (j as f64 + 0.5) / n as f64).collect();
+        let values: Vec<f64> = angles.iter().map(|t| f(from_unit(t.cos(), a, b))).collect();
+        let coefs = (0..n)
+            .map(|k| {
+                let sum: f64 = angles
+                    .iter()
+                    .zip(&values)
+                    .map(|(t, value)| value * (k as f64 * t).cos())
+                    .sum();
+                let scale = if k == 0 { 1.0 } else { 2.0 };
+                scale * sum / n as f64
+            })
+            .collect();
+        ChebyshevSeries { coefs, a, b }
+    }
+
+    /// Returns series, equal to polynomial on [a, b] (up to rounding).
+    ///
+    /// Panics if a >= b.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::polynom::chebyshev::ChebyshevSeries;
+    /// # use polylib::polynom::Polynomial;
+    /// let p = Polynomial::<f64>::from_coefs(vec![0.0, 0.0, 2.0]); // 2x^2 = T0 + T2
+    /// let s = ChebyshevSeries::from_polynomial(&p, -1.0, 1.0);
+    /// assert!((s.coefs()[0] - 1.0).abs() < 1e-15);
+    /// assert!(s.coefs()[1].abs() < 1e-15);
+    /// assert!((s.coefs()[2] - 1.0).abs() < 1e-15);
+    /// ```
+    pub fn from_polynomial<U>(poly: &Polynomial<f64, U>, a: f64, b: f64) -> ChebyshevSeries {
+        let n = poly.degree().map_or(0, |deg| deg as usize + 1);
+        // interpolation at deg + 1 nodes is exact for polynomial of degree deg
+        Self::fit(|x| poly.substitude(x), n, a, b)
+    }
+
+    /// Returns coefs c0, c1, ...
+    pub fn coefs(&self) -> &[f64] {
+        &self.coefs
+    }
+
+    /// Returns interval (a, b).
+    pub fn interval(&self) -> (f64, f64) {
+        (self.a, self.b)
+    }
+
+    /// Returns value at x by Clenshaw recurrence.
+    pub fn evaluate(&self, x: f64) -> f64 {
+        let t = to_unit(x, self.a, self.b);
+        let (mut b1, mut b2) = (0.0, 0.0);
+        for c in self.coefs.iter().skip(1).rev() {
+            (b1, b2) = (c + 2.0 * t * b1 - b2, b1);
+        }
+        match self.coefs.first() {
+            Some(c0) => c0 + t * b1 - b2,
+            None => 0.0,
+        }
+    }
+
+    /// Returns series without terms of degree greater than degree,
+    /// and bound of error on [a, b] (sum of magnitudes of dropped coefs).
+    pub fn truncate(mut self, degree: usize) -> (ChebyshevSeries, f64) {
+        let tail = self.coefs.split_off((degree + 1).min(self.coefs.len()));
+        let bound = tail.iter().map(|c| c.abs()).sum();
+        (self, bound)
+    }
+
+    /// Returns shortest series, that differs from self at most by tolerance on [a, b],
+    /// and bound of error. Highest terms are dropped while bound is within tolerance.
+    pub fn economize(mut self, tolerance: f64) -> (ChebyshevSeries, f64) {
+        let mut bound = 0.0;
+        while let Some(last) = self.coefs.last() {
+            if bound + last.abs() > tolerance {
+                break;
+            }
+            bound += last.abs();
+            self.coefs.pop();
+        }
+        (self, bound)
+    }
+
+    /// Returns series as polynomial in x.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::polynom::chebyshev::ChebyshevSeries;
+    /// let s = ChebyshevSeries::new(vec![1.0, 0.0, 1.0], 0.0, 2.0); // T0 + T2 with t = x - 1
+    /// let p = s.to_polynomial();                                   // 2(x - 1)^2
+    /// assert_eq!((p.coef(0), p.coef(1), p.coef(2)), (2.0, -4.0, 2.0));
+    /// ```
+    pub fn to_polynomial(&self) -> Polynomial<f64> {
+        // t = scale * x + shift
+        let scale = 2.0 / (self.b - self.a);
+        let shift = -(self.a + self.b) / (self.b - self.a);
+        let t = Polynomial::from_coefs(vec![shift, scale]);
+
+        let mut ans = Polynomial::from_coefs(vec![]);
+        let mut prev = Polynomial::from_coefs(vec![1.0]);
+        let mut cur = t.clone();
+        for (k, c) in self.coefs.iter().enumerate() {
+            if k == 0 {
+                ans = ans + prev.clone() * *c;
+                continue;
+            }
+            ans = ans + cur.clone() * *c;
+            // T(k+1) = 2t Tk - T(k-1)
+            let next = (t.clone() * cur.clone() * 2.0 - prev).reduce();
+            prev = cur;
+            cur = next;
+        }
+        ans.reduce()
+    }
+}
+
+impl Evaluate<f64, f64> for ChebyshevSeries {
+    fn evaluate(&self, point: f64) -> f64 {
+        ChebyshevSeries::evaluate(self, point)
+    }
+}
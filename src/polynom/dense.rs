@@ -0,0 +1,325 @@
+//! Defines `DensePolynomial` - polynomial stored as vector of all coefs.
+
+use std::{
+    fmt::{Debug, Display},
+    marker::PhantomData,
+    ops::{Add, Mul, Neg, Sub},
+};
+
+use super::{write_member, Polynomial, Powered, X};
+use crate::{Evaluate, One, SubstitutablePoint, Zero};
+
+/// Dense polynomial: coef with index `i` is coef of x^i.
+///
+/// Unlike `Polynomial`, members are always summed up, coef of any power
+/// is taken in O(1), and arithmetic runs over contiguous memory.
+/// It suits low-degree polynomials with few zero coefs (fitting, series),
+/// while `Polynomial` suits sparse ones.
+/// Trailing zero coefs are never stored, so zero polynomial has no coefs.
+///
+/// `T` and `U` have same meaning as in `Polynomial`.
+///
+/// Example:
+/// ```
+/// # use polylib::polynom::{DensePolynomial, Polynomial};
+/// let a = DensePolynomial::<i32>::from_coefs(vec![1, 1]);  // x + 1
+/// let b = DensePolynomial::<i32>::from_coefs(vec![-1, 1]); // x - 1
+/// let c = a * b;
+/// assert_eq!(c.coefs(), &[-1, 0, 1]);
+/// assert_eq!(c.substitude::<i32, i32>(3), 8);
+///
+/// let sparse: Polynomial<i32> = c.into();
+/// assert_eq!(sparse.len(), 2);
+/// ```
+pub struct DensePolynomial<T, U = X<T>> {
+    // no trailing zeros
+    coefs: Vec<T>,
+    var: PhantomData<U>,
+}
+
+impl<T, U> DensePolynomial<T, U> {
+    /// Returns polynomial with coefs (coef with index `i` is coef of x^i).
+    /// Trailing zeros are dropped.
+    pub fn from_coefs(mut coefs: Vec<T>) -> DensePolynomial<T, U>
+    where
+        T: Zero,
+    {
+        while coefs.last().is_some_and(T::is_zero) {
+            coefs.pop();
+        }
+        DensePolynomial {
+            coefs,
+            var: PhantomData,
+        }
+    }
+
+    /// Returns all coefs up to the leading one.
+    pub fn coefs(&self) -> &[T] {
+        &self.coefs
+    }
+
+    /// Returns coefs up to the leading one.
+    pub fn into_coefs(self) -> Vec<T> {
+        self.coefs
+    }
+
+    /// Returns coef of x^index or None, if index is greater than degree.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::polynom::DensePolynomial;
+    /// let p = DensePolynomial::<i32>::from_coefs(vec![3, 0, 4]);
+    /// assert_eq!(p.get(1), Some(&0));
+    /// assert_eq!(p.get(3), None);
+    /// ```
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.coefs.get(index)
+    }
+
+    /// Returns degree of polynomial, or None for zero polynomial.
+    pub fn degree(&self) -> Option<usize> {
+        self.coefs.len().checked_sub(1)
+    }
+
+    /// Calculate value of polynom at point.
+    ///
+    /// Same as `Polynomial::substitude`, but powers of point are
+    /// calculated one by one, so it takes degree multiplications.
+    pub fn substitude<A, B>(&self, point: A) -> B
+    where
+        T: Clone,
+        A: SubstitutablePoint<T, B>,
+    {
+        let mut ans = A::zero_value();
+        let mut power = A::one();
+        for (i, coef) in self.coefs.iter().enumerate() {
+            ans = A::accumulate(ans, A::term(coef.clone(), power.clone()));
+            if i + 1 < self.coefs.len() {
+                power = power * point.clone();
+            }
+        }
+        ans
+    }
+
+    /// Converts to `Polynomial`.
+    pub fn to_polynomial(&self) -> Polynomial<T, U>
+    where
+        T: Clone + Zero,
+    {
+        Polynomial::from_coefs(self.coefs.clone())
+    }
+}
+
+impl<T, U, X, Y> Evaluate<X, Y> for DensePolynomial<T, U>
+where
+    T: Clone,
+    X: SubstitutablePoint<T, Y>,
+{
+    fn evaluate(&self, point: X) -> Y {
+        self.substitude(point)
+    }
+}
+
+impl<T, U> From<DensePolynomial<T, U>> for Polynomial<T, U>
+where
+    T: Zero,
+{
+    fn from(value: DensePolynomial<T, U>) -> Self {
+        Polynomial::from_coefs(value.coefs)
+    }
+}
+
+/// Members with the same power are summed up.
+///
+/// Example:
+/// ```
+/// # use polylib::polynom::{DensePolynomial, X};
+/// # let x = X::<i32>::default();
+/// let p = DensePolynomial::from(x.pow(3) + x.pow(1) * 2 - x.pow(3));
+/// assert_eq!(p.coefs(), &[0, 2]);
+/// ```
+impl<T, U> From<Polynomial<T, U>> for DensePolynomial<T, U>
+where
+    T: Zero,
+    T: Add<T, Output = T>,
+{
+    fn from(value: Polynomial<T, U>) -> Self {
+        let value = value.reduce();
+        let len = value.members.last().map_or(0, |(_, pow)| pow.power as usize + 1);
+        let mut coefs: Vec<T> = (0..len).map(|_| T::zero()).collect();
+        for (coef, pow) in value.members {
+            coefs[pow.power as usize] = coef;
+        }
+        DensePolynomial::from_coefs(coefs)
+    }
+}
+
+impl<T, U> Default for DensePolynomial<T, U> {
+    fn default() -> Self {
+        DensePolynomial {
+            coefs: Vec::new(),
+            var: PhantomData,
+        }
+    }
+}
+
+impl<T: Zero, U> Zero for DensePolynomial<T, U> {
+    fn zero() -> Self {
+        Self::default()
+    }
+
+    fn is_zero(&self) -> bool {
+        self.coefs.is_empty()
+    }
+}
+
+impl<T: Zero + One, U> One for DensePolynomial<T, U> {
+    fn one() -> Self {
+        Self::from_coefs(vec![T::one()])
+    }
+
+    fn is_one(&self) -> bool {
+        self.coefs.len() == 1 && self.coefs[0].is_one()
+    }
+}
+
+impl<T, U> Add for DensePolynomial<T, U>
+where
+    T: Zero,
+    T: Add<T, Output = T>,
+{
+    type Output = DensePolynomial<T, U>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let (mut long, short) = if self.coefs.len() >= rhs.coefs.len() {
+            (self.coefs, rhs.coefs)
+        } else {
+            (rhs.coefs, self.coefs)
+        };
+        for (i, c) in short.into_iter().enumerate() {
+            let a = std::mem::replace(&mut long[i], T::zero());
+            long[i] = a + c;
+        }
+        Self::from_coefs(long)
+    }
+}
+
+impl<T, U> Neg for DensePolynomial<T, U>
+where
+    T: Neg<Output = T>,
+{
+    type Output = DensePolynomial<T, U>;
+
+    fn neg(self) -> Self::Output {
+        DensePolynomial {
+            coefs: self.coefs.into_iter().map(|c| -c).collect(),
+            var: PhantomData,
+        }
+    }
+}
+
+impl<T, U> Sub for DensePolynomial<T, U>
+where
+    T: Zero,
+    T: Sub<T, Output = T>,
+{
+    type Output = DensePolynomial<T, U>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        let mut coefs = self.coefs;
+        for (i, c) in rhs.coefs.into_iter().enumerate() {
+            if i < coefs.len() {
+                let a = std::mem::replace(&mut coefs[i], T::zero());
+                coefs[i] = a - c;
+            } else {
+                coefs.push(T::zero() - c);
+            }
+        }
+        Self::from_coefs(coefs)
+    }
+}
+
+impl<T, U> Mul<T> for DensePolynomial<T, U>
+where
+    T: Zero + Clone,
+    T: Mul<T, Output = T>,
+{
+    type Output = DensePolynomial<T, U>;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self::from_coefs(self.coefs.into_iter().map(|c| c * rhs.clone()).collect())
+    }
+}
+
+impl<T, U> Mul for DensePolynomial<T, U>
+where
+    T: Zero + Clone,
+    T: Add<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = DensePolynomial<T, U>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        if self.coefs.is_empty() || rhs.coefs.is_empty() {
+            return Self::default();
+        }
+        let len = self.coefs.len() + rhs.coefs.len() - 1;
+        let mut coefs: Vec<T> = (0..len).map(|_| T::zero()).collect();
+        for (i, a) in self.coefs.iter().enumerate() {
+            for (j, b) in rhs.coefs.iter().enumerate() {
+                let c = std::mem::replace(&mut coefs[i + j], T::zero());
+                coefs[i + j] = c + a.clone() * b.clone();
+            }
+        }
+        Self::from_coefs(coefs)
+    }
+}
+
+impl<T: Clone, U> Clone for DensePolynomial<T, U> {
+    fn clone(&self) -> Self {
+        DensePolynomial {
+            coefs: self.coefs.clone(),
+            var: PhantomData,
+        }
+    }
+}
+
+impl<T: PartialEq, U> PartialEq for DensePolynomial<T, U> {
+    fn eq(&self, other: &Self) -> bool {
+        self.coefs == other.coefs
+    }
+}
+
+impl<T: Eq, U> Eq for DensePolynomial<T, U> {}
+
+impl<T: Debug, U> Debug for DensePolynomial<T, U> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DensePolynomial")
+            .field("coefs", &self.coefs)
+            .finish()
+    }
+}
+
+impl<T, U> Display for DensePolynomial<T, U>
+where
+    T: Display + Zero + One,
+    U: Default + Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut first = true;
+        for (power, coef) in self.coefs.iter().enumerate() {
+            if coef.try_is_zero() == Some(true) {
+                continue;
+            }
+            if !first {
+                write!(f, " + ")?;
+            }
+            first = false;
+            write_member(f, coef, Powered::<U>::new(power as u32), power == 0)?;
+        }
+        if first {
+            write_member(f, &T::zero(), "", true)?;
+        }
+        std::fmt::Result::Ok(())
+    }
+}
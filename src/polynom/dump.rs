@@ -0,0 +1,106 @@
+//! Defines `Dump` - structural report about polynomial storage.
+
+use std::fmt::{Debug, Display};
+use std::mem::size_of;
+
+use super::terms::INLINE;
+use super::{Polynomial, Powered};
+
+/// Report about storage of polynomial, created by `Polynomial::dump`.
+///
+/// It is found without summing up members, so it needs no bounds on coefs,
+/// and tells, how far polynomial is from its reduced form.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Dump {
+    /// Count of stored members.
+    pub len: usize,
+    /// Count of members, that fit without reallocation.
+    pub capacity: usize,
+    /// Maximal power of stored members, `None` if there are no members.
+    /// Members may cancel out, so it only bounds degree.
+    pub max_power: Option<u32>,
+    /// Count of members, whose power is already taken by another member,
+    /// so `reduce` removes at least so many members.
+    pub duplicate_powers: usize,
+    /// Powers of members strictly increase.
+    pub sorted: bool,
+    /// Threshold of auto-reduce policy (see `Polynomial::with_auto_reduce`).
+    pub auto_reduce_threshold: Option<usize>,
+    /// Bytes taken by polynomial and its members. Memory, that coefs own
+    /// themselves (as `BigInt` digits), is not counted.
+    pub memory: usize,
+}
+
+impl Display for Dump {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} members (capacity {}), ", self.len, self.capacity)?;
+        match self.max_power {
+            Some(power) => write!(f, "max power {}, ", power)?,
+            None => write!(f, "no powers, ")?,
+        }
+        write!(f, "{} duplicate powers, ", self.duplicate_powers)?;
+        write!(f, "{}, ", if self.sorted { "sorted" } else { "unsorted" })?;
+        if let Some(threshold) = self.auto_reduce_threshold {
+            write!(f, "auto-reduce over {}, ", threshold)?;
+        }
+        write!(f, "{} bytes", self.memory)
+    }
+}
+
+impl<T, U> Polynomial<T, U> {
+    /// Returns report about storage of polynomial: count of members,
+    /// duplicate powers, memory and so on. It helps to find out,
+    /// why polynomial got so long, and whether it should be reduced.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::polynom::X;
+    /// # let x = X::<i32>::default();
+    /// let p = (x.pow(1) + 1) * (x.pow(1) + 1); // x^2 + x + x + 1
+    /// let dump = p.dump();
+    /// assert_eq!((dump.len, dump.max_power, dump.duplicate_powers), (4, Some(2), 1));
+    /// assert_eq!(p.reduce().dump().duplicate_powers, 0);
+    /// ```
+    pub fn dump(&self) -> Dump {
+        let mut powers: Vec<u32> = self.members.iter().map(|(_, pow)| pow.power).collect();
+        powers.sort_unstable();
+        let duplicate_powers = powers.windows(2).filter(|w| w[0] == w[1]).count();
+        let capacity = self.members.capacity();
+        let heap = if capacity > INLINE {
+            capacity * size_of::<(T, Powered<U>)>()
+        } else {
+            0
+        };
+        Dump {
+            len: powers.len(),
+            capacity,
+            max_power: powers.last().copied(),
+            duplicate_powers,
+            sorted: self.sorted,
+            auto_reduce_threshold: self.auto_reduce_threshold(),
+            memory: size_of::<Self>() + heap,
+        }
+    }
+
+    // members sorted by power, members with the same power keep their order
+    fn sorted_terms(&self) -> Vec<(u32, &T)> {
+        let mut terms: Vec<(u32, &T)> =
+            self.members.iter().map(|(coef, pow)| (pow.power, coef)).collect();
+        terms.sort_by_key(|(power, _)| *power);
+        terms
+    }
+}
+
+// `{:#?}` of polynomial shows sorted terms and storage summary
+pub(super) fn debug_alternate<T: Debug, U>(
+    poly: &Polynomial<T, U>,
+    f: &mut std::fmt::Formatter<'_>,
+) -> std::fmt::Result {
+    let dump = poly.dump();
+    f.debug_struct("Polynomial")
+        .field("terms", &poly.sorted_terms())
+        .field("len", &dump.len)
+        .field("duplicate_powers", &dump.duplicate_powers)
+        .field("sorted", &dump.sorted)
+        .finish()
+}
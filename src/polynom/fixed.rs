@@ -0,0 +1,307 @@
+//! Defines `FixedPolynomial` - dense polynomial stored in array.
+
+use std::{
+    fmt::{Debug, Display},
+    marker::PhantomData,
+    ops::{Add, Mul, Neg, Sub},
+};
+
+use super::{write_member, Polynomial, Powered, X};
+use crate::{Evaluate, One, SubstitutablePoint, Zero};
+
+/// Dense polynomial with `D` coefficients (so its degree is less than `D`),
+/// stored in array without any heap allocation.
+///
+/// Coefficient with index `i` is coefficient of x^i.
+///
+/// `T` and `U` have same meaning as in `Polynomial`.
+///
+/// Example:
+/// ```
+/// # use polylib::polynom::FixedPolynomial;
+/// let p = FixedPolynomial::<i32, 3>::new([1, 2, 3]); // is 3x^2 + 2x + 1
+/// assert_eq!(p.substitude::<i32, i32>(2), 17);
+/// ```
+pub struct FixedPolynomial<T, const D: usize, U = X<T>> {
+    coefs: [T; D],
+    var: PhantomData<U>,
+}
+
+impl<T, const D: usize, U> FixedPolynomial<T, D, U> {
+    /// Returns polynomial with coefs (coef with index `i` is coef of x^i).
+    pub const fn new(coefs: [T; D]) -> FixedPolynomial<T, D, U> {
+        FixedPolynomial {
+            coefs,
+            var: PhantomData,
+        }
+    }
+
+    /// Returns all coefs (coef with index `i` is coef of x^i).
+    pub fn coefs(&self) -> &[T; D] {
+        &self.coefs
+    }
+
+    /// Returns coef of x^index or None, if index is out of array.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::polynom::FixedPolynomial;
+    /// let p = FixedPolynomial::<i32, 2>::new([3, 4]);
+    /// assert_eq!(p.get(1), Some(&4));
+    /// assert_eq!(p.get(2), None);
+    /// ```
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.coefs.get(index)
+    }
+
+    /// Returns degree of polynomial, or None for zero polynomial.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::polynom::FixedPolynomial;
+    /// let p = FixedPolynomial::<i32, 4>::new([3, 4, 0, 0]);
+    /// assert_eq!(p.degree(), Some(1));
+    /// ```
+    pub fn degree(&self) -> Option<usize>
+    where
+        T: Zero,
+    {
+        self.coefs.iter().rposition(|c| !c.is_zero())
+    }
+
+    /// Calculate value of polynom at point.
+    ///
+    /// Same as `Polynomial::substitude`, but powers of point are
+    /// calculated one by one, so it takes `D` multiplications.
+    pub fn substitude<A, B>(&self, point: A) -> B
+    where
+        T: Clone,
+        A: SubstitutablePoint<T, B>,
+    {
+        let mut ans = A::zero_value();
+        let mut power = A::one();
+        for (i, coef) in self.coefs.iter().enumerate() {
+            ans = A::accumulate(ans, A::term(coef.clone(), power.clone()));
+            if i + 1 < D {
+                power = power * point.clone();
+            }
+        }
+        ans
+    }
+
+    /// Multiplies polynomials into type with enough coefficients.
+    ///
+    /// Result has `E` coefficients, which is checked at compile time:
+    /// `E` should be at least `D + D2 - 1`.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::polynom::FixedPolynomial;
+    /// let a = FixedPolynomial::<i32, 2>::new([1, 1]);  // x + 1
+    /// let b = FixedPolynomial::<i32, 2>::new([-1, 1]); // x - 1
+    /// let c: FixedPolynomial<i32, 3> = a.mul_widen(&b);
+    /// assert_eq!(c.coefs(), &[-1, 0, 1]);
+    /// ```
+    ///
+    /// ```compile_fail
+    /// # use polylib::polynom::FixedPolynomial;
+    /// let a = FixedPolynomial::<i32, 2>::new([1, 1]);
+    /// let c: FixedPolynomial<i32, 2> = a.mul_widen(&a); // x^2 does not fit
+    /// ```
+    pub fn mul_widen<const D2: usize, const E: usize>(
+        &self,
+        rhs: &FixedPolynomial<T, D2, U>,
+    ) -> FixedPolynomial<T, E, U>
+    where
+        T: Clone + Zero,
+        T: Add<T, Output = T>,
+        T: Mul<T, Output = T>,
+    {
+        const {
+            assert!(
+                D == 0 || D2 == 0 || E + 1 >= D + D2,
+                "FixedPolynomial product does not fit into result"
+            )
+        };
+        let mut ans = FixedPolynomial::<T, E, U>::zero();
+        for (i, a) in self.coefs.iter().enumerate() {
+            for (j, b) in rhs.coefs.iter().enumerate() {
+                let c = ans.coefs[i + j].clone();
+                ans.coefs[i + j] = c + a.clone() * b.clone();
+            }
+        }
+        ans
+    }
+
+    /// Converts to `Polynomial`.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::polynom::FixedPolynomial;
+    /// let p = FixedPolynomial::<i32, 3>::new([1, 0, 2]).to_polynomial();
+    /// assert_eq!(p.len(), 2);
+    /// ```
+    pub fn to_polynomial(&self) -> Polynomial<T, U>
+    where
+        T: Clone + Zero,
+    {
+        Polynomial::from_coefs(self.coefs.to_vec())
+    }
+}
+
+macro_rules! impl_const_eval {
+    ($($t:ty),*) => {
+        $(
+            impl<const D: usize, U> FixedPolynomial<$t, D, U> {
+                /// Calculate value of polynom at point using Horner's method.
+                ///
+                /// It is `const fn`, so it can be used to build compile-time tables.
+                /// Overflow is a compile error in const context.
+                ///
+                /// Example:
+                /// ```
+                /// # use polylib::polynom::FixedPolynomial;
+                /// const P: FixedPolynomial<i64, 3> = FixedPolynomial::new([1, 2, 3]);
+                /// const Y: i64 = P.eval(5); // 3 * 25 + 2 * 5 + 1
+                /// assert_eq!(Y, 86);
+                /// ```
+                pub const fn eval(&self, point: $t) -> $t {
+                    let mut ans = 0;
+                    let mut i = D;
+                    while i > 0 {
+                        i -= 1;
+                        ans = ans * point + self.coefs[i];
+                    }
+                    ans
+                }
+            }
+        )*
+    };
+}
+
+impl_const_eval!(i8, i16, i32, i64, i128, isize);
+impl_const_eval!(u8, u16, u32, u64, u128, usize);
+
+impl<T, const D: usize, U, X, Y> Evaluate<X, Y> for FixedPolynomial<T, D, U>
+where
+    T: Clone,
+    X: SubstitutablePoint<T, Y>,
+{
+    fn evaluate(&self, point: X) -> Y {
+        self.substitude(point)
+    }
+}
+
+impl<T, const D: usize, U> From<FixedPolynomial<T, D, U>> for Polynomial<T, U>
+where
+    T: Zero,
+{
+    fn from(value: FixedPolynomial<T, D, U>) -> Self {
+        Polynomial::from_coefs(Vec::from(value.coefs))
+    }
+}
+
+impl<T: Zero, const D: usize, U> Zero for FixedPolynomial<T, D, U> {
+    fn zero() -> Self {
+        Self::new(std::array::from_fn(|_| T::zero()))
+    }
+
+    fn is_zero(&self) -> bool {
+        self.coefs.iter().all(T::is_zero)
+    }
+}
+
+impl<T, const D: usize, U> Add for FixedPolynomial<T, D, U>
+where
+    T: Add<T, Output = T>,
+{
+    type Output = FixedPolynomial<T, D, U>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let mut rhs = rhs.coefs.into_iter();
+        Self::new(self.coefs.map(|c| c + rhs.next().expect("same size")))
+    }
+}
+
+impl<T, const D: usize, U> Neg for FixedPolynomial<T, D, U>
+where
+    T: Neg<Output = T>,
+{
+    type Output = FixedPolynomial<T, D, U>;
+
+    fn neg(self) -> Self::Output {
+        Self::new(self.coefs.map(|c| -c))
+    }
+}
+
+impl<T, const D: usize, U> Sub for FixedPolynomial<T, D, U>
+where
+    T: Sub<T, Output = T>,
+{
+    type Output = FixedPolynomial<T, D, U>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        let mut rhs = rhs.coefs.into_iter();
+        Self::new(self.coefs.map(|c| c - rhs.next().expect("same size")))
+    }
+}
+
+impl<T, const D: usize, U> Mul<T> for FixedPolynomial<T, D, U>
+where
+    T: Mul<T, Output = T>,
+    T: Clone,
+{
+    type Output = FixedPolynomial<T, D, U>;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self::new(self.coefs.map(|c| c * rhs.clone()))
+    }
+}
+
+impl<T: Clone, const D: usize, U> Clone for FixedPolynomial<T, D, U> {
+    fn clone(&self) -> Self {
+        Self::new(self.coefs.clone())
+    }
+}
+
+impl<T: Copy, const D: usize, U> Copy for FixedPolynomial<T, D, U> {}
+
+impl<T: PartialEq, const D: usize, U> PartialEq for FixedPolynomial<T, D, U> {
+    fn eq(&self, other: &Self) -> bool {
+        self.coefs == other.coefs
+    }
+}
+
+impl<T: Eq, const D: usize, U> Eq for FixedPolynomial<T, D, U> {}
+
+impl<T: Debug, const D: usize, U> Debug for FixedPolynomial<T, D, U> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FixedPolynomial")
+            .field("coefs", &self.coefs)
+            .finish()
+    }
+}
+
+impl<T, const D: usize, U> Display for FixedPolynomial<T, D, U>
+where
+    T: Display + Zero + One,
+    U: Default + Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut first = true;
+        for (power, coef) in self.coefs.iter().enumerate() {
+            if coef.try_is_zero() == Some(true) {
+                continue;
+            }
+            if !first {
+                write!(f, " + ")?;
+            }
+            first = false;
+            write_member(f, coef, Powered::<U>::new(power as u32), power == 0)?;
+        }
+        if first {
+            write_member(f, &T::zero(), "", true)?;
+        }
+        std::fmt::Result::Ok(())
+    }
+}
@@ -0,0 +1,252 @@
+//! Utilities for polynomials with floating point coefs.
+
+use super::Polynomial;
+
+// error-free transformation: a + b = sum + err exactly
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let sum = a + b;
+    let z = sum - a;
+    (sum, (a - (sum - z)) + (b - z))
+}
+
+// error-free transformation: a * b = prod + err exactly
+fn two_prod(a: f64, b: f64) -> (f64, f64) {
+    let prod = a * b;
+    (prod, a.mul_add(b, -prod))
+}
+
+// complex numbers as (re, im)
+fn complex_mul(a: (f64, f64), b: (f64, f64)) -> (f64, f64) {
+    (a.0 * b.0 - a.1 * b.1, a.0 * b.1 + a.1 * b.0)
+}
+
+fn complex_div(a: (f64, f64), b: (f64, f64)) -> (f64, f64) {
+    let den = b.0 * b.0 + b.1 * b.1;
+    ((a.0 * b.0 + a.1 * b.1) / den, (a.1 * b.0 - a.0 * b.1) / den)
+}
+
+// |a - b| is within absolute or relative tolerance
+fn close(a: f64, b: f64, abs_tol: f64, rel_tol: f64) -> bool {
+    let diff = (a - b).abs();
+    diff <= abs_tol || diff <= rel_tol * a.abs().max(b.abs())
+}
+
+impl<U> Polynomial<f64, U> {
+    /// Compares reduced polynomials coef by coef: coefs a and b are close,
+    /// if `|a - b| <= abs_tol` or `|a - b| <= rel_tol * max(|a|, |b|)`.
+    /// Missing coef is zero. NaN is not close to anything.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::polynom::Polynomial;
+    /// let a = Polynomial::<f64>::from_coefs(vec![0.1 + 0.2, 1e10, 1e-17]);
+    /// let b = Polynomial::<f64>::from_coefs(vec![0.3, 1e10 + 1.0]);
+    /// assert!(a != b);
+    /// assert!(a.approx_eq(&b, 1e-12, 1e-9));
+    /// assert!(!a.approx_eq(&b, 1e-12, 0.0));
+    /// ```
+    pub fn approx_eq(&self, other: &Self, abs_tol: f64, rel_tol: f64) -> bool {
+        let lhs = self.clone().reduce();
+        let rhs = other.clone().reduce();
+        let mut lhs = lhs.members.iter().peekable();
+        let mut rhs = rhs.members.iter().peekable();
+        loop {
+            let (a, b) = match (lhs.peek(), rhs.peek()) {
+                (None, None) => return true,
+                (Some((a, a_pow)), Some((b, b_pow))) if a_pow.power == b_pow.power => {
+                    lhs.next();
+                    rhs.next();
+                    (*a, *b)
+                }
+                (Some((a, a_pow)), Some((_, b_pow))) if a_pow.power < b_pow.power => {
+                    lhs.next();
+                    (*a, 0.0)
+                }
+                (Some((a, _)), None) => {
+                    lhs.next();
+                    (*a, 0.0)
+                }
+                (_, Some((b, _))) => {
+                    rhs.next();
+                    (0.0, *b)
+                }
+            };
+            if !close(a, b, abs_tol, rel_tol) {
+                return false;
+            }
+        }
+    }
+
+    /// Same as `reduce`, but also drops members with `|coef| <= tol`,
+    /// that are rounding garbage after floating point operations.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::polynom::Polynomial;
+    /// let p = Polynomial::<f64>::from_coefs(vec![1.0, 0.1 + 0.2 - 0.3, 2.0]);
+    /// assert_eq!(p.len(), 3);
+    /// assert_eq!(p.approx_reduce(1e-12).len(), 2);
+    /// ```
+    pub fn approx_reduce(self, tol: f64) -> Polynomial<f64, U> {
+        let reduced = self.reduce();
+        let mut ans = Polynomial::new();
+        ans.auto_reduce = reduced.auto_reduce;
+        for (coef, pow) in reduced.members {
+            if coef.abs() > tol {
+                ans.push(coef, pow);
+            }
+        }
+        ans
+    }
+
+    /// Rounds every coef to `decimals` digits after decimal point,
+    /// members, that become zero, are dropped. Members are not summed up.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::polynom::Polynomial;
+    /// let p = Polynomial::<f64>::from_coefs(vec![0.1 + 0.2, 1e-9, -2.71828]);
+    /// let p = p.round_coefs(3);
+    /// assert_eq!(p.len(), 2);
+    /// assert_eq!((p.coef(0), p.coef(2)), (0.3, -2.718));
+    /// ```
+    pub fn round_coefs(self, decimals: i32) -> Polynomial<f64, U> {
+        let scale = 10f64.powi(decimals);
+        self.map_coefs(|coef| (coef * scale).round() / scale)
+    }
+
+    /// Drops members with `|coef| < threshold`. Members are not summed up,
+    /// use `approx_reduce` to sum them up first.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::polynom::Polynomial;
+    /// let p = Polynomial::<f64>::from_coefs(vec![1e-20, 2.0, -3e-17]);
+    /// assert_eq!(p.chop(1e-15).to_string(), "2x");
+    /// ```
+    pub fn chop(self, threshold: f64) -> Polynomial<f64, U> {
+        self.map_coefs(|coef| if coef.abs() < threshold { 0.0 } else { coef })
+    }
+
+    /// Calculates value of polynomial at point by compensated Horner's method:
+    /// rounding errors of every step are computed exactly and summed up
+    /// by second Horner's scheme, then added to result.
+    ///
+    /// Result is as accurate, as if it was computed with twice the precision
+    /// and rounded to f64, so it stays accurate near multiple roots,
+    /// where `substitude` returns noise. It is about 4 times slower.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::polynom::X;
+    /// # let x = X::<f64>::default();
+    /// // (x - 1)^10 has relative condition number about 10^23 at 1.01
+    /// let p = (x.pow(1) - 1.0).pow(10);
+    /// let exact = (1.01f64 - 1.0).powi(10);
+    /// let value = p.substitude_compensated(1.01);
+    /// assert!(((value - exact) / exact).abs() < 1e-6);
+    /// let naive: f64 = p.substitude(1.01);
+    /// assert!(((naive - exact) / exact).abs() > 1.0);
+    /// ```
+    pub fn substitude_compensated(&self, point: f64) -> f64 {
+        let reduced = self.clone().reduce();
+        let degree = match reduced.members.last() {
+            Some((_, pow)) => pow.power,
+            None => return 0.0,
+        };
+        let mut value = reduced.coef(degree);
+        let mut err = 0.0;
+        for i in (0..degree).rev() {
+            let (prod, prod_err) = two_prod(value, point);
+            let (sum, sum_err) = two_sum(prod, reduced.coef(i));
+            value = sum;
+            err = err * point + (prod_err + sum_err);
+        }
+        value + err
+    }
+
+    /// Returns all roots (with multiplicity) as pairs (re, im), sorted by re, then by im.
+    ///
+    /// Roots are found by Durand–Kerner iteration for monic polynomial.
+    /// Simple roots are precise, multiple ones lose about half of digits or more.
+    /// Imaginary parts, that are negligible, are set to zero.
+    /// Panics for zero polynomial.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::polynom::Polynomial;
+    /// // 2(x - 1)(x^2 + 4)
+    /// let p = Polynomial::<f64>::from_coefs(vec![-8.0, 8.0, -2.0, 2.0]);
+    /// let roots = p.complex_roots();
+    /// assert_eq!(roots.len(), 3);
+    /// assert!(roots[0].0.abs() < 1e-12 && (roots[0].1 + 2.0).abs() < 1e-12);
+    /// assert!(roots[1].0.abs() < 1e-12 && (roots[1].1 - 2.0).abs() < 1e-12);
+    /// assert!((roots[2].0 - 1.0).abs() < 1e-12 && roots[2].1 == 0.0);
+    /// ```
+    pub fn complex_roots(&self) -> Vec<(f64, f64)> {
+        let reduced = self.clone().reduce();
+        let n = match reduced.members.last() {
+            Some((_, pow)) => pow.power as usize,
+            None => panic!("complex_roots of zero polynomial: every point is its root"),
+        };
+        // monic, coefs from x^0 to x^n
+        let lead = reduced.coef(n as u32);
+        let coefs: Vec<f64> = (0..=n as u32).map(|i| reduced.coef(i) / lead).collect();
+        let bound = 1.0 + coefs[..n].iter().fold(0.0_f64, |acc, c| acc.max(c.abs()));
+        let value_at = |z: (f64, f64)| {
+            coefs.iter().rev().fold((0.0, 0.0), |acc, &c| {
+                let (re, im) = complex_mul(acc, z);
+                (re + c, im)
+            })
+        };
+
+        // starting points are not symmetric to real axis, so complex roots are reachable
+        let mut roots: Vec<(f64, f64)> = Vec::with_capacity(n);
+        let mut start = (1.0, 0.0);
+        for _ in 0..n {
+            roots.push((start.0 * bound, start.1 * bound));
+            start = complex_mul(start, (0.4, 0.9));
+        }
+        for _ in 0..1000 {
+            let mut change: f64 = 0.0;
+            for k in 0..n {
+                let mut den = (1.0, 0.0);
+                for j in 0..n {
+                    if j != k {
+                        den = complex_mul(den, (roots[k].0 - roots[j].0, roots[k].1 - roots[j].1));
+                    }
+                }
+                let step = complex_div(value_at(roots[k]), den);
+                if !step.0.is_finite() || !step.1.is_finite() {
+                    continue;
+                }
+                roots[k] = (roots[k].0 - step.0, roots[k].1 - step.1);
+                change = change.max(step.0.abs() + step.1.abs());
+            }
+            if change <= f64::EPSILON * bound {
+                break;
+            }
+        }
+
+        for root in roots.iter_mut() {
+            if root.1.abs() <= 1e-10 * (1.0 + root.0.abs()) {
+                root.1 = 0.0;
+            }
+        }
+        roots.sort_by(|a, b| a.partial_cmp(b).expect("roots are not NaN"));
+        roots
+    }
+
+    // applies f to every coef and drops zero results
+    fn map_coefs<F: Fn(f64) -> f64>(self, f: F) -> Polynomial<f64, U> {
+        let mut ans = Polynomial::new();
+        ans.auto_reduce = self.auto_reduce;
+        for (coef, pow) in self.members {
+            let coef = f(coef);
+            if coef != 0.0 {
+                ans.push(coef, pow);
+            }
+        }
+        ans
+    }
+}
@@ -0,0 +1,288 @@
+// Root finding and evaluation for polynomials over `Zn`,
+// reduction of integer polynomials modulo n.
+//
+// For odd prime N polynomials are kept as dense coef vectors here
+// (index is power, no trailing zeros), inverses are taken by Fermat's little theorem.
+
+use super::{Polynomial, Powered};
+use crate::custom_types::zp::is_prime;
+use crate::custom_types::{DynZn, Zn};
+use crate::{One, Zero};
+
+type Dense<const N: u32> = Vec<Zn<N>>;
+
+fn trim<const N: u32>(mut a: Dense<N>) -> Dense<N> {
+    while a.last().is_some_and(Zn::is_zero) {
+        a.pop();
+    }
+    a
+}
+
+fn inv<const N: u32>(a: Zn<N>) -> Zn<N> {
+    a.pow(N as u64 - 2)
+}
+
+// remainder of a divided by nonzero b
+fn rem<const N: u32>(mut a: Dense<N>, b: &Dense<N>) -> Dense<N> {
+    let lead = inv(*b.last().expect("divisor is not zero"));
+    while a.len() >= b.len() {
+        let coef = *a.last().expect("a is longer than b") * lead;
+        let shift = a.len() - b.len();
+        for (i, &c) in b.iter().enumerate() {
+            a[i + shift] -= coef * c;
+        }
+        a = trim(a);
+    }
+    a
+}
+
+fn mul_rem<const N: u32>(a: &Dense<N>, b: &Dense<N>, modulus: &Dense<N>) -> Dense<N> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let mut ans = vec![Zn::zero(); a.len() + b.len() - 1];
+    for (i, &x) in a.iter().enumerate() {
+        for (j, &y) in b.iter().enumerate() {
+            ans[i + j] += x * y;
+        }
+    }
+    rem(trim(ans), modulus)
+}
+
+fn pow_rem<const N: u32>(base: &Dense<N>, mut exp: u64, modulus: &Dense<N>) -> Dense<N> {
+    let mut ans = rem(vec![Zn::one()], modulus);
+    let mut base = rem(base.clone(), modulus);
+    while exp > 0 {
+        if exp & 1 == 1 {
+            ans = mul_rem(&ans, &base, modulus);
+        }
+        base = mul_rem(&base, &base, modulus);
+        exp >>= 1;
+    }
+    ans
+}
+
+// a - b
+fn sub<const N: u32>(mut a: Dense<N>, b: &Dense<N>) -> Dense<N> {
+    if a.len() < b.len() {
+        a.resize(b.len(), Zn::zero());
+    }
+    for (i, &c) in b.iter().enumerate() {
+        a[i] -= c;
+    }
+    trim(a)
+}
+
+// monic gcd, a and b are not both zero
+fn gcd<const N: u32>(mut a: Dense<N>, mut b: Dense<N>) -> Dense<N> {
+    while !b.is_empty() {
+        let r = rem(a, &b);
+        a = b;
+        b = r;
+    }
+    let lead = inv(*a.last().expect("gcd is not zero"));
+    a.into_iter().map(|c| c * lead).collect()
+}
+
+// pushes roots of monic f, that is product of distinct linear factors (Cantor–Zassenhaus)
+fn split<const N: u32>(f: Dense<N>, roots: &mut Vec<Zn<N>>) {
+    match f.len() {
+        0 | 1 => return,
+        // f is x + f0
+        2 => return roots.push(Zn::zero() - f[0]),
+        _ => {}
+    }
+    // roots r with (r + a) being quadratic residue are roots of gcd,
+    // some shift a separates any two roots
+    for a in 0..N {
+        let shifted = pow_rem(&vec![Zn::new(a), Zn::one()], (N as u64 - 1) / 2, &f);
+        let g = gcd(f.clone(), sub(shifted, &vec![Zn::one()]));
+        if g.len() > 1 && g.len() < f.len() {
+            let mut h = f;
+            let mut quotient = vec![Zn::zero(); h.len() - g.len() + 1];
+            while h.len() >= g.len() {
+                let coef = *h.last().expect("h is longer than g");
+                let shift = h.len() - g.len();
+                quotient[shift] = coef;
+                for (i, &c) in g.iter().enumerate() {
+                    h[i + shift] -= coef * c;
+                }
+                h = trim(h);
+            }
+            split(g, roots);
+            split(quotient, roots);
+            return;
+        }
+    }
+}
+
+// in-place radix-2 transform: a[k] becomes sum a[i] omega^(ik), omega^n = 1, n = 2^m
+pub(super) fn ntt<const N: u32>(a: &mut [Zn<N>], omega: Zn<N>) {
+    let n = a.len();
+    if n < 2 {
+        return;
+    }
+    let bits = n.trailing_zeros();
+    for i in 0..n {
+        let j = i.reverse_bits() >> (usize::BITS - bits);
+        if i < j {
+            a.swap(i, j);
+        }
+    }
+    let mut len = 2;
+    while len <= n {
+        let step = omega.pow((n / len) as u64);
+        for start in (0..n).step_by(len) {
+            let mut w = Zn::one();
+            for i in start..start + len / 2 {
+                let (u, v) = (a[i], a[i + len / 2] * w);
+                a[i] = u + v;
+                a[i + len / 2] = u - v;
+                w *= step;
+            }
+        }
+        len *= 2;
+    }
+}
+
+impl<const N: u32, U> Polynomial<Zn<N>, U> {
+    /// Returns all residues r in ascending order, where p(r) = 0 in Zn<N>.
+    /// Zero polynomial vanishes at every residue.
+    ///
+    /// For odd prime N roots are found as roots of gcd(p, x^N - x),
+    /// which is split by Cantor–Zassenhaus algorithm,
+    /// so N may be large. For other N every residue is checked.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::Zn;
+    /// # use polylib::polynom::Polynomial;
+    /// // x^2 - 1 has four roots modulo 8
+    /// let p = Polynomial::<Zn<8>>::from_coefs(vec![Zn::from_i64(-1), Zn::new(0), Zn::new(1)]);
+    /// let roots: Vec<u32> = p.roots_mod_n().iter().map(Zn::value).collect();
+    /// assert_eq!(roots, vec![1, 3, 5, 7]);
+    ///
+    /// // x^2 + 1 over Z(1_000_000_007) has no roots, since 10^9 + 7 = 3 mod 4
+    /// type F = Zn<1_000_000_007>;
+    /// let p = Polynomial::<F>::from_coefs(vec![F::new(1), F::new(0), F::new(1)]);
+    /// assert!(p.roots_mod_n().is_empty());
+    /// ```
+    pub fn roots_mod_n(&self) -> Vec<Zn<N>> {
+        let reduced = self.clone().reduce();
+        let degree = match reduced.degree() {
+            Some(degree) => degree,
+            None => return (0..N).map(Zn::new).collect(),
+        };
+        if N == 2 || !is_prime(N) {
+            return (0..N)
+                .map(Zn::new)
+                .filter(|&r| reduced.substitude::<Zn<N>, Zn<N>>(r).is_zero())
+                .collect();
+        }
+
+        let f: Dense<N> = (0..=degree).map(|i| reduced.coef(i)).collect();
+        let x = vec![Zn::zero(), Zn::one()];
+        let frobenius = pow_rem(&x, N as u64, &f);
+        let g = gcd(f, sub(frobenius, &x));
+        let mut roots = Vec::with_capacity(g.len() - 1);
+        split(g, &mut roots);
+        roots.sort();
+        roots
+    }
+
+    /// Returns values p(1), p(omega), ..., p(omega^(n-1)).
+    ///
+    /// If n is power of two and omega is primitive root of unity of order n
+    /// (that is omega^(n/2) = -1), values are found by number theoretic
+    /// transform in O(n log n) (after coefs are folded by x^n = 1),
+    /// otherwise each value is computed separately.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::custom_types::Zn;
+    /// # use polylib::polynom::Polynomial;
+    /// type F = Zn<998_244_353>;
+    /// let omega = F::new(3).pow((998_244_353 - 1) / 8); // root of unity of order 8
+    /// let p = Polynomial::<F>::from_coefs(vec![F::new(1), F::new(2), F::new(3)]);
+    /// let values = p.evaluate_at_root_powers(omega, 8);
+    /// assert_eq!(values[0], F::new(6));
+    /// assert_eq!(values[4], F::new(2)); // omega^4 = -1
+    /// assert_eq!(values[3], p.substitude(omega.pow(3)));
+    /// ```
+    pub fn evaluate_at_root_powers(&self, omega: Zn<N>, n: usize) -> Vec<Zn<N>> {
+        let reduced = self.clone().reduce();
+        // butterflies need omega^(n/2) = -1, omega^n = 1 is not enough
+        let primitive = match n {
+            1 => true,
+            _ => n.is_power_of_two() && omega.pow(n as u64 / 2) == Zn::from_i64(-1),
+        };
+        if !primitive {
+            let mut point = Zn::one();
+            let mut ans = Vec::with_capacity(n);
+            for _ in 0..n {
+                ans.push(reduced.substitude(point));
+                point *= omega;
+            }
+            return ans;
+        }
+        let mut values = vec![Zn::zero(); n];
+        for (coef, pow) in &reduced.members {
+            values[pow.power as usize % n] += *coef;
+        }
+        ntt(&mut values, omega);
+        values
+    }
+}
+
+impl Polynomial<i64> {
+    /// Returns polynomial with every coef reduced modulo N
+    /// (negative coefs are wrapped into [0, N)).
+    /// Members, that become zero, are dropped, others are not summed up.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::polynom::Polynomial;
+    /// # use polylib::custom_types::Zn;
+    /// let p = Polynomial::<i64>::from_coefs(vec![-1, 10, 6]); // 6x^2 + 10x - 1
+    /// let q = p.mod_n::<5>();
+    /// assert_eq!(q, Polynomial::from_coefs(vec![Zn::new(4), Zn::new(0), Zn::new(1)]));
+    /// assert_eq!(q.len(), 2);
+    /// ```
+    pub fn mod_n<const N: u32>(&self) -> Polynomial<Zn<N>> {
+        let mut ans = Polynomial::new();
+        ans.members.reserve(self.members.len());
+        for (coef, pow) in self.members.iter() {
+            let coef = Zn::from_i64(*coef);
+            if !coef.is_zero() {
+                ans.push(coef, Powered::new(pow.power));
+            }
+        }
+        ans
+    }
+
+    /// Same as `mod_n`, but modulus is known only at runtime.
+    /// Panics for zero modulus.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::polynom::Polynomial;
+    /// let p = Polynomial::<i64>::from_coefs(vec![-1, 10, 6]);
+    /// let q = p.mod_dyn(5);
+    /// assert_eq!(q.coef(0).value(), 4);
+    /// assert_eq!(q.coef(2).modulus(), 5);
+    /// ```
+    pub fn mod_dyn(&self, modulus: u32) -> Polynomial<DynZn> {
+        if modulus == 0 {
+            panic!("Can't create DynZn with modulus 0");
+        }
+        let mut ans = Polynomial::new();
+        ans.members.reserve(self.members.len());
+        for (coef, pow) in self.members.iter() {
+            let coef = DynZn::from_i64(*coef, modulus);
+            if coef.value() != 0 {
+                ans.push(coef, Powered::new(pow.power));
+            }
+        }
+        ans
+    }
+}
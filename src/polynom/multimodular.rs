@@ -0,0 +1,158 @@
+//! Exact multiplication of integer polynomials by number theoretic transform
+//! modulo several primes and Chinese remainder theorem.
+//!
+//! Example:
+//! ```
+//! # use polylib::polynom::{multimodular, Polynomial};
+//! let big = 1i64 << 50;
+//! let a = Polynomial::<i64>::from_coefs(vec![big, -1, 3]);
+//! let b = Polynomial::<i64>::from_coefs(vec![big, 5]);
+//! let c = multimodular::mul(&a, &b); // doesn't fit into i64
+//! assert_eq!(c.coef(0), 1i128 << 100);
+//! assert_eq!(c.coef(1), 5 * (1i128 << 50) - (1i128 << 50));
+//! assert_eq!(c.coef(3), 15);
+//! ```
+
+use super::modular::ntt;
+use super::{Polynomial, Powered};
+use crate::custom_types::Zn;
+use crate::Zero;
+
+// primes p = c * 2^k + 1 with primitive roots, their product is about 2^115.5
+const P1: u32 = 998_244_353; // 119 * 2^23 + 1
+const P2: u32 = 167_772_161; // 5 * 2^25 + 1
+const P3: u32 = 469_762_049; // 7 * 2^26 + 1
+const P4: u32 = 754_974_721; // 45 * 2^24 + 1
+const PRIMES: [u32; 4] = [P1, P2, P3, P4];
+
+/// Maximal length of product (count of coefs up to degree), that can be transformed:
+/// 2^23 divides p - 1 for every prime.
+pub const MAX_LEN: usize = 1 << 23;
+
+// product of a and b modulo P, coefs are dense, n is power of two
+fn mul_mod<const P: u32>(a: &[i64], b: &[i64], root: u32, n: usize) -> Vec<u32> {
+    let omega = Zn::<P>::new(root).pow((P as u64 - 1) / n as u64);
+    let transform = |coefs: &[i64]| {
+        let mut values = vec![Zn::<P>::zero(); n];
+        for (value, coef) in values.iter_mut().zip(coefs) {
+            *value = Zn::from_i64(*coef);
+        }
+        ntt(&mut values, omega);
+        values
+    };
+    let mut values = transform(a);
+    for (value, other) in values.iter_mut().zip(transform(b)) {
+        *value *= other;
+    }
+    // inverse transform by inverse root, then division by n
+    ntt(&mut values, omega.pow(P as u64 - 2));
+    let n_inv = Zn::<P>::new(n as u32).pow(P as u64 - 2);
+    values.into_iter().map(|v| (v * n_inv).value()).collect()
+}
+
+// x mod p for x < 2^128
+fn rem(x: u128, p: u32) -> u64 {
+    (x % p as u128) as u64
+}
+
+// modular inverse by Fermat's little theorem
+fn inv(a: u64, p: u32) -> u64 {
+    let (mut ans, mut base, mut exp) = (1u64, a % p as u64, p as u64 - 2);
+    while exp > 0 {
+        if exp & 1 == 1 {
+            ans = ans * base % p as u64;
+        }
+        base = base * base % p as u64;
+        exp >>= 1;
+    }
+    ans
+}
+
+// Garner's algorithm: x = a1 + a2 * p1 + a3 * p1 * p2 + ..., then x is taken from (-M/2, M/2]
+fn reconstruct(residues: &[u32], primes: &[u32]) -> i128 {
+    let mut x: u128 = 0;
+    let mut modulus: u128 = 1;
+    for (&r, &p) in residues.iter().zip(primes) {
+        let diff = (r as u64 + p as u64 - rem(x, p)) % p as u64;
+        let digit = diff * inv(rem(modulus, p), p) % p as u64;
+        x += digit as u128 * modulus;
+        modulus *= p as u128;
+    }
+    if x > modulus / 2 {
+        -((modulus - x) as i128)
+    } else {
+        x as i128
+    }
+}
+
+// dense coefs of reduced polynomial and maximal absolute value of them
+fn dense(p: &Polynomial<i64>) -> (Vec<i64>, u128) {
+    let reduced = p.clone().reduce();
+    let len = reduced.members.last().map_or(0, |(_, pow)| pow.power as usize + 1);
+    let mut coefs = vec![0; len];
+    let mut max = 0;
+    for (coef, pow) in reduced.members.iter() {
+        coefs[pow.power as usize] = *coef;
+        max = max.max(coef.unsigned_abs() as u128);
+    }
+    (coefs, max)
+}
+
+/// Returns exact product of integer polynomials.
+///
+/// Polynomials are multiplied by number theoretic transform modulo
+/// up to four primes in O(n log n), and coefs are restored by Chinese remainder theorem.
+/// Count of primes is chosen by bound `min(len(a), len(b)) * max|a_i| * max|b_j|`
+/// of product coefs, so small coefs take one prime only.
+///
+/// Panics, if product has more than `MAX_LEN` coefs, or if bound of its coefs
+/// exceeds 2^114 (product of primes is about 2^115.5).
+pub fn mul(a: &Polynomial<i64>, b: &Polynomial<i64>) -> Polynomial<i128> {
+    let (a, a_max) = dense(a);
+    let (b, b_max) = dense(b);
+    if a.is_empty() || b.is_empty() {
+        return Polynomial::new();
+    }
+    let len = a.len() + b.len() - 1;
+    if len > MAX_LEN {
+        panic!("product of {} coefs is too long for transform", len);
+    }
+    let n = len.next_power_of_two();
+
+    let bound = (a.len().min(b.len()) as u128)
+        .checked_mul(a_max)
+        .and_then(|bound| bound.checked_mul(b_max))
+        .filter(|bound| *bound < 1 << 114)
+        .unwrap_or_else(|| panic!("coefs of product don't fit into 2^114"));
+    // product of the first k primes should be more than 2 * bound
+    let mut count = 1;
+    let mut modulus = P1 as u128;
+    while modulus / 2 <= bound {
+        modulus *= PRIMES[count] as u128;
+        count += 1;
+    }
+
+    let mut residues: Vec<Vec<u32>> = vec![mul_mod::<P1>(&a, &b, 3, n)];
+    if count > 1 {
+        residues.push(mul_mod::<P2>(&a, &b, 3, n));
+    }
+    if count > 2 {
+        residues.push(mul_mod::<P3>(&a, &b, 3, n));
+    }
+    if count > 3 {
+        residues.push(mul_mod::<P4>(&a, &b, 11, n));
+    }
+
+    let mut ans = Polynomial::new();
+    let mut column = vec![0; count];
+    for i in 0..len {
+        for (k, values) in residues.iter().enumerate() {
+            column[k] = values[i];
+        }
+        let coef = reconstruct(&column, &PRIMES[..count]);
+        if !coef.is_zero() {
+            ans.push(coef, Powered::new(i as u32));
+        }
+    }
+    ans
+}
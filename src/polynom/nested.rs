@@ -0,0 +1,153 @@
+//! Operations on nested polynomials `Polynomial<Polynomial<T, V>, U>`,
+//! that treat them as polynomials of two variables.
+//!
+//! `Zero::is_zero` panics for polynomials, so outer coefs are never
+//! checked for zero: they are dropped only if they have no members.
+
+use std::ops::{Add, Mul};
+
+use super::{times, Polynomial, Powered};
+use crate::{One, Zero};
+
+impl<T, U> Polynomial<T, U> {
+    /// Returns derivative of polynomial.
+    ///
+    /// Coef of x^k is multiplied by k through additions, so any ring works.
+    /// Members with zero coef after multiplication are dropped.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::polynom::X;
+    /// # let x = X::<i32>::default();
+    /// let p = x.pow(3) * 2 - x.pow(1) + 5; // 2x^3 - x + 5
+    /// assert_eq!(p.derivative(), x.pow(2) * 6 - 1);
+    /// ```
+    pub fn derivative(&self) -> Polynomial<T, U>
+    where
+        T: Clone + Zero,
+        T: Add<T, Output = T>,
+    {
+        let mut ans = Polynomial::new();
+        ans.auto_reduce = self.auto_reduce;
+        for (coef, pow) in self.members.iter() {
+            if pow.power == 0 {
+                continue;
+            }
+            let coef = times(coef.clone(), pow.power);
+            if !coef.is_zero() {
+                ans.push(coef, Powered::new(pow.power - 1));
+            }
+        }
+        ans
+    }
+}
+
+impl<T, V, U> Polynomial<Polynomial<T, V>, U> {
+    /// Returns partial derivative by outer variable (x in `Polynomial<Polynomial<T, Y>, X>`).
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::polynom::{Polynomial, X, Y};
+    /// type Inner = Polynomial<i32, Y<i32>>;
+    /// let x = X::<Inner>::default();
+    /// let y = Y::<i32>::default();
+    /// // (y^2 + 1) x^3 + y x
+    /// let p = x.pow(3) * (y.pow(2) + 1) + x.pow(1) * y.pow(1);
+    /// // 3(y^2 + 1) x^2 + y
+    /// let q = p.d_dx();
+    /// assert_eq!(q.get(2), Some(&(y.pow(2) * 3 + 3)));
+    /// assert_eq!(q.get(0), Some(&(y.pow(1) + 0)));
+    /// ```
+    pub fn d_dx(&self) -> Polynomial<Polynomial<T, V>, U>
+    where
+        T: Clone + Zero,
+        T: Add<T, Output = T>,
+    {
+        let mut ans = Polynomial::new();
+        ans.auto_reduce = self.auto_reduce;
+        for (coef, pow) in self.members.iter() {
+            if pow.power == 0 {
+                continue;
+            }
+            // inner coefs are multiplied, so inner polynomial doesn't grow
+            let mut inner = Polynomial::new();
+            inner.auto_reduce = coef.auto_reduce;
+            for (c, p) in coef.members.iter() {
+                let c = times(c.clone(), pow.power);
+                if !c.is_zero() {
+                    inner.push(c, p.clone());
+                }
+            }
+            if !inner.members.is_empty() {
+                ans.push(inner, Powered::new(pow.power - 1));
+            }
+        }
+        ans
+    }
+
+    /// Returns partial derivative by inner variable (y in `Polynomial<Polynomial<T, Y>, X>`).
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::polynom::{Polynomial, X, Y};
+    /// type Inner = Polynomial<i32, Y<i32>>;
+    /// let x = X::<Inner>::default();
+    /// let y = Y::<i32>::default();
+    /// // (y^2 + 1) x^3 + y x + 7
+    /// let p = x.pow(3) * (y.pow(2) + 1) + x.pow(1) * y.pow(1) + Inner::new_const(7);
+    /// // 2y x^3 + x
+    /// let q = p.d_dy();
+    /// assert_eq!(q.get(3), Some(&(y.pow(1) * 2)));
+    /// assert_eq!(q.get(1), Some(&Inner::new_const(1)));
+    /// assert!(q.get(0).is_none());
+    /// ```
+    pub fn d_dy(&self) -> Polynomial<Polynomial<T, V>, U>
+    where
+        T: Clone + Zero,
+        T: Add<T, Output = T>,
+    {
+        let mut ans = Polynomial::new();
+        ans.auto_reduce = self.auto_reduce;
+        for (coef, pow) in self.members.iter() {
+            let inner = coef.derivative();
+            if !inner.members.is_empty() {
+                ans.push(inner, pow.clone());
+            }
+        }
+        ans
+    }
+
+    /// Substitudes value of outer variable (x in `Polynomial<Polynomial<T, Y>, X>`)
+    /// and returns polynomial of inner variable.
+    ///
+    /// Like other operations, result is not reduced.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::polynom::{Polynomial, X, Y};
+    /// type Inner = Polynomial<i32, Y<i32>>;
+    /// let x = X::<Inner>::default();
+    /// let y = Y::<i32>::default();
+    /// // (y^2 + 1) x^2 + y x
+    /// let p = x.pow(2) * (y.pow(2) + 1) + x.pow(1) * y.pow(1);
+    /// // 9(y^2 + 1) + 3y at x = 3
+    /// let q = p.partial_substitude(3);
+    /// assert_eq!(q, y.pow(2) * 9 + y.pow(1) * 3 + 9);
+    /// ```
+    pub fn partial_substitude(&self, x_value: T) -> Polynomial<T, V>
+    where
+        T: Clone + One,
+        T: Mul<T, Output = T>,
+    {
+        let mut ans = Polynomial::new();
+        for (coef, pow) in self.members.iter() {
+            let power = pow.substitude(x_value.clone());
+            ans.auto_reduce = ans.auto_reduce.or(coef.auto_reduce);
+            for (c, p) in coef.members.iter() {
+                ans.push(c.clone() * power.clone(), p.clone());
+            }
+        }
+        ans.auto_reduce();
+        ans
+    }
+}
@@ -0,0 +1,158 @@
+//! Norms of polynomial coefs and bounds, that are derived from them.
+
+use std::ops::{Add, Mul, Neg};
+
+use super::Polynomial;
+use crate::custom_types::BigInt;
+use crate::{One, Zero};
+
+// absolute value through ordering, so no special trait is needed
+fn abs<T>(value: T) -> T
+where
+    T: Zero + PartialOrd,
+    T: Neg<Output = T>,
+{
+    if value < T::zero() {
+        -value
+    } else {
+        value
+    }
+}
+
+// smallest r with r * r >= value, value is not negative
+fn ceil_sqrt(value: &BigInt) -> BigInt {
+    if value.is_zero() {
+        return BigInt::zero();
+    }
+    let two = BigInt::from(2);
+    // power of two, that is not less than floor of root
+    let mut x = BigInt::one();
+    while &(&x * &x) < value {
+        x = &x * &two;
+    }
+    // Newton's method decreases x down to floor of root
+    loop {
+        let y = (&x + &(value / &x)) / two.clone();
+        if y >= x {
+            break;
+        }
+        x = y;
+    }
+    if &(&x * &x) < value {
+        x += BigInt::one();
+    }
+    x
+}
+
+impl<T, U> Polynomial<T, U>
+where
+    T: Clone + Zero + PartialOrd,
+    T: Add<T, Output = T>,
+    T: Neg<Output = T>,
+{
+    /// Returns sum of absolute values of coefs (of reduced polynomial).
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::polynom::X;
+    /// # let x = X::<i32>::default();
+    /// let p = x.pow(3) * 2 - x.pow(1) * 5 + 3 + x.pow(1); // 2x^3 - 4x + 3
+    /// assert_eq!(p.l1_norm(), 9);
+    /// ```
+    pub fn l1_norm(&self) -> T {
+        let reduced = self.clone().reduce();
+        let mut ans = T::zero();
+        for (coef, _) in reduced.members {
+            ans = ans + abs(coef);
+        }
+        ans
+    }
+
+    /// Returns maximal absolute value of coefs (of reduced polynomial),
+    /// zero for zero polynomial.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::polynom::Polynomial;
+    /// let p = Polynomial::<f64>::from_coefs(vec![1.5, -7.0, 2.0]);
+    /// assert_eq!(p.max_norm(), 7.0);
+    /// ```
+    pub fn max_norm(&self) -> T {
+        let reduced = self.clone().reduce();
+        let mut ans = T::zero();
+        for (coef, _) in reduced.members {
+            let coef = abs(coef);
+            if coef > ans {
+                ans = coef;
+            }
+        }
+        ans
+    }
+
+    /// Returns height of polynomial: maximal absolute value of coefs,
+    /// same as `max_norm`, but named as in number theory.
+    pub fn height(&self) -> T {
+        self.max_norm()
+    }
+}
+
+impl<T, U> Polynomial<T, U>
+where
+    T: Clone + Zero,
+    T: Add<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    /// Returns sum of squares of coefs (of reduced polynomial),
+    /// that is square of euclidean norm, so it is exact for integer coefs.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::polynom::Polynomial;
+    /// let p = Polynomial::<i32>::from_coefs(vec![1, -2, 0, 2]);
+    /// assert_eq!(p.l2_norm_sqr(), 9);
+    /// ```
+    pub fn l2_norm_sqr(&self) -> T {
+        let reduced = self.clone().reduce();
+        let mut ans = T::zero();
+        for (coef, _) in reduced.members {
+            ans = ans + coef.clone() * coef;
+        }
+        ans
+    }
+}
+
+impl<T, U> Polynomial<T, U>
+where
+    T: Clone + Zero + Into<BigInt>,
+    T: Add<T, Output = T>,
+{
+    /// Returns Mignotte bound for integer polynomial f of degree n:
+    /// `2^n * ceil(||f||_2)`.
+    ///
+    /// If g divides f over integers, then sum of absolute values of coefs of g
+    /// is at most `2^deg(g) * ||f||_2`, so no coef of any factor exceeds the bound.
+    /// Zassenhaus factorization lifts factors modulo p^k > 2 * bound.
+    /// Computed in `BigInt`, so it never overflows. Zero for zero polynomial.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::polynom::Polynomial;
+    /// # use polylib::custom_types::BigInt;
+    /// // x^4 - 1 = (x - 1)(x + 1)(x^2 + 1), ||f||_2 = sqrt(2)
+    /// let p = Polynomial::<i64>::from_coefs(vec![-1, 0, 0, 0, 1]);
+    /// assert_eq!(p.factor_coefficient_bound(), BigInt::from(16 * 2));
+    /// ```
+    pub fn factor_coefficient_bound(&self) -> BigInt {
+        let reduced = self.clone().reduce();
+        let degree = match reduced.members.last() {
+            Some((_, pow)) => pow.power,
+            None => return BigInt::zero(),
+        };
+        let mut norm_sqr = BigInt::zero();
+        for (coef, _) in reduced.members {
+            let coef: BigInt = coef.into();
+            norm_sqr = &norm_sqr + &(&coef * &coef);
+        }
+        BigInt::from(2).pow(degree) * ceil_sqrt(&norm_sqr)
+    }
+}
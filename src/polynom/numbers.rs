@@ -0,0 +1,171 @@
+//! Combinatorial numbers, that come from polynomials:
+//! binomial coefs, falling factorials, Stirling numbers of both kinds,
+//! Bell and Bernoulli numbers.
+//!
+//! Values are exact in any ring (Bernoulli numbers need a field, as `Fraction`),
+//! so they can be taken modulo prime with `Zp` as well.
+//!
+//! Example:
+//! ```
+//! # use polylib::polynom::numbers;
+//! # use polylib::custom_types::Fraction;
+//! // x^3 = x + 3x(x - 1) + x(x - 1)(x - 2)
+//! assert_eq!(numbers::stirling2::<i64>(3, 2), 3);
+//! assert_eq!(numbers::bell::<i64>(5), 52);
+//! assert_eq!(numbers::bernoulli::<Fraction<i64>>(6), Fraction::new(1, 42));
+//! ```
+
+use std::ops::Sub;
+
+use super::{times, Polynomial};
+use crate::{CoefField, CoefRing, CoefSemiring};
+
+/// Returns binomial coef C(n, k) in ring T, zero for k > n.
+///
+/// It is found by Pascal's triangle with additions only, so it is exact in any
+/// semiring, as `Zn` with composite modulus, where n! is not invertible.
+/// Takes O(n k) additions, for large n modulo prime use `Zp::binomial`.
+///
+/// Example:
+/// ```
+/// # use polylib::polynom::numbers;
+/// # use polylib::custom_types::Zn;
+/// assert_eq!(numbers::binomial::<i64>(10, 3), 120);
+/// assert_eq!(numbers::binomial::<Zn<12>>(10, 3), Zn::new(0));
+/// assert_eq!(numbers::binomial::<i64>(3, 10), 0);
+/// ```
+pub fn binomial<T: CoefSemiring>(n: u32, k: u32) -> T {
+    if k > n {
+        return T::zero();
+    }
+    let k = k.min(n - k) as usize;
+    // row[j] = C(i, j) for j <= k
+    let mut row = vec![T::zero(); k + 1];
+    row[0] = T::one();
+    for i in 1..=n as usize {
+        for j in (1..=k.min(i)).rev() {
+            row[j] = row[j].clone() + row[j - 1].clone();
+        }
+    }
+    row[k].clone()
+}
+
+/// Returns falling factorial x(x - 1)...(x - k + 1) of element of ring,
+/// one for k = 0. It needs no division, so x may be any ring element,
+/// even polynomial.
+///
+/// Example:
+/// ```
+/// # use polylib::polynom::numbers;
+/// # use polylib::custom_types::Fraction;
+/// assert_eq!(numbers::falling_factorial(5i64, 3), 60);
+/// assert_eq!(numbers::falling_factorial(2i64, 3), 0);
+/// let half = Fraction::<i64>::new(1, 2);
+/// assert_eq!(numbers::falling_factorial(half, 2), Fraction::new(-1, 4));
+/// ```
+pub fn falling_factorial<T>(x: T, k: u32) -> T
+where
+    T: CoefSemiring + Sub<Output = T>,
+{
+    let mut ans = T::one();
+    let mut factor = x;
+    for _ in 0..k {
+        ans = ans * factor.clone();
+        factor = factor - T::one();
+    }
+    ans
+}
+
+/// Returns falling factorial x(x - 1)...(x - n + 1), whose coefs are
+/// signed Stirling numbers of the first kind s(n, k).
+///
+/// Example:
+/// ```
+/// # use polylib::polynom::{numbers, Polynomial};
+/// // x(x - 1)(x - 2) = 2x - 3x^2 + x^3
+/// assert_eq!(numbers::stirling1_row::<i32>(3), Polynomial::from_coefs(vec![0, 2, -3, 1]));
+/// ```
+pub fn stirling1_row<T: CoefRing>(n: u32) -> Polynomial<T> {
+    let mut ans = Polynomial::new_const(T::one());
+    for i in 0..n {
+        let factor = Polynomial::from_coefs(vec![-times(T::one(), i), T::one()]);
+        ans = (ans * factor).reduce();
+    }
+    ans
+}
+
+/// Returns Touchard polynomial S(n, 0) + S(n, 1) x + ... + S(n, n) x^n,
+/// whose coefs are Stirling numbers of the second kind:
+/// counts of partitions of n elements into k nonempty blocks.
+///
+/// Rows are found by `T(n + 1) = x (T(n) + T(n)')`,
+/// that is recurrence `S(n + 1, k) = k S(n, k) + S(n, k - 1)`.
+///
+/// Example:
+/// ```
+/// # use polylib::polynom::{numbers, Polynomial};
+/// assert_eq!(numbers::stirling2_row::<i32>(4), Polynomial::from_coefs(vec![0, 1, 7, 6, 1]));
+/// ```
+pub fn stirling2_row<T: CoefRing>(n: u32) -> Polynomial<T> {
+    let x = Polynomial::<T>::from_coefs(vec![T::zero(), T::one()]);
+    let mut ans = Polynomial::new_const(T::one());
+    for _ in 0..n {
+        let derivative = ans.derivative();
+        ans = (x.clone() * (ans + derivative)).reduce();
+    }
+    ans
+}
+
+/// Returns signed Stirling number of the first kind s(n, k):
+/// coef of x^k in x(x - 1)...(x - n + 1). It is zero for k > n.
+pub fn stirling1<T: CoefRing>(n: u32, k: u32) -> T {
+    stirling1_row::<T>(n).coef(k)
+}
+
+/// Returns Stirling number of the second kind S(n, k):
+/// count of partitions of n elements into k nonempty blocks.
+pub fn stirling2<T: CoefRing>(n: u32, k: u32) -> T {
+    stirling2_row::<T>(n).coef(k)
+}
+
+/// Returns Bell number: count of all partitions of n elements,
+/// that is value of Touchard polynomial at 1.
+///
+/// Example:
+/// ```
+/// # use polylib::polynom::numbers;
+/// let bell: Vec<i64> = (0..7).map(numbers::bell).collect();
+/// assert_eq!(bell, vec![1, 1, 2, 5, 15, 52, 203]);
+/// ```
+pub fn bell<T: CoefRing>(n: u32) -> T {
+    stirling2_row::<T>(n).substitude(T::one())
+}
+
+/// Returns Bernoulli number B(n) with B(1) = -1/2, found from Stirling numbers
+/// of the second kind: `B(n) = sum of (-1)^k k! S(n, k) / (k + 1)`.
+/// Odd ones except B(1) are zero.
+///
+/// Coefs should form a field, where 2, ..., n + 1 are invertible.
+///
+/// Example:
+/// ```
+/// # use polylib::polynom::numbers;
+/// # use polylib::custom_types::Fraction;
+/// type Q = Fraction<i64>;
+/// let b: Vec<Q> = (0..5).map(numbers::bernoulli).collect();
+/// assert_eq!(b, vec![Q::from(1), Q::new(-1, 2), Q::new(1, 6), Q::from(0), Q::new(-1, 30)]);
+/// ```
+pub fn bernoulli<T: CoefField>(n: u32) -> T {
+    let row = stirling2_row::<T>(n);
+    let mut ans = T::zero();
+    // (-1)^k k!
+    let mut factorial = T::one();
+    for k in 0..=n {
+        if k > 0 {
+            factorial = -(factorial * times(T::one(), k));
+        }
+        let term = factorial.clone() * row.coef(k) / times(T::one(), k + 1);
+        ans = ans + term;
+    }
+    ans
+}
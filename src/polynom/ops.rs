@@ -0,0 +1,170 @@
+//! Linear operators on polynomials as values: `Differentiate`, `Integrate`,
+//! `Shift`, `Scale` and `Truncate`. They share trait `Operator`, so they can be
+//! chained by `then` into pipelines, that are stored and applied many times.
+//!
+//! Example:
+//! ```
+//! # use polylib::polynom::ops::{Differentiate, Operator, Shift, Truncate};
+//! # use polylib::polynom::Polynomial;
+//! // shift, differentiate twice, truncate
+//! let pipeline = Shift(1).then(Differentiate).then(Differentiate).then(Truncate(1));
+//! let p = Polynomial::<i32>::from_coefs(vec![0, 0, 0, 1]); // x^3
+//! // (x + 1)^3 = x^3 + 3x^2 + 3x + 1, second derivative is 6x + 6
+//! assert_eq!(pipeline.apply(&p), Polynomial::from_coefs(vec![6, 6]));
+//! ```
+
+use std::ops::{Add, Div, Mul, Sub};
+
+use super::{times, Polynomial, Powered, X};
+use crate::{One, Zero};
+
+/// Operator, that maps polynomial to another polynomial
+/// with coefs of the same type and the same variable.
+pub trait Operator<T, U = X<T>> {
+    /// Returns image of polynomial, polynomial itself is not changed.
+    fn apply(&self, poly: &Polynomial<T, U>) -> Polynomial<T, U>;
+}
+
+// `then` is inherent, so it does not need types of coefs to be known yet
+macro_rules! impl_then {
+    ($($name:ident $(<$($gen:ident),+>)?),+) => {$(
+        impl$(<$($gen),+>)? $name$(<$($gen),+>)? {
+            /// Returns operator, that applies self, then next.
+            pub fn then<O>(self, next: O) -> Then<Self, O> {
+                Then(self, next)
+            }
+        }
+    )+};
+}
+
+/// Composition of operators: the first one is applied, then the second one.
+/// Created by `then` of any operator of this module.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Then<A, B>(pub A, pub B);
+
+impl<T, U, A, B> Operator<T, U> for Then<A, B>
+where
+    A: Operator<T, U>,
+    B: Operator<T, U>,
+{
+    fn apply(&self, poly: &Polynomial<T, U>) -> Polynomial<T, U> {
+        self.1.apply(&self.0.apply(poly))
+    }
+}
+
+/// Derivative, same as `Polynomial::derivative`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Differentiate;
+
+impl<T, U> Operator<T, U> for Differentiate
+where
+    T: Clone + Zero,
+    T: Add<T, Output = T>,
+{
+    fn apply(&self, poly: &Polynomial<T, U>) -> Polynomial<T, U> {
+        poly.derivative()
+    }
+}
+
+/// Antiderivative with zero constant term: c x^k goes to c / (k + 1) x^(k + 1).
+/// Integers 1, ..., deg + 1 should be invertible in coefs.
+///
+/// Example:
+/// ```
+/// # use polylib::polynom::ops::{Differentiate, Integrate, Operator};
+/// # use polylib::polynom::Polynomial;
+/// let p = Polynomial::<f64>::from_coefs(vec![1.0, 1.0]);
+/// assert_eq!(Integrate.apply(&p), Polynomial::from_coefs(vec![0.0, 1.0, 0.5]));
+/// assert_eq!(Integrate.then(Differentiate).apply(&p), p);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Integrate;
+
+impl<T, U> Operator<T, U> for Integrate
+where
+    T: Clone + Zero + One,
+    T: Add<T, Output = T>,
+    T: Div<T, Output = T>,
+{
+    fn apply(&self, poly: &Polynomial<T, U>) -> Polynomial<T, U> {
+        let mut ans = Polynomial::new();
+        ans.auto_reduce = poly.auto_reduce;
+        for (coef, pow) in poly.members.iter() {
+            let power = pow.power + 1;
+            ans.push(coef.clone() / times(T::one(), power), Powered::new(power));
+        }
+        ans
+    }
+}
+
+/// Shift of variable by a: p(x) goes to p(x + a). Result is reduced.
+///
+/// Example:
+/// ```
+/// # use polylib::polynom::ops::{Operator, Shift};
+/// # use polylib::polynom::Polynomial;
+/// let p = Polynomial::<i32>::from_coefs(vec![0, 0, 1]); // x^2
+/// assert_eq!(Shift(-1).apply(&p), Polynomial::from_coefs(vec![1, -2, 1]));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Shift<T>(pub T);
+
+impl<T, U> Operator<T, U> for Shift<T>
+where
+    T: Clone + Zero,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    fn apply(&self, poly: &Polynomial<T, U>) -> Polynomial<T, U> {
+        // roots of p(x + a) are roots of p decreased by a
+        poly.poly_with_roots_shifted(T::zero() - self.0.clone())
+    }
+}
+
+/// Scale of variable by c: p(x) goes to p(c x), so coef of x^k is multiplied by c^k.
+/// Members with zero coef after multiplication are dropped.
+///
+/// Example:
+/// ```
+/// # use polylib::polynom::ops::{Operator, Scale};
+/// # use polylib::polynom::Polynomial;
+/// let p = Polynomial::<i32>::from_coefs(vec![1, 1, 1]);
+/// assert_eq!(Scale(2).apply(&p), Polynomial::from_coefs(vec![1, 2, 4]));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Scale<T>(pub T);
+
+impl<T, U> Operator<T, U> for Scale<T>
+where
+    T: Clone + Zero + One,
+    T: Add<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    fn apply(&self, poly: &Polynomial<T, U>) -> Polynomial<T, U> {
+        // same as `poly_with_roots_scaled`, but coef of x^k is multiplied by c^k
+        poly.scale_members(self.0.clone(), |power| power)
+    }
+}
+
+/// Drops terms of degree greater than n, as `ChebyshevSeries::truncate`.
+///
+/// Example:
+/// ```
+/// # use polylib::polynom::ops::{Operator, Truncate};
+/// # use polylib::polynom::Polynomial;
+/// let p = Polynomial::<i32>::from_coefs(vec![1, 2, 3, 4]);
+/// assert_eq!(Truncate(1).apply(&p), Polynomial::from_coefs(vec![1, 2]));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Truncate(pub u32);
+
+impl<T: Clone, U> Operator<T, U> for Truncate {
+    fn apply(&self, poly: &Polynomial<T, U>) -> Polynomial<T, U> {
+        let mut ans = poly.clone();
+        ans.retain_terms(|power, _| power <= self.0);
+        ans
+    }
+}
+
+impl_then!(Then<A, B>, Differentiate, Integrate, Shift<T>, Scale<T>, Truncate);
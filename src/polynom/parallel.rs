@@ -0,0 +1,153 @@
+//! Parallel versions of heavy polynomial operations.
+//!
+//! Members are split into chunks, each chunk is processed
+//! by its own `std::thread::scope` worker, and partial results
+//! are combined by usual addition. Many points are split
+//! between workers in the same way.
+
+use std::{num::NonZeroUsize, ops::Add, ops::Mul, panic, thread};
+
+use super::Polynomial;
+use crate::{SubstitutablePoint, Zero};
+
+// count of workers for `len` members
+fn workers(len: usize) -> usize {
+    let threads = thread::available_parallelism().map_or(1, NonZeroUsize::get);
+    threads.min(len).max(1)
+}
+
+// waits for worker, passing its panic further
+fn join<R>(handle: thread::ScopedJoinHandle<'_, R>) -> R {
+    handle.join().unwrap_or_else(|err| panic::resume_unwind(err))
+}
+
+impl<T, U> Polynomial<T, U> {
+    /// Same as `substitude`, but members are evaluated on several threads.
+    ///
+    /// It is worth for large sparse polynomials with expensive points (like matrices).
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::polynom::X;
+    /// # use polylib::custom_types::Matrix;
+    /// # let x = X::<i32>::default();
+    /// let p = (x ^ 100) + (x ^ 10) + 1;
+    /// let m = Matrix::<2, 2, i32>::eye(1);
+    /// assert_eq!(p.par_substitude(m.clone()), p.substitude(m));
+    /// ```
+    pub fn par_substitude<X, Y>(&self, point: X) -> Y
+    where
+        T: Clone + Sync,
+        X: SubstitutablePoint<T, Y> + Send + Sync,
+        Y: Send,
+    {
+        let chunk = self.members.len().div_ceil(workers(self.members.len())).max(1);
+        thread::scope(|s| {
+            let handles: Vec<_> = self
+                .members
+                .chunks(chunk)
+                .map(|members| {
+                    let point = &point;
+                    s.spawn(move || {
+                        let mut ans = X::zero_value();
+                        for (coef, var) in members {
+                            let rhs = var.substitude(point.clone());
+                            ans = X::accumulate(ans, X::term(coef.clone(), rhs));
+                        }
+                        ans
+                    })
+                })
+                .collect();
+            handles.into_iter().fold(X::zero_value(), |ans, h| {
+                X::accumulate(ans, join(h))
+            })
+        })
+    }
+
+    /// Same as `*`, but members of `self` are split between several threads.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::polynom::Polynomial;
+    /// let a = Polynomial::<i32>::from_coefs(vec![1, 1]);
+    /// let b = Polynomial::<i32>::from_coefs(vec![-1, 1]);
+    /// let c = a.par_mul(&b).reduce(); // x^2 - 1
+    /// assert_eq!(c.len(), 2);
+    /// assert_eq!(c.substitude(3), 8);
+    /// ```
+    pub fn par_mul(&self, rhs: &Polynomial<T, U>) -> Polynomial<T, U>
+    where
+        T: Clone + Send + Sync,
+        T: Mul<T, Output = T>,
+    {
+        let chunk = self.members.len().div_ceil(workers(self.members.len())).max(1);
+        thread::scope(|s| {
+            let handles: Vec<_> = self
+                .members
+                .chunks(chunk)
+                .map(|members| {
+                    s.spawn(move || {
+                        let mut ans = Polynomial::<T, U>::new();
+                        ans.reserve(members.len() * rhs.members.len());
+                        for memb1 in members {
+                            for memb2 in &rhs.members {
+                                ans.push(
+                                    memb1.0.clone() * memb2.0.clone(),
+                                    memb1.1.clone() + memb2.1.clone(),
+                                );
+                            }
+                        }
+                        ans
+                    })
+                })
+                .collect();
+            handles
+                .into_iter()
+                .map(join)
+                .sum()
+        })
+    }
+
+    /// Evaluates polynomial at each of points, points are split between several threads.
+    ///
+    /// Polynomial is reduced once before evaluation, so every point is powered
+    /// only for distinct powers. It is worth for many expensive points,
+    /// like random matrices of Monte-Carlo method.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::polynom::X;
+    /// # use polylib::custom_types::Matrix;
+    /// # let x = X::<i32>::default();
+    /// type M = Matrix<2, 2, i32>;
+    /// let p = (x ^ 2) + (x ^ 2) - 1; // not reduced
+    /// let points: Vec<M> = (0..4).map(M::eye).collect();
+    /// let values: Vec<M> = p.par_eval_at_points(&points);
+    /// assert_eq!(values[3], M::eye(17));
+    /// ```
+    pub fn par_eval_at_points<X, Y>(&self, points: &[X]) -> Vec<Y>
+    where
+        T: Clone + Zero + Send + Sync,
+        T: Add<T, Output = T>,
+        X: SubstitutablePoint<T, Y> + Sync,
+        Y: Send,
+    {
+        let reduced = self.clone().reduce();
+        let chunk = points.len().div_ceil(workers(points.len())).max(1);
+        thread::scope(|s| {
+            let handles: Vec<_> = points
+                .chunks(chunk)
+                .map(|points| {
+                    let reduced = &reduced;
+                    s.spawn(move || {
+                        points
+                            .iter()
+                            .map(|point| reduced.substitude(point.clone()))
+                            .collect::<Vec<Y>>()
+                    })
+                })
+                .collect();
+            handles.into_iter().flat_map(join).collect()
+        })
+    }
+}
@@ -0,0 +1,105 @@
+//! Defines `PreparedPoint` - point of substitution, that caches its powers.
+
+use std::collections::BTreeMap;
+use std::ops::Mul;
+
+use super::Polynomial;
+use crate::{One, SubstitutablePoint};
+
+/// Point, that remembers its powers, so many polynomials
+/// can be evaluated at it without powering point again.
+///
+/// Power, that is not cached yet, is the nearest lower cached power
+/// multiplied by cached squares point^(2^k).
+///
+/// Example:
+/// ```
+/// # use polylib::polynom::{Polynomial, PreparedPoint};
+/// # use polylib::custom_types::Matrix;
+/// type M = Matrix<2, 2, i64>;
+/// let mut point = PreparedPoint::new(M::from_data(vec![1, 1, 1, 0]));
+/// let p = Polynomial::<i64>::from_coefs(vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1]); // x^10
+/// let q = Polynomial::<i64>::from_coefs(vec![1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1]); // x^11 + 1
+/// let a: M = p.substitude_prepared(&mut point);
+/// let b: M = q.substitude_prepared(&mut point); // x^11 = x^10 * x
+/// assert_eq!(a[(0, 1)], 55); // Fibonacci numbers
+/// assert_eq!(b[(0, 1)], 89);
+/// assert_eq!(point.cached(), 2);
+/// ```
+#[derive(Clone, Debug)]
+pub struct PreparedPoint<X> {
+    point: X,
+    // squares[k] is point^(2^k)
+    squares: Vec<X>,
+    powers: BTreeMap<u32, X>,
+}
+
+impl<X> PreparedPoint<X>
+where
+    X: Clone + One + Mul<X, Output = X>,
+{
+    /// Creates point without cached powers.
+    pub fn new(point: X) -> PreparedPoint<X> {
+        PreparedPoint {
+            squares: vec![point.clone()],
+            point,
+            powers: BTreeMap::new(),
+        }
+    }
+
+    /// Returns point itself.
+    pub fn point(&self) -> &X {
+        &self.point
+    }
+
+    /// Returns count of cached powers.
+    pub fn cached(&self) -> usize {
+        self.powers.len()
+    }
+
+    /// Returns point^power and caches it.
+    pub fn power(&mut self, power: u32) -> X {
+        if power == 0 {
+            return X::one();
+        }
+        if let Some(value) = self.powers.get(&power) {
+            return value.clone();
+        }
+        let (mut ans, lower) = match self.powers.range(..power).next_back() {
+            Some((&lower, value)) => (value.clone(), lower),
+            None => (X::one(), 0),
+        };
+        let mut rest = power - lower;
+        let mut k = 0;
+        while rest > 0 {
+            if k == self.squares.len() {
+                let last = self.squares[k - 1].clone();
+                self.squares.push(last.clone() * last);
+            }
+            if rest & 1 == 1 {
+                ans = ans * self.squares[k].clone();
+            }
+            rest >>= 1;
+            k += 1;
+        }
+        self.powers.insert(power, ans.clone());
+        ans
+    }
+}
+
+impl<T, U> Polynomial<T, U> {
+    /// Same as `substitude`, but powers of point are taken from cache of point,
+    /// and new ones are added there.
+    pub fn substitude_prepared<X, Y>(&self, point: &mut PreparedPoint<X>) -> Y
+    where
+        T: Clone,
+        X: SubstitutablePoint<T, Y>,
+    {
+        let mut ans = X::zero_value();
+        for (coef, var) in self.members.iter() {
+            let power = point.power(var.power);
+            ans = X::accumulate(ans, X::term(coef.clone(), power));
+        }
+        ans
+    }
+}
@@ -0,0 +1,102 @@
+//! Defines `EvalRange` - values of polynomial at consecutive points.
+
+use std::ops::{Add, Mul, RangeInclusive, Sub};
+
+use super::Polynomial;
+use crate::{One, Zero};
+
+/// Iterator over values of polynomial at a, a + 1, ..., b,
+/// created by `Polynomial::eval_range`.
+///
+/// Keeps finite differences of all orders at current point:
+/// the n-th difference of polynomial of degree n is constant,
+/// so next value takes only n additions.
+#[derive(Clone, Debug)]
+pub struct EvalRange<T> {
+    // diffs[k] is k-th finite difference at point
+    diffs: Vec<T>,
+    point: T,
+    end: T,
+    exhausted: bool,
+}
+
+impl<T> Iterator for EvalRange<T>
+where
+    T: Clone + Zero + One + PartialOrd,
+    T: Add<T, Output = T>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.exhausted {
+            return None;
+        }
+        let value = self.diffs.first().cloned().unwrap_or_else(T::zero);
+        // point + 1 is not taken at the end, so T::MAX may end the range
+        let next_point = if self.point < self.end {
+            Some(self.point.clone() + T::one()).filter(|next| *next <= self.end)
+        } else {
+            None
+        };
+        if let Some(next_point) = next_point {
+            self.point = next_point;
+            for k in 0..self.diffs.len().saturating_sub(1) {
+                let next = self.diffs[k + 1].clone();
+                self.diffs[k] = self.diffs[k].clone() + next;
+            }
+        } else {
+            self.exhausted = true;
+        }
+        Some(value)
+    }
+}
+
+impl<T, U> Polynomial<T, U>
+where
+    T: Clone + Zero + One + PartialOrd,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    /// Returns iterator over values p(a), p(a + 1), ..., p(b).
+    ///
+    /// Polynomial of degree n is evaluated at a, ..., a + n only,
+    /// other values are found by finite differences in n additions each,
+    /// so it suits tables of values. Note, that points up to a + n
+    /// are evaluated even if range is shorter.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::polynom::Polynomial;
+    /// let p = Polynomial::<i64>::from_coefs(vec![1, 0, 1]); // x^2 + 1
+    /// let values: Vec<i64> = p.eval_range(-2..=3).collect();
+    /// assert_eq!(values, vec![5, 2, 1, 2, 5, 10]);
+    /// ```
+    pub fn eval_range(&self, range: RangeInclusive<T>) -> EvalRange<T> {
+        let (start, end) = range.into_inner();
+        let exhausted = start.partial_cmp(&end).is_none_or(|ord| ord.is_gt());
+        let reduced = self.clone().reduce();
+        // degree + 1, zero polynomial has no differences
+        let count = reduced.members.last().map_or(0, |(_, pow)| pow.power as usize + 1);
+        // values at start, start + 1, ..., then differences in place
+        let mut diffs: Vec<T> = Vec::with_capacity(count);
+        let mut point = start.clone();
+        for i in 0..count {
+            if i > 0 {
+                point = point + T::one();
+            }
+            diffs.push(reduced.substitude(point.clone()));
+        }
+        for k in 1..count {
+            for j in (k..count).rev() {
+                diffs[j] = diffs[j].clone() - diffs[j - 1].clone();
+            }
+        }
+        EvalRange {
+            diffs,
+            point: start,
+            end,
+            exhausted,
+        }
+    }
+}
@@ -0,0 +1,258 @@
+//! Functions of polynomial roots, that are computed from coefs
+//! without finding roots themselves.
+
+use std::ops::{Add, Div, Mul, Sub};
+
+use super::{times, Polynomial, Powered};
+use crate::{One, Zero};
+
+impl<T, U> Polynomial<T, U>
+where
+    T: Clone + Zero + One,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Mul<T, Output = T>,
+    T: Div<T, Output = T>,
+{
+    // coefs a0, a1, ..., an of reduced polynomial, panics for zero polynomial
+    fn root_coefs(&self, caller: &str) -> Vec<T> {
+        let reduced = self.clone().reduce();
+        let degree = match reduced.members.last() {
+            Some((_, pow)) => pow.power,
+            None => panic!("{} of zero polynomial: every point is its root", caller),
+        };
+        (0..=degree).map(|i| reduced.coef(i)).collect()
+    }
+
+    /// Returns sums of powers of roots p0, p1, ..., pk, where pi = r1^i + ... + rn^i
+    /// for roots r1, ..., rn (with multiplicity, including complex ones),
+    /// so p0 is degree of polynomial.
+    ///
+    /// Power sums are found by Newton's identities
+    /// `an * pi + a(n-1) * p(i-1) + ... + i * a(n-i) = 0`,
+    /// so coefs should form a field, or polynomial should be monic
+    /// (then integer polynomial has integer power sums).
+    /// Panics for zero polynomial.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::polynom::Polynomial;
+    /// // (x - 1)(x - 2)(x - 3)
+    /// let p = Polynomial::<i64>::from_coefs(vec![-6, 11, -6, 1]);
+    /// assert_eq!(p.power_sums(4), vec![3, 6, 14, 36, 98]);
+    /// ```
+    pub fn power_sums(&self, k: usize) -> Vec<T> {
+        let coefs = self.root_coefs("power_sums");
+        let n = coefs.len() - 1;
+        let lead = coefs[n].clone();
+        let mut sums = Vec::with_capacity(k + 1);
+        sums.push(times(T::one(), n as u32));
+        for i in 1..=k {
+            let mut sum = if i <= n {
+                times(coefs[n - i].clone(), i as u32)
+            } else {
+                T::zero()
+            };
+            for j in 1..i.min(n + 1) {
+                sum = sum + coefs[n - j].clone() * sums[i - j].clone();
+            }
+            sums.push((T::zero() - sum) / lead.clone());
+        }
+        sums
+    }
+
+    /// Returns k-th elementary symmetric polynomial of roots: sum of all
+    /// products of k different roots (with multiplicity), that is
+    /// `(-1)^k * a(n-k) / an` by Vieta's formulas.
+    /// e0 is one, and ek is zero for k greater than degree.
+    ///
+    /// Coefs should form a field, or polynomial should be monic.
+    /// Panics for zero polynomial.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::polynom::Polynomial;
+    /// // (x - 1)(x - 2)(x - 3)
+    /// let p = Polynomial::<i64>::from_coefs(vec![-6, 11, -6, 1]);
+    /// let e: Vec<i64> = (0..5).map(|k| p.elementary_symmetric(k)).collect();
+    /// assert_eq!(e, vec![1, 6, 11, 6, 0]); // 1 + 2 + 3, 1*2 + 1*3 + 2*3, 1*2*3
+    /// ```
+    pub fn elementary_symmetric(&self, k: usize) -> T {
+        let coefs = self.root_coefs("elementary_symmetric");
+        let n = coefs.len() - 1;
+        if k > n {
+            return T::zero();
+        }
+        let e = coefs[n - k].clone() / coefs[n].clone();
+        if k % 2 == 1 {
+            T::zero() - e
+        } else {
+            e
+        }
+    }
+}
+
+impl<T, U> Polynomial<T, U>
+where
+    T: Clone + Zero,
+    T: Add<T, Output = T>,
+{
+    /// Returns polynomial, whose roots are roots of self multiplied by c:
+    /// `c^n * p(x / c)`, that is coef of x^i multiplied by c^(n-i).
+    /// Leading coef is kept.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::polynom::Polynomial;
+    /// // (x - 1)(x + 2) -> (x - 3)(x + 6)
+    /// let p = Polynomial::<i32>::from_coefs(vec![-2, 1, 1]);
+    /// assert_eq!(p.poly_with_roots_scaled(3), Polynomial::from_coefs(vec![-18, 3, 1]));
+    /// ```
+    pub fn poly_with_roots_scaled(&self, c: T) -> Polynomial<T, U>
+    where
+        T: One,
+        T: Mul<T, Output = T>,
+    {
+        let reduced = self.clone().reduce();
+        let degree = reduced.members.last().map_or(0, |(_, pow)| pow.power);
+        let mut ans = reduced.scale_members(c, |power| degree - power);
+        ans.auto_reduce = self.auto_reduce;
+        ans
+    }
+
+    // multiplies coef of x^i by c^exponent(i), members with zero product are dropped
+    pub(super) fn scale_members(&self, c: T, exponent: impl Fn(u32) -> u32) -> Polynomial<T, U>
+    where
+        T: One,
+        T: Mul<T, Output = T>,
+    {
+        let mut ans = Polynomial::new();
+        ans.auto_reduce = self.auto_reduce;
+        for (coef, pow) in self.members.iter() {
+            let factor = Powered::<U>::new(exponent(pow.power)).substitude(c.clone());
+            let coef = coef.clone() * factor;
+            if !coef.is_zero() {
+                ans.push(coef, pow.clone());
+            }
+        }
+        ans
+    }
+
+    /// Returns polynomial, whose roots are roots of self increased by a:
+    /// `p(x - a)`, computed by Horner's method in O(n^2).
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::polynom::Polynomial;
+    /// // (x - 1)(x + 2) -> (x - 3)x
+    /// let p = Polynomial::<i32>::from_coefs(vec![-2, 1, 1]);
+    /// assert_eq!(p.poly_with_roots_shifted(2), Polynomial::from_coefs(vec![0, -3, 1]));
+    /// ```
+    pub fn poly_with_roots_shifted(&self, a: T) -> Polynomial<T, U>
+    where
+        T: Sub<T, Output = T>,
+        T: Mul<T, Output = T>,
+    {
+        let reduced = self.clone().reduce();
+        let degree = match reduced.members.last() {
+            Some((_, pow)) => pow.power as usize,
+            None => return reduced,
+        };
+        // coefs of x^0, x^1, ... of Horner's accumulator
+        let mut coefs: Vec<T> = Vec::with_capacity(degree + 1);
+        for i in (0..=degree).rev() {
+            // acc = acc * (x - a) + coef of x^i
+            coefs.insert(0, T::zero());
+            for j in 0..coefs.len() - 1 {
+                let next = coefs[j + 1].clone();
+                coefs[j] = coefs[j].clone() - next * a.clone();
+            }
+            coefs[0] = coefs[0].clone() + reduced.coef(i as u32);
+        }
+        let mut ans = Polynomial::from_coefs(coefs);
+        ans.auto_reduce = self.auto_reduce;
+        ans
+    }
+
+    /// Returns polynomial, whose roots are reciprocals of nonzero roots of self:
+    /// `x^n * p(1 / x)`, that is reversed coefs.
+    /// Zero roots have no reciprocal, so they are dropped and degree decreases.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::polynom::Polynomial;
+    /// // (2x - 1)(x - 3)x -> (x - 2)(3x - 1)
+    /// let p = Polynomial::<i32>::from_coefs(vec![0, 3, -7, 2]);
+    /// assert_eq!(p.poly_with_reciprocal_roots(), Polynomial::from_coefs(vec![2, -7, 3]));
+    /// ```
+    pub fn poly_with_reciprocal_roots(&self) -> Polynomial<T, U> {
+        let reduced = self.clone().reduce();
+        let high = match reduced.members.last() {
+            Some((_, pow)) => pow.power,
+            None => return reduced,
+        };
+        let mut ans = Polynomial::new();
+        ans.auto_reduce = self.auto_reduce;
+        ans.members.reserve(reduced.members.len());
+        for (coef, pow) in reduced.members.iter().rev() {
+            ans.push(coef.clone(), Powered::new(high - pow.power));
+        }
+        ans
+    }
+}
+
+impl<T, U> Polynomial<T, U>
+where
+    T: Clone + Zero,
+    T: Add<T, Output = T>,
+    T: Sub<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    /// Returns polynomial, whose roots are roots of self to the power of 2^k
+    /// (Graeffe's root squaring), and leading coef is leading coef of self to the same power.
+    ///
+    /// Each step splits p(x) = e(x^2) + x * o(x^2) and returns
+    /// `(-1)^n * p(x) * p(-x) = (-1)^n * (e(y)^2 - y * o(y)^2)`, where y = x^2.
+    /// Roots of different absolute values become far apart, so root magnitudes
+    /// can be estimated from coefs. Note, that coefs grow very fast.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::polynom::Polynomial;
+    /// // (x - 1)(x + 2) -> (x - 1)(x - 4) -> (x - 1)(x - 16)
+    /// let p = Polynomial::<i64>::from_coefs(vec![-2, 1, 1]);
+    /// assert_eq!(p.graeffe_iterate(1), Polynomial::from_coefs(vec![4, -5, 1]));
+    /// assert_eq!(p.graeffe_iterate(2), Polynomial::from_coefs(vec![16, -17, 1]));
+    /// ```
+    pub fn graeffe_iterate(&self, k: u32) -> Polynomial<T, U> {
+        let mut ans = self.clone().reduce();
+        for _ in 0..k {
+            let degree = match ans.members.last() {
+                Some((_, pow)) => pow.power,
+                None => break,
+            };
+            let mut even: Polynomial<T, U> = Polynomial::new();
+            let mut odd: Polynomial<T, U> = Polynomial::new();
+            for (coef, pow) in ans.members.iter() {
+                if pow.power % 2 == 0 {
+                    even.push(coef.clone(), Powered::new(pow.power / 2));
+                } else {
+                    odd.push(coef.clone(), Powered::new(pow.power / 2));
+                }
+            }
+            let mut next = even.clone() * even;
+            for (coef, pow) in (odd.clone() * odd).members {
+                next.push(T::zero() - coef, Powered::new(pow.power + 1));
+            }
+            next.reduce_in_place();
+            if degree % 2 == 1 {
+                for (coef, _) in next.members.iter_mut() {
+                    *coef = T::zero() - coef.clone();
+                }
+            }
+            next.auto_reduce = self.auto_reduce;
+            ans = next;
+        }
+        ans
+    }
+}
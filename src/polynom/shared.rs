@@ -0,0 +1,120 @@
+//! Defines `SharedPolynomial` - cheaply clonable handle to polynomial.
+
+use std::{ops::Deref, sync::Arc};
+
+use super::{Polynomial, X};
+use crate::{Evaluate, SubstitutablePoint};
+
+/// Handle to polynomial, that is shared between its clones.
+///
+/// Cloning handle does not copy members, so it is cheap to store
+/// the same huge polynomial in many places or send it to many threads.
+/// Polynomial is copied only on mutation (`make_mut`), if it is still shared.
+///
+/// All `&self` methods of `Polynomial` can be called directly on handle.
+///
+/// Example:
+/// ```
+/// # use polylib::polynom::X;
+/// # let x = X::<i32>::default();
+/// let shared = (x.pow(2) + 1).share(); // x^2 + 1
+/// let handles: Vec<_> = (0..4)
+///     .map(|i| {
+///         let poly = shared.clone();
+///         std::thread::spawn(move || poly.substitude::<i32, i32>(i))
+///     })
+///     .collect();
+/// let values: Vec<i32> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+/// assert_eq!(values, vec![1, 2, 5, 10]);
+/// ```
+pub struct SharedPolynomial<T, U = X<T>>(Arc<Polynomial<T, U>>);
+
+impl<T, U> SharedPolynomial<T, U> {
+    /// Returns mutable reference to polynomial.
+    /// If polynomial is shared with other handles, it is copied first.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::polynom::Polynomial;
+    /// let a = Polynomial::<i32>::from_coefs(vec![1, 2]).share();
+    /// let mut b = a.clone();
+    /// b.make_mut().reserve(10);
+    /// assert!(!a.ptr_eq(&b));
+    /// ```
+    pub fn make_mut(&mut self) -> &mut Polynomial<T, U>
+    where
+        T: Clone,
+    {
+        Arc::make_mut(&mut self.0)
+    }
+
+    /// Returns polynomial back. It is copied only if it is still shared.
+    pub fn unshare(self) -> Polynomial<T, U>
+    where
+        T: Clone,
+    {
+        Arc::try_unwrap(self.0).unwrap_or_else(|shared| (*shared).clone())
+    }
+
+    /// Checks if both handles point to the same polynomial.
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+
+    /// Returns count of handles, that share this polynomial.
+    pub fn share_count(&self) -> usize {
+        Arc::strong_count(&self.0)
+    }
+}
+
+impl<T, U> Polynomial<T, U> {
+    /// Moves polynomial into cheaply clonable `SharedPolynomial`.
+    pub fn share(self) -> SharedPolynomial<T, U> {
+        SharedPolynomial(Arc::new(self))
+    }
+}
+
+impl<T, U> From<Polynomial<T, U>> for SharedPolynomial<T, U> {
+    fn from(value: Polynomial<T, U>) -> Self {
+        value.share()
+    }
+}
+
+impl<T, U> Deref for SharedPolynomial<T, U> {
+    type Target = Polynomial<T, U>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T, U, X, Y> Evaluate<X, Y> for SharedPolynomial<T, U>
+where
+    T: Clone,
+    X: SubstitutablePoint<T, Y>,
+{
+    fn evaluate(&self, point: X) -> Y {
+        self.substitude(point)
+    }
+}
+
+impl<T, U> Clone for SharedPolynomial<T, U> {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl<T: std::fmt::Debug, U> std::fmt::Debug for SharedPolynomial<T, U> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("SharedPolynomial").field(&*self.0).finish()
+    }
+}
+
+impl<T, U> std::fmt::Display for SharedPolynomial<T, U>
+where
+    Polynomial<T, U>: std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(&*self.0, f)
+    }
+}
@@ -0,0 +1,367 @@
+//! Defines `SparsePolynomial` - polynomial stored as map from power to coef.
+
+use std::{
+    collections::BTreeMap,
+    fmt::{Debug, Display},
+    marker::PhantomData,
+    ops::{Add, Mul, Neg, Sub},
+};
+
+use super::{write_member, Polynomial, Powered, X};
+use crate::{Evaluate, One, PolyError, SubstitutablePoint, Zero};
+
+/// Sparse polynomial with u64 powers: map from power to nonzero coef.
+///
+/// Unlike `Polynomial`, it is always reduced, and `get`, `add_term`
+/// take O(log n) for n members, so it suits few members with huge powers.
+///
+/// `T` and `U` have same meaning as in `Polynomial`.
+///
+/// Example:
+/// ```
+/// # use polylib::polynom::SparsePolynomial;
+/// let mut p = SparsePolynomial::<i64>::new();
+/// p.add_term(10_000_000_000, 3);
+/// p.add_term(1, 2);
+/// p.add_term(10_000_000_000, -3);
+/// assert_eq!(p.len(), 1);
+/// assert_eq!(p.degree(), Some(1));
+/// assert_eq!(p.get(1), Some(&2));
+/// ```
+pub struct SparsePolynomial<T, U = X<T>> {
+    // no zero coefs
+    terms: BTreeMap<u64, T>,
+    var: PhantomData<U>,
+}
+
+// point^power by binary exponentiation
+fn pow<A>(point: A, mut power: u64) -> A
+where
+    A: Clone + One + Mul<A, Output = A>,
+{
+    let mut ans = A::one();
+    let mut to_mul = point;
+    while power > 0 {
+        if power & 1 == 1 {
+            ans = ans * to_mul.clone();
+        }
+        power >>= 1;
+        // last square is not needed and may overflow
+        if power > 0 {
+            to_mul = to_mul.clone() * to_mul;
+        }
+    }
+    ans
+}
+
+impl<T, U> SparsePolynomial<T, U> {
+    /// Returns zero polynomial.
+    pub fn new() -> SparsePolynomial<T, U> {
+        SparsePolynomial {
+            terms: BTreeMap::new(),
+            var: PhantomData,
+        }
+    }
+
+    /// Returns polynomial with members (power, coef).
+    /// Members with the same power are summed up.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::polynom::SparsePolynomial;
+    /// let p = SparsePolynomial::<i32>::from_terms(vec![(1 << 40, 1), (0, 5), (1 << 40, 1)]);
+    /// assert_eq!(p.get(1 << 40), Some(&2));
+    /// ```
+    pub fn from_terms<I>(terms: I) -> SparsePolynomial<T, U>
+    where
+        I: IntoIterator<Item = (u64, T)>,
+        T: Zero,
+        T: Add<T, Output = T>,
+    {
+        let mut ans = Self::new();
+        for (power, coef) in terms {
+            ans.add_term(power, coef);
+        }
+        ans
+    }
+
+    /// Adds coef * x^power in O(log n).
+    pub fn add_term(&mut self, power: u64, coef: T)
+    where
+        T: Zero,
+        T: Add<T, Output = T>,
+    {
+        let sum = match self.terms.remove(&power) {
+            Some(old) => old + coef,
+            None => coef,
+        };
+        if !sum.is_zero() {
+            self.terms.insert(power, sum);
+        }
+    }
+
+    /// Returns coef of x^power in O(log n), or None if it is zero.
+    pub fn get(&self, power: u64) -> Option<&T> {
+        self.terms.get(&power)
+    }
+
+    /// Returns degree of polynomial, or None for zero polynomial.
+    pub fn degree(&self) -> Option<u64> {
+        self.terms.last_key_value().map(|(&power, _)| power)
+    }
+
+    /// Returns count of nonzero members.
+    pub fn len(&self) -> usize {
+        self.terms.len()
+    }
+
+    /// Returns true for zero polynomial.
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    /// Returns members (power, coef) in ascending order of powers.
+    pub fn terms(&self) -> impl Iterator<Item = (u64, &T)> {
+        self.terms.iter().map(|(&power, coef)| (power, coef))
+    }
+
+    /// Calculate value of polynom at point.
+    ///
+    /// Powers are visited in ascending order, so each power of point
+    /// is previous one multiplied by point^difference.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::polynom::SparsePolynomial;
+    /// # use polylib::custom_types::Zn;
+    /// // x^(10^18) + 1 at 2 modulo 1_000_003 (prime, so 2^(10^18) = 2^(10^18 mod 1_000_002))
+    /// let p = SparsePolynomial::<Zn<1_000_003>>::from_terms(vec![
+    ///     (1_000_000_000_000_000_000, Zn::new(1)),
+    ///     (0, Zn::new(1)),
+    /// ]);
+    /// let exp = 1_000_000_000_000_000_000u64 % 1_000_002;
+    /// assert_eq!(p.substitude::<_, Zn<1_000_003>>(Zn::new(2)), Zn::new(2).pow(exp) + Zn::new(1));
+    /// ```
+    pub fn substitude<A, B>(&self, point: A) -> B
+    where
+        T: Clone,
+        A: SubstitutablePoint<T, B>,
+    {
+        let mut ans = A::zero_value();
+        let mut power = A::one();
+        let mut last = 0;
+        for (&exp, coef) in &self.terms {
+            power = power * pow(point.clone(), exp - last);
+            last = exp;
+            ans = A::accumulate(ans, A::term(coef.clone(), power.clone()));
+        }
+        ans
+    }
+
+    /// Converts to `Polynomial`.
+    ///
+    /// Returns `PolyError::ExponentOverflow`, if some power doesn't fit into u32.
+    pub fn to_polynomial(&self) -> Result<Polynomial<T, U>, PolyError>
+    where
+        T: Clone,
+    {
+        let mut ans = Polynomial::new();
+        for (&power, coef) in &self.terms {
+            let power = u32::try_from(power).map_err(|_| PolyError::ExponentOverflow(power))?;
+            ans.push(coef.clone(), Powered::new(power));
+        }
+        Ok(ans)
+    }
+}
+
+impl<T, U, X, Y> Evaluate<X, Y> for SparsePolynomial<T, U>
+where
+    T: Clone,
+    X: SubstitutablePoint<T, Y>,
+{
+    fn evaluate(&self, point: X) -> Y {
+        self.substitude(point)
+    }
+}
+
+/// Members with the same power are summed up.
+impl<T, U> From<Polynomial<T, U>> for SparsePolynomial<T, U>
+where
+    T: Zero,
+    T: Add<T, Output = T>,
+{
+    fn from(value: Polynomial<T, U>) -> Self {
+        Self::from_terms(
+            value
+                .members
+                .into_iter()
+                .map(|(coef, pow)| (pow.power as u64, coef)),
+        )
+    }
+}
+
+impl<T, U> Default for SparsePolynomial<T, U> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Zero, U> Zero for SparsePolynomial<T, U> {
+    fn zero() -> Self {
+        Self::new()
+    }
+
+    fn is_zero(&self) -> bool {
+        self.terms.is_empty()
+    }
+}
+
+impl<T, U> One for SparsePolynomial<T, U>
+where
+    T: One + Zero,
+    T: Add<T, Output = T>,
+{
+    fn one() -> Self {
+        Self::from_terms([(0, T::one())])
+    }
+
+    fn is_one(&self) -> bool {
+        self.terms.len() == 1 && self.get(0).is_some_and(T::is_one)
+    }
+}
+
+impl<T, U> Add for SparsePolynomial<T, U>
+where
+    T: Zero,
+    T: Add<T, Output = T>,
+{
+    type Output = SparsePolynomial<T, U>;
+
+    fn add(mut self, rhs: Self) -> Self::Output {
+        for (power, coef) in rhs.terms {
+            self.add_term(power, coef);
+        }
+        self
+    }
+}
+
+impl<T, U> Neg for SparsePolynomial<T, U>
+where
+    T: Neg<Output = T>,
+{
+    type Output = SparsePolynomial<T, U>;
+
+    fn neg(self) -> Self::Output {
+        SparsePolynomial {
+            terms: self.terms.into_iter().map(|(power, coef)| (power, -coef)).collect(),
+            var: PhantomData,
+        }
+    }
+}
+
+impl<T, U> Sub for SparsePolynomial<T, U>
+where
+    T: Zero,
+    T: Add<T, Output = T>,
+    T: Neg<Output = T>,
+{
+    type Output = SparsePolynomial<T, U>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + (-rhs)
+    }
+}
+
+impl<T, U> Mul<T> for SparsePolynomial<T, U>
+where
+    T: Zero + Clone,
+    T: Mul<T, Output = T>,
+{
+    type Output = SparsePolynomial<T, U>;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        SparsePolynomial {
+            terms: self
+                .terms
+                .into_iter()
+                .map(|(power, coef)| (power, coef * rhs.clone()))
+                .filter(|(_, coef)| !coef.is_zero())
+                .collect(),
+            var: PhantomData,
+        }
+    }
+}
+
+/// Panics if power of product doesn't fit into u64.
+impl<T, U> Mul for SparsePolynomial<T, U>
+where
+    T: Zero + Clone,
+    T: Add<T, Output = T>,
+    T: Mul<T, Output = T>,
+{
+    type Output = SparsePolynomial<T, U>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let mut ans = Self::new();
+        for (p1, c1) in &self.terms {
+            for (p2, c2) in &rhs.terms {
+                let power = p1
+                    .checked_add(*p2)
+                    .unwrap_or_else(|| panic!("power of x^{} * x^{} overflows u64", p1, p2));
+                ans.add_term(power, c1.clone() * c2.clone());
+            }
+        }
+        ans
+    }
+}
+
+impl<T: Clone, U> Clone for SparsePolynomial<T, U> {
+    fn clone(&self) -> Self {
+        SparsePolynomial {
+            terms: self.terms.clone(),
+            var: PhantomData,
+        }
+    }
+}
+
+impl<T: PartialEq, U> PartialEq for SparsePolynomial<T, U> {
+    fn eq(&self, other: &Self) -> bool {
+        self.terms == other.terms
+    }
+}
+
+impl<T: Eq, U> Eq for SparsePolynomial<T, U> {}
+
+impl<T: Debug, U> Debug for SparsePolynomial<T, U> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SparsePolynomial")
+            .field("terms", &self.terms)
+            .finish()
+    }
+}
+
+impl<T, U> Display for SparsePolynomial<T, U>
+where
+    T: Display + Zero + One,
+    U: Default + Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut first = true;
+        for (&power, coef) in &self.terms {
+            if !first {
+                write!(f, " + ")?;
+            }
+            first = false;
+            let var = match power {
+                0 => String::new(),
+                1 => format!("{}", U::default()),
+                _ => format!("{}^{}", U::default(), power),
+            };
+            write_member(f, coef, var, power == 0)?;
+        }
+        if first {
+            write_member(f, &T::zero(), "", true)?;
+        }
+        std::fmt::Result::Ok(())
+    }
+}
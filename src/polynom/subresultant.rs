@@ -0,0 +1,166 @@
+//! Bezout matrix and subresultant polynomial remainder sequence
+//! of two polynomials, that work over integral domains without fractions.
+//!
+//! Example:
+//! ```
+//! # use polylib::polynom::{subresultant, Polynomial};
+//! // common root 2
+//! let p = Polynomial::<i64>::from_coefs(vec![2, -3, 1]); // (x - 1)(x - 2)
+//! let q = Polynomial::<i64>::from_coefs(vec![-6, 1, 1]); // (x - 2)(x + 3)
+//! assert_eq!(subresultant::bezout_matrix(&p, &q).determinant(), 0);
+//! let prs = subresultant::subresultants(&p, &q);
+//! assert_eq!(prs.last().unwrap(), &Polynomial::from_coefs(vec![-8, 4])); // 4(x - 2)
+//! ```
+
+use super::{Polynomial, Powered};
+use crate::custom_types::DynMatrix;
+use crate::{CoefField, CoefRing, Zero};
+
+// dense coefs of reduced polynomial, without leading zeros
+fn dense<T: CoefRing, U>(p: &Polynomial<T, U>) -> Vec<T> {
+    let reduced = p.clone().reduce();
+    let len = reduced.members.last().map_or(0, |(_, pow)| pow.power as usize + 1);
+    (0..len).map(|i| reduced.coef(i as u32)).collect()
+}
+
+// drops leading zeros
+fn trim<T: Zero>(coefs: &mut Vec<T>) {
+    while coefs.last().is_some_and(|c| c.is_zero()) {
+        coefs.pop();
+    }
+}
+
+// pseudo-remainder lc(b)^(deg a - deg b + 1) * a mod b, b is not zero and deg a >= deg b
+fn prem<T: CoefRing>(a: &[T], b: &[T]) -> Vec<T> {
+    let lead = b[b.len() - 1].clone();
+    let mut r = a.to_vec();
+    let mut exp = a.len() - b.len() + 1;
+    while r.len() >= b.len() {
+        let coef = r[r.len() - 1].clone();
+        let shift = r.len() - b.len();
+        for c in r.iter_mut() {
+            *c = c.clone() * lead.clone();
+        }
+        for (i, c) in b.iter().enumerate() {
+            r[shift + i] = r[shift + i].clone() - coef.clone() * c.clone();
+        }
+        r.pop();
+        trim(&mut r);
+        exp -= 1;
+    }
+    let scale = Powered::<()>::new(exp as u32).substitude(lead);
+    for c in r.iter_mut() {
+        *c = c.clone() * scale.clone();
+    }
+    r
+}
+
+/// Returns Bezout matrix<n, n> of p and q, where n is maximal degree:
+/// `(p(x) q(y) - p(y) q(x)) / (x - y) = sum of b(i, j) x^i y^j`.
+///
+/// Matrix is symmetric, its determinant is resultant of p and q up to sign
+/// (and up to power of leading coef, if degrees differ), so it is zero,
+/// iff p and q have common root. Elements are found without division.
+///
+/// Example:
+/// ```
+/// # use polylib::polynom::{subresultant, Polynomial};
+/// let p = Polynomial::<i32>::from_coefs(vec![2, -3, 1]); // (x - 1)(x - 2)
+/// let q = Polynomial::<i32>::from_coefs(vec![-3, 1]); // x - 3
+/// let b = subresultant::bezout_matrix(&p, &q);
+/// assert_eq!(b.get_data(), &vec![7, -3, -3, 1]);
+/// assert_eq!(b.determinant(), -2); // q(1) * q(2) = 2
+/// ```
+pub fn bezout_matrix<T: CoefRing, U>(
+    p: &Polynomial<T, U>,
+    q: &Polynomial<T, U>,
+) -> DynMatrix<T> {
+    let a = dense(p);
+    let b = dense(q);
+    let n = a.len().max(b.len()).saturating_sub(1);
+    let coef = |coefs: &Vec<T>, i: usize| coefs.get(i).cloned().unwrap_or_else(T::zero);
+    // coef of x^u y^v in p(x) q(y) - p(y) q(x)
+    let c = |u: usize, v: usize| coef(&a, u) * coef(&b, v) - coef(&a, v) * coef(&b, u);
+    // (x - y) * B(x, y) = C(x, y) gives b(i, j) = b(i - 1, j + 1) - c(i, j + 1)
+    let mut ans = DynMatrix::full(n, n, T::zero());
+    for i in 0..n {
+        for j in 0..n {
+            let prev = if i > 0 && j + 1 < n {
+                ans[(i - 1, j + 1)].clone()
+            } else {
+                T::zero()
+            };
+            ans[(i, j)] = prev - c(i, j + 1);
+        }
+    }
+    ans
+}
+
+/// Returns subresultant polynomial remainder sequence of p and q:
+/// p, q, r2, r3, ..., where the last one is gcd of p and q up to constant factor.
+/// Polynomial of greater degree goes first, zero polynomials are not included.
+///
+/// Remainders are computed by Brown's algorithm: each pseudo-remainder is divided
+/// by known factor exactly, so coefs of integer polynomials grow only polynomially,
+/// and each ri equals subresultant of p and q up to sign. If degree of the last one is 0,
+/// it is resultant up to sign, and p and q have no common roots.
+/// Coefs should form an integral domain with exact division, such as integers.
+///
+/// Example:
+/// ```
+/// # use polylib::polynom::{subresultant, Polynomial};
+/// let p = Polynomial::<i64>::from_coefs(vec![-5, 2, 8, -3, -3, 0, 1, 0, 1]);
+/// let q = Polynomial::<i64>::from_coefs(vec![21, -9, -4, 0, 5, 0, 3]);
+/// let prs = subresultant::subresultants(&p, &q);
+/// assert_eq!(prs.len(), 6);
+/// assert_eq!(prs[2], Polynomial::from_coefs(vec![9, 0, -3, 0, 15]));
+/// assert_eq!(prs[5], Polynomial::from_coefs(vec![260708]));
+/// ```
+pub fn subresultants<T: CoefField, U>(
+    p: &Polynomial<T, U>,
+    q: &Polynomial<T, U>,
+) -> Vec<Polynomial<T, U>> {
+    let mut a = dense(p);
+    let mut b = dense(q);
+    if a.len() < b.len() {
+        std::mem::swap(&mut a, &mut b);
+    }
+    if a.is_empty() {
+        return Vec::new();
+    }
+    let mut seq: Vec<Vec<T>> = Vec::new();
+    seq.push(a);
+    if b.is_empty() {
+        return seq.into_iter().map(Polynomial::from_coefs).collect();
+    }
+    seq.push(b);
+
+    let pow = |value: T, exp: usize| Powered::<()>::new(exp as u32).substitude(value);
+    let minus_one = T::zero() - T::one();
+    // d = deg r(i-1) - deg r(i), beta(1) = (-1)^(d + 1), psi(1) = -1
+    let mut d = seq[0].len() - seq[1].len();
+    let mut beta = pow(minus_one.clone(), d + 1);
+    let mut psi = minus_one;
+    loop {
+        let len = seq.len();
+        let (prev, last) = (&seq[len - 2], &seq[len - 1]);
+        let mut r = prem(prev, last);
+        if r.is_empty() {
+            break;
+        }
+        for c in r.iter_mut() {
+            *c = c.clone() / beta.clone();
+        }
+        // psi(i + 1) = (-lc)^d / psi(i)^(d - 1), beta(i + 1) = -lc * psi(i + 1)^d'
+        let lead = T::zero() - last[last.len() - 1].clone();
+        psi = if d == 0 {
+            psi
+        } else {
+            pow(lead.clone(), d) / pow(psi, d - 1)
+        };
+        d = last.len() - r.len();
+        beta = lead * pow(psi.clone(), d);
+        seq.push(r);
+    }
+    seq.into_iter().map(Polynomial::from_coefs).collect()
+}
@@ -0,0 +1,333 @@
+// Storage for polynomial members with small-buffer optimization:
+// first `INLINE` members are stored inside the structure,
+// so short polynomials are created and cloned without allocation.
+
+use std::{
+    fmt::Debug,
+    mem::{ManuallyDrop, MaybeUninit},
+    ops::{Deref, DerefMut},
+    ptr,
+};
+
+// count of members, that are stored without allocation
+pub(super) const INLINE: usize = 4;
+
+pub(super) enum Terms<E> {
+    // first `len` items of `buf` are initialized
+    Inline {
+        len: usize,
+        buf: [MaybeUninit<E>; INLINE],
+    },
+    Heap(Vec<E>),
+}
+
+impl<E> Terms<E> {
+    pub(super) fn new() -> Terms<E> {
+        Terms::Inline {
+            len: 0,
+            buf: [const { MaybeUninit::uninit() }; INLINE],
+        }
+    }
+
+    pub(super) fn push(&mut self, item: E) {
+        match self {
+            Terms::Inline { len, buf } if *len < INLINE => {
+                buf[*len].write(item);
+                *len += 1;
+            }
+            Terms::Inline { .. } => {
+                let mut v = self.take_vec(INLINE * 2);
+                v.push(item);
+                *self = Terms::Heap(v);
+            }
+            Terms::Heap(v) => v.push(item),
+        }
+    }
+
+    pub(super) fn capacity(&self) -> usize {
+        match self {
+            Terms::Inline { .. } => INLINE,
+            Terms::Heap(v) => v.capacity(),
+        }
+    }
+
+    pub(super) fn reserve(&mut self, additional: usize) {
+        match self {
+            Terms::Inline { len, .. } if *len + additional <= INLINE => {}
+            Terms::Inline { len, .. } => {
+                let capacity = *len + additional;
+                let v = self.take_vec(capacity);
+                *self = Terms::Heap(v);
+            }
+            Terms::Heap(v) => v.reserve(additional),
+        }
+    }
+
+    // drops items after first `new_len`
+    pub(super) fn truncate(&mut self, new_len: usize) {
+        match self {
+            Terms::Inline { len, buf } => {
+                let old_len = *len;
+                if new_len >= old_len {
+                    return;
+                }
+                *len = new_len;
+                for item in &mut buf[new_len..old_len] {
+                    // SAFETY: items before old `len` are initialized,
+                    // and `len` is already decreased, so they are never read again
+                    unsafe { item.assume_init_drop() };
+                }
+            }
+            Terms::Heap(v) => v.truncate(new_len),
+        }
+    }
+
+    // moves heap items back inline, if they fit
+    pub(super) fn shrink_to_fit(&mut self) {
+        if let Terms::Heap(v) = self {
+            if v.len() > INLINE {
+                v.shrink_to_fit();
+                return;
+            }
+            let mut ans = Terms::new();
+            for item in std::mem::take(v) {
+                ans.push(item);
+            }
+            *self = ans;
+        }
+    }
+
+    // moves all items to vector with given capacity, leaving self empty
+    fn take_vec(&mut self, capacity: usize) -> Vec<E> {
+        match self {
+            Terms::Inline { len, buf } => {
+                let mut v = Vec::with_capacity(capacity.max(*len));
+                let count = std::mem::replace(len, 0);
+                for item in buf.iter().take(count) {
+                    // SAFETY: first `count` items are initialized, and `len` is already 0,
+                    // so they are never read again
+                    v.push(unsafe { item.assume_init_read() });
+                }
+                v
+            }
+            Terms::Heap(v) => std::mem::take(v),
+        }
+    }
+}
+
+impl<E> Deref for Terms<E> {
+    type Target = [E];
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            // SAFETY: first `len` items are initialized
+            Terms::Inline { len, buf } => unsafe {
+                std::slice::from_raw_parts(buf.as_ptr() as *const E, *len)
+            },
+            Terms::Heap(v) => v,
+        }
+    }
+}
+
+impl<E> DerefMut for Terms<E> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        match self {
+            // SAFETY: first `len` items are initialized
+            Terms::Inline { len, buf } => unsafe {
+                std::slice::from_raw_parts_mut(buf.as_mut_ptr() as *mut E, *len)
+            },
+            Terms::Heap(v) => v,
+        }
+    }
+}
+
+impl<E> Drop for Terms<E> {
+    fn drop(&mut self) {
+        if let Terms::Inline { len, buf } = self {
+            let count = std::mem::replace(len, 0);
+            for item in buf.iter_mut().take(count) {
+                // SAFETY: first `count` items are initialized
+                unsafe { item.assume_init_drop() };
+            }
+        }
+    }
+}
+
+impl<E: Clone> Clone for Terms<E> {
+    fn clone(&self) -> Self {
+        match self {
+            Terms::Inline { .. } => {
+                let mut ans = Terms::new();
+                for item in self.iter() {
+                    ans.push(item.clone());
+                }
+                ans
+            }
+            Terms::Heap(v) => Terms::Heap(v.clone()),
+        }
+    }
+}
+
+impl<E: Debug> Debug for Terms<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, E> IntoIterator for &'a Terms<E> {
+    type Item = &'a E;
+    type IntoIter = std::slice::Iter<'a, E>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<E> IntoIterator for Terms<E> {
+    type Item = E;
+    type IntoIter = IntoIter<E>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let mut this = ManuallyDrop::new(self);
+        match &mut *this {
+            Terms::Inline { len, buf } => IntoIter::Inline {
+                pos: 0,
+                len: *len,
+                // SAFETY: `this` is never dropped, so items are moved only once
+                buf: unsafe { ptr::read(buf) },
+            },
+            // SAFETY: `this` is never dropped, so vector is moved only once
+            Terms::Heap(v) => IntoIter::Heap(unsafe { ptr::read(v) }.into_iter()),
+        }
+    }
+}
+
+pub(super) enum IntoIter<E> {
+    // items in `pos..len` are initialized and not yet returned
+    Inline {
+        pos: usize,
+        len: usize,
+        buf: [MaybeUninit<E>; INLINE],
+    },
+    Heap(std::vec::IntoIter<E>),
+}
+
+impl<E> Iterator for IntoIter<E> {
+    type Item = E;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            IntoIter::Inline { pos, len, buf } => {
+                if *pos == *len {
+                    return None;
+                }
+                *pos += 1;
+                // SAFETY: item at `pos - 1` is initialized and is not read anymore
+                Some(unsafe { buf[*pos - 1].assume_init_read() })
+            }
+            IntoIter::Heap(iter) => iter.next(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            IntoIter::Inline { pos, len, .. } => (*len - *pos, Some(*len - *pos)),
+            IntoIter::Heap(iter) => iter.size_hint(),
+        }
+    }
+}
+
+impl<E> Drop for IntoIter<E> {
+    fn drop(&mut self) {
+        if let IntoIter::Inline { pos, len, buf } = self {
+            for item in buf.iter_mut().take(*len).skip(*pos) {
+                // SAFETY: items in `pos..len` are initialized and were not returned
+                unsafe { item.assume_init_drop() };
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod terms_tests {
+    use std::rc::Rc;
+
+    use super::{Terms, INLINE};
+
+    #[test]
+    fn test_push() {
+        let mut t = Terms::new();
+        for i in 0..INLINE {
+            t.push(i);
+            assert!(matches!(t, Terms::Inline { .. }));
+        }
+        t.push(INLINE);
+        assert!(matches!(t, Terms::Heap(_)));
+        assert_eq!(&t[..], &(0..=INLINE).collect::<Vec<_>>()[..]);
+    }
+
+    #[test]
+    fn test_capacity() {
+        let mut t = Terms::<i32>::new();
+        assert_eq!(t.capacity(), INLINE);
+        t.reserve(INLINE);
+        assert!(matches!(t, Terms::Inline { .. }));
+        t.reserve(INLINE + 1);
+        assert!(t.capacity() > INLINE);
+        t.push(1);
+        t.shrink_to_fit();
+        assert!(matches!(t, Terms::Inline { .. }));
+        assert_eq!(&t[..], &[1]);
+    }
+
+    #[test]
+    fn test_drop() {
+        let item = Rc::new(0);
+        for count in [0, 1, INLINE, INLINE + 3] {
+            let mut t = Terms::new();
+            for _ in 0..count {
+                t.push(item.clone());
+            }
+            let cloned = t.clone();
+            assert_eq!(Rc::strong_count(&item), 1 + 2 * count);
+            drop(t);
+            assert_eq!(Rc::strong_count(&item), 1 + count);
+
+            // partially consumed iterator drops the rest
+            let mut iter = cloned.into_iter();
+            let first = iter.next();
+            assert_eq!(first.is_some(), count > 0);
+            drop(iter);
+            drop(first);
+            assert_eq!(Rc::strong_count(&item), 1);
+        }
+    }
+
+    #[test]
+    fn test_truncate() {
+        let item = Rc::new(0);
+        for count in [INLINE, INLINE + 3] {
+            let mut t = Terms::new();
+            for _ in 0..count {
+                t.push(item.clone());
+            }
+            t.truncate(count + 1);
+            assert_eq!(t.len(), count);
+            t.truncate(1);
+            assert_eq!(t.len(), 1);
+            assert_eq!(Rc::strong_count(&item), 2);
+            t.truncate(0);
+            assert_eq!(Rc::strong_count(&item), 1);
+        }
+    }
+
+    #[test]
+    fn test_into_iter() {
+        let mut t = Terms::new();
+        t.push(String::from("a"));
+        t.push(String::from("b"));
+        t.sort_by(|a, b| b.cmp(a));
+        let v: Vec<String> = t.into_iter().collect();
+        assert_eq!(v, vec!["b", "a"]);
+    }
+}
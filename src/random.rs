@@ -0,0 +1,165 @@
+//! Defines small random generator `Xorshift64` and trait `Random`.
+//!
+//! Generator is not cryptographic. It is made for tests and fuzzing,
+//! where same seed must give same values.
+
+/// Xorshift pseudo random generator with 64 bit state.
+///
+/// Example:
+/// ```
+/// # use polylib::random::Xorshift64;
+/// let mut a = Xorshift64::new(42);
+/// let mut b = Xorshift64::new(42);
+/// assert_eq!(a.next_u64(), b.next_u64());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Xorshift64 {
+    state: u64,
+}
+
+impl Xorshift64 {
+    /// Creates generator from seed. Zero seed is replaced with other
+    /// constant, because xorshift never leaves zero state.
+    pub fn new(seed: u64) -> Xorshift64 {
+        let state = if seed == 0 { 0x9E37_79B9_7F4A_7C15 } else { seed };
+        Xorshift64 { state }
+    }
+
+    /// Returns next random `u64`.
+    pub fn next_u64(&mut self) -> u64 {
+        let mut x = self.state;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.state = x;
+        x
+    }
+
+    /// Returns next random `u32`.
+    pub fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    /// Returns random value in `[0, bound)`.
+    ///
+    /// Example:
+    /// ```
+    /// # use polylib::random::Xorshift64;
+    /// let mut rng = Xorshift64::new(1);
+    /// assert!(rng.below(10) < 10);
+    /// ```
+    pub fn below(&mut self, bound: u64) -> u64 {
+        if bound == 0 {
+            panic!("Can't get random value below 0");
+        }
+        // rejection of the last incomplete block makes result uniform
+        let zone = u64::MAX - u64::MAX % bound;
+        loop {
+            let v = self.next_u64();
+            if v < zone {
+                return v % bound;
+            }
+        }
+    }
+}
+
+/**
+ * Means, that value of type can be generated with `Xorshift64`.
+ *
+ * Integers take any value of the type, floats are in `[0, 1)`.
+ *
+ * Example:
+ * ```
+ * # use polylib::random::{Random, Xorshift64};
+ * # use polylib::custom_types::Zn;
+ * let mut rng = Xorshift64::new(7);
+ * let v = Zn::<5>::random(&mut rng);
+ * assert!(v.value() < 5);
+ * ```
+*/
+pub trait Random {
+    /// Returns random value.
+    fn random(rng: &mut Xorshift64) -> Self;
+}
+
+macro_rules! impl_random_int {
+    ($($t:ty),*) => {
+        $(
+            impl Random for $t {
+                fn random(rng: &mut Xorshift64) -> Self {
+                    rng.next_u64() as $t
+                }
+            }
+        )*
+    };
+}
+
+impl_random_int!(i8, i16, i32, i64, isize);
+impl_random_int!(u8, u16, u32, u64, usize);
+
+impl Random for i128 {
+    fn random(rng: &mut Xorshift64) -> Self {
+        u128::random(rng) as i128
+    }
+}
+
+impl Random for u128 {
+    fn random(rng: &mut Xorshift64) -> Self {
+        ((rng.next_u64() as u128) << 64) | rng.next_u64() as u128
+    }
+}
+
+impl Random for f32 {
+    fn random(rng: &mut Xorshift64) -> Self {
+        (rng.next_u64() >> 40) as f32 / (1u32 << 24) as f32
+    }
+}
+
+impl Random for f64 {
+    fn random(rng: &mut Xorshift64) -> Self {
+        (rng.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+#[cfg(test)]
+mod random_tests {
+    use super::{Random, Xorshift64};
+
+    #[test]
+    fn test_reproducible() {
+        let mut a = Xorshift64::new(123);
+        let mut b = Xorshift64::new(123);
+        for _ in 0..100 {
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+        let mut c = Xorshift64::new(124);
+        assert_ne!(a.next_u64(), c.next_u64());
+    }
+
+    #[test]
+    fn test_zero_seed() {
+        let mut rng = Xorshift64::new(0);
+        assert_ne!(rng.next_u64(), 0);
+    }
+
+    #[test]
+    fn test_below() {
+        let mut rng = Xorshift64::new(5);
+        let mut seen = [false; 6];
+        for _ in 0..200 {
+            seen[rng.below(6) as usize] = true;
+        }
+        assert!(seen.iter().all(|s| *s));
+    }
+
+    #[test]
+    fn test_float() {
+        let mut rng = Xorshift64::new(9);
+        for _ in 0..100 {
+            let v = f64::random(&mut rng);
+            assert!((0.0..1.0).contains(&v));
+            let v = f32::random(&mut rng);
+            assert!((0.0..1.0).contains(&v));
+        }
+    }
+}
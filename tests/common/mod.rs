@@ -1,8 +1,8 @@
 use std::fmt::{Debug, Display};
-use std::ops::{Add, Mul};
+use std::ops::Add;
 
 use polylib::polynom::Polynomial;
-use polylib::{One, Zero};
+use polylib::{SubstitutablePoint, Zero};
 
 #[allow(dead_code)]
 pub fn same_coef<T, U>(poly: Polynomial<T, U>, coef: Vec<T>)
@@ -14,8 +14,8 @@ where
     let poly = poly.reduce();
 
     let mut need_coef = 0;
-    for i in 0..coef.len() {
-        if coef[i].is_zero() {
+    for (i, c) in coef.iter().enumerate() {
+        if c.is_zero() {
             continue;
         }
         need_coef += 1;
@@ -24,11 +24,11 @@ where
             None => {
                 panic!(
                     "poly '{}' is expected to have coef {} equal to {:?}",
-                    poly, i, coef[i]
+                    poly, i, c
                 )
             }
             Some(val) => {
-                assert_eq!(val.clone(), coef[i], "poly '{}' has bad coef {}", poly, i)
+                assert_eq!(val, c, "poly '{}' has bad coef {}", poly, i)
             }
         }
     }
@@ -40,14 +40,12 @@ where
     }
 }
 
+#[allow(dead_code)]
 pub fn substitude_check<A, B, T, U>(poly: Polynomial<T, U>, x: Vec<A>, ans: Vec<B>)
 where
     T: Clone,
-    A: One + Clone + Debug,
-    B: Zero + Clone + Eq + Debug,
-    T: Mul<A, Output = B>,
-    A: Mul<A, Output = A>,
-    B: Add<B, Output = B>,
+    A: SubstitutablePoint<T, B> + Debug,
+    B: Clone + Eq + Debug,
     Polynomial<T, U>: Display,
 {
     assert_eq!(x.len(), ans.len(), "bad test: different sizes of x, ans");
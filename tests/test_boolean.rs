@@ -0,0 +1,36 @@
+use polylib::custom_types::{Bool, Matrix};
+use polylib::polynom::Polynomial;
+use polylib::{One, Zero};
+
+mod common;
+use common::same_coef;
+
+fn b(value: bool) -> Bool {
+    Bool::new(value)
+}
+
+#[test]
+fn test_poly() {
+    // (x + 1)^2 = x^2 + x + 1, because 1 + 1 = 1
+    let p = Polynomial::<Bool>::from_coefs(vec![b(true), b(true)]);
+    same_coef(p.pow(2), vec![b(true), b(true), b(true)]);
+}
+
+#[test]
+fn test_reachability() {
+    // path 0 -> 1 -> 2 -> 3
+    type Type = Matrix<4, 4, Bool>;
+    let mut graph = Type::zero();
+    for i in 0..3 {
+        graph[(i, i + 1)] = Bool::one();
+    }
+    // x + x^2 gives vertices, reachable in 1 or 2 steps
+    let poly = Polynomial::<Type>::from_coefs(vec![Type::zero(), Type::one(), Type::one()]);
+    let reach: Type = poly.substitude(graph);
+    assert_eq!(reach[(0, 1)], b(true));
+    assert_eq!(reach[(0, 2)], b(true));
+    assert_eq!(reach[(0, 3)], b(false));
+    assert_eq!(reach[(1, 3)], b(true));
+    assert_eq!(reach[(0, 0)], b(false));
+    assert_eq!(reach[(3, 0)], b(false));
+}
@@ -0,0 +1,59 @@
+use polylib::polynom::{Bounded, Polynomial, X};
+use polylib::{Evaluate, PolyError};
+
+type Poly = Polynomial<i64>;
+type Filter = Bounded<i64, 3>;
+
+#[test]
+fn test_bounded_new() {
+    let x = X::<i64>::default();
+    // x^5 cancels out, so degree is 2
+    let p = x.pow(5) + x.pow(2) * 3 - x.pow(5) + 1;
+    let f = Filter::new(p.clone());
+    assert_eq!(f.get(), &Poly::from_coefs(vec![1, 0, 3]));
+    assert_eq!(f.get().len(), 2);
+    assert_eq!(f.max_degree(), 3);
+    let err = PolyError::DegreeOverflow { degree: 4, max: 3 };
+    assert_eq!(Filter::try_from(x.pow(4) + 0), Err(err));
+    assert!(Filter::try_new(Poly::default()).is_ok());
+    assert_eq!(Poly::from(f.clone()), p);
+    assert_eq!(f.to_string(), "1 + 3x^2");
+    assert_eq!(f.evaluate(2i64), 13);
+    assert_eq!(
+        PolyError::DegreeOverflow { degree: 4, max: 3 }.to_string(),
+        "degree 4 exceeds bound 3"
+    );
+}
+
+#[test]
+#[should_panic(expected = "Can't create Bounded: degree 4 exceeds bound 3")]
+fn test_bounded_new_overflow() {
+    Filter::new(Poly::from_coefs(vec![0, 0, 0, 0, 1]));
+}
+
+#[test]
+fn test_bounded_ops() {
+    let a = Filter::new(Poly::from_coefs(vec![1, 2, 0, 4]));
+    let b = Filter::new(Poly::from_coefs(vec![3, 0, 1, -4]));
+    assert_eq!((a.clone() + b.clone()).get(), &Poly::from_coefs(vec![4, 2, 1]));
+    assert_eq!((a.clone() - a.clone()).get().len(), 0);
+    assert_eq!((-a.clone() * 2).get(), &Poly::from_coefs(vec![-2, -4, 0, -8]));
+
+    let c = Filter::new(Poly::from_coefs(vec![5, 1]));
+    let d = Filter::new(Poly::from_coefs(vec![-1, 0, 1]));
+    assert_eq!(c.clone() * d.clone(), Filter::new(Poly::from_coefs(vec![-5, -1, 5, 1])));
+    assert_eq!(c.checked_mul(&a), Err(PolyError::DegreeOverflow { degree: 4, max: 3 }));
+
+    let product: Bounded<i64, 6> = a.mul_widen(&b);
+    let expect = Poly::from_coefs(vec![1, 2, 0, 4]) * Poly::from_coefs(vec![3, 0, 1, -4]);
+    assert_eq!(product.get(), &expect.reduce());
+    let wide: Bounded<i64, 10> = a.clone().widen();
+    assert_eq!(wide.into_inner(), a.into_inner());
+}
+
+#[test]
+#[should_panic(expected = "Can't multiply Bounded: degree 6 exceeds bound 3")]
+fn test_bounded_mul_overflow() {
+    let a = Filter::new(Poly::from_coefs(vec![1, 0, 0, 1]));
+    let _ = a.clone() * a;
+}
@@ -0,0 +1,77 @@
+use polylib::polynom::chebyshev::{nodes, ChebyshevSeries};
+use polylib::polynom::Polynomial;
+
+#[test]
+fn test_nodes() {
+    let x = nodes(5, -1.0, 3.0);
+    assert_eq!(x.len(), 5);
+    assert!(x.windows(2).all(|w| w[0] < w[1]));
+    assert!(x.iter().all(|x| -1.0 < *x && *x < 3.0));
+    // nodes are symmetric around middle, odd count contains middle
+    assert!((x[2] - 1.0).abs() < 1e-15);
+    assert!((x[0] + x[4] - 2.0).abs() < 1e-15);
+    assert!(nodes(0, 0.0, 1.0).is_empty());
+}
+
+#[test]
+#[should_panic]
+fn test_empty_interval() {
+    let _ = nodes(3, 1.0, 1.0);
+}
+
+#[test]
+fn test_polynomial_roundtrip() {
+    let p = Polynomial::<f64>::from_coefs(vec![1.0, -2.0, 0.5, 3.0]);
+    let s = ChebyshevSeries::from_polynomial(&p, 1.0, 4.0);
+    assert_eq!(s.coefs().len(), 4);
+    assert_eq!(s.interval(), (1.0, 4.0));
+    for x in [1.0, 1.7, 2.5, 4.0] {
+        let expect: f64 = p.substitude(x);
+        assert!((s.evaluate(x) - expect).abs() < 1e-10);
+    }
+
+    let q = s.to_polynomial();
+    for i in 0..4 {
+        assert!((q.coef(i) - p.coef(i)).abs() < 1e-10);
+    }
+    assert!(ChebyshevSeries::from_polynomial(&Polynomial::<f64>::from_coefs(vec![]), 0.0, 1.0)
+        .coefs()
+        .is_empty());
+}
+
+#[test]
+fn test_economization() {
+    // x^4 on [-1, 1] is (3 T0 + 4 T2 + T4) / 8
+    let p = Polynomial::<f64>::from_coefs(vec![0.0, 0.0, 0.0, 0.0, 1.0]);
+    let s = ChebyshevSeries::from_polynomial(&p, -1.0, 1.0);
+    let (short, bound) = s.clone().truncate(2);
+    assert_eq!(short.coefs().len(), 3);
+    assert!((bound - 0.125).abs() < 1e-15);
+
+    // x^4 - x^2 + 1/8 is the best quadratic approximation, error is 1/8
+    let q = short.to_polynomial();
+    assert!((q.coef(0) + 0.125).abs() < 1e-12);
+    assert!(q.coef(1).abs() < 1e-12);
+    assert!((q.coef(2) - 1.0).abs() < 1e-12);
+    for x in nodes(50, -1.0, 1.0) {
+        assert!((short.evaluate(x) - x.powi(4)).abs() <= bound + 1e-12);
+    }
+
+    let (same, bound) = s.clone().economize(0.1);
+    assert_eq!((same.coefs().len(), bound), (5, 0.0));
+    let (all, _) = s.clone().truncate(10);
+    assert_eq!(all, s);
+}
+
+#[test]
+fn test_fit() {
+    let s = ChebyshevSeries::fit(f64::sin, 15, 0.0, 3.0);
+    for x in nodes(40, 0.0, 3.0) {
+        assert!((s.evaluate(x) - x.sin()).abs() < 1e-12);
+    }
+    let (short, bound) = s.economize(1e-6);
+    assert!(short.coefs().len() < 15);
+    for x in nodes(40, 0.0, 3.0) {
+        assert!((short.evaluate(x) - x.sin()).abs() <= bound + 1e-12);
+    }
+}
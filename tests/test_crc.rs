@@ -0,0 +1,59 @@
+use polylib::applications::Crc;
+use polylib::custom_types::Gf2Poly;
+
+const CHECK: &[u8] = b"123456789";
+
+#[test]
+fn test_catalogue() {
+    assert_eq!(Crc::crc8().checksum(CHECK), 0xF4);
+    assert_eq!(Crc::crc16().checksum(CHECK), 0xBB3D);
+    assert_eq!(Crc::crc32().checksum(CHECK), 0xCBF43926);
+    assert_eq!(Crc::crc64().checksum(CHECK), 0x995DC9BBDF1939FA);
+    // CRC-16/IBM-3740
+    assert_eq!(Crc::new(16, 0x1021).with_init(0xFFFF).checksum(CHECK), 0x29B1);
+    // CRC-32C (Castagnoli)
+    let crc = Crc::new(32, 0x1EDC6F41)
+        .with_init(0xFFFFFFFF)
+        .with_xor_out(0xFFFFFFFF)
+        .reflected(true);
+    assert_eq!(crc.checksum(CHECK), 0xE3069283);
+    // CRC-64/ECMA-182
+    assert_eq!(Crc::new(64, 0x42F0E1EBA9EA3693).checksum(CHECK), 0x6C40DF5F0B497347);
+    // short widths: CRC-5/USB and CRC-3/ROHC
+    let crc = Crc::new(5, 0x05).with_init(0x1F).with_xor_out(0x1F).reflected(true);
+    assert_eq!(crc.checksum(CHECK), 0x19);
+    assert_eq!(Crc::new(3, 0x3).with_init(0x7).reflected(true).checksum(CHECK), 0x6);
+}
+
+#[test]
+fn test_polynomial_division() {
+    // plain CRC is remainder of data(x) * x^width by generator
+    let g = Gf2Poly::from_powers(&[16, 15, 2, 0]);
+    let crc = Crc::from_gf2_poly(&g);
+    assert_eq!(crc.width(), 16);
+    assert_eq!(crc.generator(), g);
+
+    let data = b"polynomials";
+    let mut poly = Gf2Poly::default();
+    let bits = data.len() * 8;
+    for (i, byte) in data.iter().enumerate() {
+        for b in 0..8 {
+            // first bit is the highest power
+            poly.set(bits - 1 - (i * 8 + b), (byte >> (7 - b)) & 1 == 1);
+        }
+    }
+    let rem = poly.shl(16) % g;
+    assert_eq!(rem.words().first().copied().unwrap_or(0), crc.checksum(data));
+
+    // appending checksum gives zero remainder
+    let sum = crc.checksum(data);
+    let mut full = data.to_vec();
+    full.extend_from_slice(&(sum as u16).to_be_bytes());
+    assert_eq!(crc.checksum(&full), 0);
+}
+
+#[test]
+#[should_panic]
+fn test_bad_width() {
+    let _ = Crc::from_gf2_poly(&Gf2Poly::from_powers(&[65, 0]));
+}
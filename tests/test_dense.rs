@@ -0,0 +1,93 @@
+use polylib::custom_types::{Matrix, Zn};
+use polylib::polynom::{DensePolynomial, Polynomial, X, Y};
+use polylib::{One, Zero};
+
+mod common;
+use common::same_coef;
+
+type Dense = DensePolynomial<i32>;
+
+#[test]
+fn test_create() {
+    let p = Dense::from_coefs(vec![1, 0, -2, 0, 0]);
+    assert_eq!(p.coefs(), &[1, 0, -2]);
+    assert_eq!(p.get(2), Some(&-2));
+    assert_eq!(p.get(3), None);
+    assert_eq!(p.degree(), Some(2));
+    assert_eq!(p.into_coefs(), vec![1, 0, -2]);
+
+    let p = Dense::from_coefs(vec![0, 0]);
+    assert!(p.is_zero());
+    assert_eq!(p, Dense::zero());
+    assert_eq!(p.degree(), None);
+    assert!(Dense::one().is_one());
+}
+
+#[test]
+fn test_arithmetic() {
+    let a = Dense::from_coefs(vec![1, 2, 3]);
+    let b = Dense::from_coefs(vec![3, -2]);
+    assert_eq!((a.clone() + b.clone()).coefs(), &[4, 0, 3]);
+    assert_eq!((b.clone() + a.clone()).coefs(), &[4, 0, 3]);
+    assert_eq!((a.clone() - b.clone()).coefs(), &[-2, 4, 3]);
+    assert_eq!((b.clone() - a.clone()).coefs(), &[2, -4, -3]);
+    assert_eq!((-a.clone()).coefs(), &[-1, -2, -3]);
+    assert_eq!((a.clone() * 2).coefs(), &[2, 4, 6]);
+    assert_eq!((a.clone() * b.clone()).coefs(), &[3, 4, 5, -6]);
+
+    // leading coefs cancel
+    assert_eq!((a.clone() - a.clone()).degree(), None);
+    let c = Dense::from_coefs(vec![0, 0, -3]);
+    assert_eq!((a.clone() + c).coefs(), &[1, 2]);
+    assert!((a * Dense::zero()).is_zero());
+
+    // Zn<6> has zero divisors
+    let p = DensePolynomial::<Zn<6>>::from_coefs(vec![Zn::new(1), Zn::new(2)]);
+    let q = DensePolynomial::<Zn<6>>::from_coefs(vec![Zn::new(1), Zn::new(3)]);
+    assert_eq!((p * q).degree(), Some(1));
+}
+
+#[test]
+fn test_substitude() {
+    let p = Dense::from_coefs(vec![1, 2, 3]);
+    assert_eq!(p.substitude::<i32, i32>(0), 1);
+    assert_eq!(p.substitude::<i32, i32>(-1), 2);
+    assert_eq!(p.substitude::<i32, i32>(3), 34);
+    assert_eq!(Dense::zero().substitude::<i32, i32>(3), 0);
+
+    type Type = Matrix<2, 2, i32>;
+    let p = DensePolynomial::<Type>::from_coefs(vec![Type::eye(1), Type::eye(0), Type::eye(1)]);
+    let m = Type::from_data(vec![0, 1, 1, 0]);
+    assert_eq!(p.substitude::<Type, Type>(m), Type::eye(2));
+}
+
+#[test]
+fn test_conversion() {
+    let x = X::<i32>::default();
+    let sparse = x.pow(4) * 2 + x.pow(1) - x.pow(4) + 7 + x.pow(1);
+    let dense = Dense::from(sparse.clone());
+    assert_eq!(dense.coefs(), &[7, 2, 0, 0, 1]);
+    assert_eq!(dense.to_polynomial(), sparse);
+    same_coef(dense.into(), vec![7, 2, 0, 0, 1]);
+
+    let p = DensePolynomial::<i32, Y<i32>>::from_coefs(vec![0, 1, 0, 3]);
+    let poly: Polynomial<i32, Y<i32>> = p.clone().into();
+    assert_eq!(poly.len(), 2);
+    assert_eq!(DensePolynomial::from(poly), p);
+    assert!(Dense::from(x.pow(2) - x.pow(2)).is_zero());
+
+    // products agree with sparse ones
+    let a = Polynomial::<i32>::from_coefs(vec![1, -1, 0, 2]);
+    let b = Polynomial::<i32>::from_coefs(vec![0, 3, 1]);
+    assert_eq!(
+        Dense::from(a.clone()) * Dense::from(b.clone()),
+        Dense::from(a * b)
+    );
+}
+
+#[test]
+fn test_display() {
+    assert_eq!(format!("{}", Dense::from_coefs(vec![1, 1, 3])), "1 + x + 3x^2");
+    assert_eq!(format!("{}", Dense::from_coefs(vec![0, 0, 1])), "x^2");
+    assert_eq!(format!("{}", Dense::zero()), "0");
+}
@@ -0,0 +1,39 @@
+use polylib::custom_types::Dual;
+use polylib::polynom::Polynomial;
+
+mod common;
+use common::substitude_check;
+
+type Poly = Polynomial<i64>;
+
+#[test]
+fn test_dual() {
+    // p = x^3 - 2x + 5, p' = 3x^2 - 2
+    let poly = Poly::from_coefs(vec![5, -2, 0, 1]);
+    substitude_check(
+        poly,
+        vec![Dual::variable(0), Dual::variable(1), Dual::variable(-3)],
+        vec![Dual::new(5, -2), Dual::new(4, 1), Dual::new(-16, 25)],
+    );
+}
+
+#[test]
+fn test_dual_coefs() {
+    // coefs may be dual too: d/dx (x^2 + x) at 4
+    let poly = Polynomial::<Dual<f64>>::from_coefs(vec![
+        Dual::constant(0.0),
+        Dual::constant(1.0),
+        Dual::constant(1.0),
+    ]);
+    let ans: Dual<f64> = poly.substitude(Dual::variable(4.0));
+    assert_eq!(ans.into_parts(), (20.0, 9.0));
+}
+
+#[test]
+fn test_jet() {
+    use polylib::custom_types::Jet;
+    // p = x^3 - 2x + 5 at 2: p = 9, p' = 10, p'' = 12, p''' = 6
+    let poly = Poly::from_coefs(vec![5, -2, 0, 1]);
+    let ans: Jet<i64, 4> = poly.substitude(Jet::variable(2));
+    assert_eq!(ans.derivatives(), vec![9, 10, 12, 6, 0]);
+}
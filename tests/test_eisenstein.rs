@@ -0,0 +1,32 @@
+use polylib::custom_types::EisensteinInt;
+use polylib::polynom::Polynomial;
+
+mod common;
+use common::same_coef;
+use common::substitude_check;
+
+type TestType = EisensteinInt;
+type Poly = Polynomial<TestType>;
+
+fn e(a: i64, b: i64) -> TestType {
+    EisensteinInt::new(a, b)
+}
+
+#[test]
+fn test_eisenstein() {
+    // x^2 + x + 1 has roots ω and ω^2 = -1 - ω
+    let poly = Poly::from_coefs(vec![e(1, 0), e(1, 0), e(1, 0)]);
+    substitude_check(
+        poly,
+        vec![e(0, 1), e(-1, -1), e(1, 0)],
+        vec![e(0, 0), e(0, 0), e(3, 0)],
+    );
+}
+
+#[test]
+fn test_product() {
+    // (x - ω)(x + 1 + ω) = x^2 + x + 1
+    let a = Poly::from_coefs(vec![e(0, -1), e(1, 0)]);
+    let b = Poly::from_coefs(vec![e(1, 1), e(1, 0)]);
+    same_coef(a * b, vec![e(1, 0), e(1, 0), e(1, 0)]);
+}
@@ -0,0 +1,96 @@
+use polylib::custom_types::{Matrix, Zn};
+use polylib::polynom::{FixedPolynomial, Polynomial, Y};
+use polylib::Zero;
+
+mod common;
+use common::same_coef;
+
+type Fixed3 = FixedPolynomial<i32, 3>;
+
+#[test]
+fn test_create() {
+    let p = Fixed3::new([1, 0, -2]);
+    assert_eq!(p.coefs(), &[1, 0, -2]);
+    assert_eq!(p.get(2), Some(&-2));
+    assert_eq!(p.get(3), None);
+    assert_eq!(p.degree(), Some(2));
+
+    let p = Fixed3::zero();
+    assert!(p.is_zero());
+    assert_eq!(p.degree(), None);
+}
+
+#[test]
+fn test_arithmetic() {
+    let a = Fixed3::new([1, 2, 3]);
+    let b = Fixed3::new([3, -2, 0]);
+    assert_eq!((a + b).coefs(), &[4, 0, 3]);
+    assert_eq!((a - b).coefs(), &[-2, 4, 3]);
+    assert_eq!((-a).coefs(), &[-1, -2, -3]);
+    assert_eq!((a * 2).coefs(), &[2, 4, 6]);
+
+    let c: FixedPolynomial<i32, 5> = a.mul_widen(&b);
+    assert_eq!(c.coefs(), &[3, 4, 5, -6, 0]);
+
+    let d: FixedPolynomial<i32, 6> = a.mul_widen(&FixedPolynomial::<i32, 4>::new([0, 0, 0, 1]));
+    assert_eq!(d.coefs(), &[0, 0, 0, 1, 2, 3]);
+}
+
+#[test]
+fn test_substitude() {
+    let p = Fixed3::new([1, 2, 3]);
+    assert_eq!(p.substitude::<i32, i32>(0), 1);
+    assert_eq!(p.substitude::<i32, i32>(-1), 2);
+    assert_eq!(p.substitude::<i32, i32>(3), 34);
+
+    let p = FixedPolynomial::<Zn<7>, 2>::new([Zn::new(3), Zn::new(1)]);
+    assert_eq!(p.substitude::<Zn<7>, Zn<7>>(Zn::new(5)), Zn::new(1));
+
+    type Type = Matrix<2, 2, i32>;
+    let p = FixedPolynomial::<Type, 3>::new([Type::eye(1), Type::eye(0), Type::eye(1)]);
+    let m = Type::from_data(vec![0, 1, 1, 0]);
+    assert_eq!(p.substitude::<Type, Type>(m), Type::eye(2));
+}
+
+#[test]
+fn test_to_polynomial() {
+    let p = FixedPolynomial::<i32, 4, Y<i32>>::new([0, 1, 0, 3]);
+    let poly: Polynomial<i32, Y<i32>> = p.into();
+    assert_eq!(poly.len(), 2);
+    same_coef(poly, vec![0, 1, 0, 3]);
+
+    same_coef(Fixed3::new([5, 0, 1]).to_polynomial(), vec![5, 0, 1]);
+}
+
+#[test]
+fn test_display() {
+    assert_eq!(format!("{}", Fixed3::new([1, 1, 3])), "1 + x + 3x^2");
+    assert_eq!(format!("{}", Fixed3::new([0, 0, 1])), "x^2");
+    assert_eq!(format!("{}", Fixed3::zero()), "0");
+}
+
+const SQUARES: [u32; 5] = {
+    let p = FixedPolynomial::<u32, 3>::new([0, 0, 1]);
+    let mut table = [0; 5];
+    let mut i = 0;
+    while i < table.len() {
+        table[i] = p.eval(i as u32);
+        i += 1;
+    }
+    table
+};
+
+#[test]
+fn test_const_eval() {
+    const Y: i64 = FixedPolynomial::<i64, 3>::new([1, 2, 3]).eval(5);
+    assert_eq!(Y, 86);
+    assert_eq!(SQUARES, [0, 1, 4, 9, 16]);
+
+    const EMPTY: u8 = FixedPolynomial::<u8, 0>::new([]).eval(7);
+    assert_eq!(EMPTY, 0);
+
+    let p = FixedPolynomial::<i32, 4>::new([-1, 0, 2, 1]);
+    for x in -3..3 {
+        assert_eq!(p.eval(x), p.substitude::<i32, i32>(x));
+    }
+}
@@ -0,0 +1,23 @@
+use polylib::custom_types::Fixed;
+use polylib::polynom::Polynomial;
+
+mod common;
+use common::substitude_check;
+
+type TestType = Fixed<16>;
+type Poly = Polynomial<TestType>;
+
+fn q(value: f64) -> TestType {
+    Fixed::from_f64(value)
+}
+
+#[test]
+fn test_calibration() {
+    // 0.5x^2 - 1.25x + 3
+    let poly = Poly::from_coefs(vec![q(3.0), q(-1.25), q(0.5)]);
+    substitude_check(
+        poly,
+        vec![q(0.0), q(2.0), q(-1.5)],
+        vec![q(3.0), q(2.5), q(6.0)],
+    );
+}
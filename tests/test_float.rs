@@ -0,0 +1,107 @@
+use polylib::polynom::{Polynomial, X};
+
+type Poly = Polynomial<f64, X<f64>>;
+
+#[test]
+fn test_approx_eq() {
+    let x = X::<f64>::default();
+    // (x - 0.1)(x - 0.2)(x - 0.3) with rounding errors
+    let p: Poly = [0.1, 0.2, 0.3].iter().map(|r| x.pow(1) - *r).product();
+    let exact = Poly::from_coefs(vec![-0.006, 0.11, -0.6, 1.0]);
+    assert!(p.approx_eq(&exact, 1e-15, 0.0));
+    assert!(p.approx_eq(&exact, 0.0, 1e-14));
+    assert!(!p.approx_eq(&Poly::from_coefs(vec![-0.006, 0.11, -0.6]), 1e-9, 1e-9));
+
+    // missing coefs are zeros, unreduced members are summed up
+    let a = x.pow(5) * 1e-13 + x.pow(1) * 2.0 - x.pow(1) + 0.0;
+    let b = Poly::from_coefs(vec![1e-14, 1.0]);
+    assert!(a.approx_eq(&b, 1e-12, 0.0));
+    assert!(b.approx_eq(&a, 1e-12, 0.0));
+    assert!(!a.approx_eq(&b, 1e-14, 1e-3));
+
+    // relative tolerance for large coefs
+    let a = Poly::from_coefs(vec![1e20, 1.0]);
+    let b = Poly::from_coefs(vec![1e20 + 1e6, 1.0]);
+    assert!(a.approx_eq(&b, 1e-9, 1e-12));
+    assert!(!a.approx_eq(&b, 1e-9, 1e-15));
+
+    assert!(Poly::default().approx_eq(&Poly::from_coefs(vec![0.0, -1e-20]), 1e-15, 0.0));
+    let nan = Poly::from_coefs(vec![f64::NAN]);
+    assert!(!nan.approx_eq(&nan, 1.0, 1.0));
+}
+
+#[test]
+fn test_approx_reduce() {
+    let x = X::<f64>::default();
+    let p = (x.pow(1) + 0.1) * (x.pow(1) - 0.1) - x.pow(2) + 0.01 + x.pow(3) * 1e-20;
+    let r = p.clone().approx_reduce(1e-15);
+    assert!(r.is_empty());
+    let r = p.approx_reduce(0.0);
+    assert_eq!(r.degree(), Some(3));
+
+    let p = (Poly::from_coefs(vec![1e-18, 3.0, -1e-16]) + 1.0).with_auto_reduce(8);
+    let r = p.approx_reduce(1e-12);
+    assert_eq!(r.len(), 2);
+    assert_eq!((r.coef(0), r.coef(1)), (1.0, 3.0));
+    assert_eq!(r.auto_reduce_threshold(), Some(8));
+}
+
+#[test]
+fn test_round_and_chop() {
+    let x = X::<f64>::default();
+    let p = x.pow(3) * 1.23456 - x.pow(1) * 0.0004 + 2.5 + x.pow(3) * 1e-12;
+    let r = p.clone().round_coefs(2);
+    assert_eq!(r.len(), 2);
+    assert_eq!(r.coef(3), 1.23);
+    assert_eq!(r.coef(0), 2.5);
+    assert!(r.get(1).is_none());
+    // negative decimals round to tens
+    let r = Poly::from_coefs(vec![1234.5, -55.0, 4.0]).round_coefs(-1);
+    assert_eq!((r.coef(0), r.coef(1)), (1230.0, -60.0));
+    assert_eq!(r.len(), 2);
+
+    let c = p.clone().chop(1e-9);
+    assert_eq!(c.len(), 3);
+    let c = p.chop(1e-3);
+    assert_eq!(c.len(), 2);
+    assert_eq!(c.degree(), Some(3));
+    assert!(Poly::from_coefs(vec![1e-30]).chop(1e-20).is_empty());
+}
+
+#[test]
+fn test_display_precision() {
+    let x = X::<f64>::default();
+    let p = x.pow(2) * (1.0 / 3.0) - x.pow(1) * 2.0 + 1.0;
+    assert_eq!(format!("{:.3}", p), "1.000 + -2.000x + 0.333x^2");
+    assert_eq!(format!("{:.0}", p), "1 + -2x + 0x^2");
+    assert_eq!(format!("{}", Poly::from_coefs(vec![0.5, 1.0])), "0.5 + x");
+    assert_eq!(format!("{:.2}", Poly::default()), "0.00");
+
+    // precision is ignored by integer coefs
+    let p = Polynomial::<i32>::from_coefs(vec![1, 0, 3]);
+    assert_eq!(format!("{:.2}", p), format!("{}", p));
+}
+
+#[test]
+fn test_substitude_compensated() {
+    let x = X::<f64>::default();
+    // well conditioned polynomial: same as usual evaluation
+    let p = x.pow(7) * 0.5 - x.pow(3) * 2.0 + x.pow(1) + 3.0;
+    for point in [-2.0, -0.5, 0.0, 0.25, 1.0, 3.0] {
+        let value: f64 = p.substitude(point);
+        assert!((p.substitude_compensated(point) - value).abs() <= 1e-12 * value.abs().max(1.0));
+    }
+    // unreduced members and missing powers
+    let p = x.pow(4) * 2.0 + x.pow(4) - 1.0;
+    assert_eq!(p.substitude_compensated(2.0), 47.0);
+    assert_eq!(Poly::default().substitude_compensated(5.0), 0.0);
+    assert_eq!(Poly::new_const(-1.5).substitude_compensated(5.0), -1.5);
+
+    // (x - 0.75)^5 (x - 1)^6 near its multiple roots
+    let p = (x.pow(1) - 0.75).pow(5) * (x.pow(1) - 1.0).pow(6);
+    for point in [0.74, 0.76, 0.99, 1.005] {
+        let exact = (point - 0.75f64).powi(5) * (point - 1.0f64).powi(6);
+        let value = p.substitude_compensated(point);
+        assert!(((value - exact) / exact).abs() < 1e-9, "at {}", point);
+    }
+}
@@ -0,0 +1,34 @@
+use polylib::custom_types::Fraction;
+use polylib::polynom::Polynomial;
+
+mod common;
+use common::same_coef;
+use common::substitude_check;
+
+type TestType = Fraction<i64>;
+type Poly = Polynomial<TestType>;
+
+fn q(num: i64, den: i64) -> TestType {
+    Fraction::new(num, den)
+}
+
+#[test]
+fn test_fraction() {
+    // x^2 / 2 - 1 / 3
+    let poly = Poly::from_coefs(vec![q(-1, 3), q(0, 1), q(1, 2)]);
+    substitude_check(
+        poly,
+        vec![q(0, 1), q(1, 1), q(2, 3)],
+        vec![q(-1, 3), q(1, 6), q(-1, 9)],
+    );
+}
+
+#[test]
+fn test_exact_division() {
+    // (2x^2 + 1) / (3x + 1) = 2/3 x - 2/9, remainder 11/9
+    let a = Poly::from_coefs(vec![q(1, 1), q(0, 1), q(2, 1)]);
+    let b = Poly::from_coefs(vec![q(1, 1), q(3, 1)]);
+    let (quot, rem) = a.div_rem(&b);
+    same_coef(quot.reduce(), vec![q(-2, 9), q(2, 3)]);
+    same_coef(rem.reduce(), vec![q(11, 9)]);
+}
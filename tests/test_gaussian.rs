@@ -0,0 +1,32 @@
+use polylib::custom_types::GaussianInt;
+use polylib::polynom::Polynomial;
+
+mod common;
+use common::same_coef;
+use common::substitude_check;
+
+type TestType = GaussianInt;
+type Poly = Polynomial<TestType>;
+
+fn g(re: i64, im: i64) -> TestType {
+    GaussianInt::new(re, im)
+}
+
+#[test]
+fn test_gaussian() {
+    // x^2 + 1 has roots i and -i
+    let poly = Poly::from_coefs(vec![g(1, 0), g(0, 0), g(1, 0)]);
+    substitude_check(
+        poly,
+        vec![g(0, 1), g(0, -1), g(1, 1)],
+        vec![g(0, 0), g(0, 0), g(1, 2)],
+    );
+}
+
+#[test]
+fn test_product() {
+    // (x - i)(x + i) = x^2 + 1
+    let a = Poly::from_coefs(vec![g(0, -1), g(1, 0)]);
+    let b = Poly::from_coefs(vec![g(0, 1), g(1, 0)]);
+    same_coef(a * b, vec![g(1, 0), g(0, 0), g(1, 0)]);
+}
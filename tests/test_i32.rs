@@ -1,5 +1,6 @@
 use polylib::polynom::Polynomial;
 use polylib::polynom::{X, Y};
+use polylib::custom_types::Zn;
 use polylib::{One, Zero};
 
 mod common;
@@ -122,6 +123,7 @@ fn test_neg() {
 }
 
 #[test]
+#[allow(clippy::erasing_op)]
 fn test_const_mul() {
     let a = Poly::from_coefs(vec![2, 1, 0, -2]);
     same_coef(a.clone() * 3, vec![6, 3, 0, -6]);
@@ -238,3 +240,510 @@ fn test_build() {
     let poly = Polynomial::default() + 1 + (x ^ 2) * 3 - (x ^ 1) + 8;
     same_coef(poly, vec![9, -1, 3]);
 }
+
+#[test]
+fn test_sum() {
+    let polys = vec![
+        Poly::from_coefs(vec![1, 2]),
+        Poly::from_coefs(vec![0, 1, 3]),
+        Poly::from_coefs(vec![-1]),
+    ];
+    let sum: Poly = polys.iter().sum();
+    same_coef(sum, vec![0, 3, 3]);
+
+    let sum: Poly = polys.into_iter().sum();
+    same_coef(sum, vec![0, 3, 3]);
+
+    let sum: Poly = Vec::<Poly>::new().into_iter().sum();
+    same_coef(sum, vec![]);
+}
+
+#[test]
+fn test_product() {
+    let x = X::<i32>::default();
+
+    // (x - 1)(x - 2)(x - 3)
+    let prod: Poly = [1, 2, 3].iter().map(|r| x.pow(1) - *r).product();
+    same_coef(prod, vec![-6, 11, -6, 1]);
+
+    let polys = vec![Poly::from_coefs(vec![1, 1]); 3];
+    let prod: Poly = polys.iter().product();
+    same_coef(prod, vec![1, 3, 3, 1]);
+
+    let prod: Poly = Vec::<Poly>::new().into_iter().product();
+    same_coef(prod, vec![1]);
+}
+
+#[test]
+fn test_coef() {
+    let a = Poly::from_coefs(vec![3, 0, -1]);
+    assert_eq!(a.coef(0), 3);
+    assert_eq!(a.coef(1), 0);
+    assert_eq!(a.coef(2), -1);
+    assert_eq!(a.coef(100), 0);
+
+    let sum: i32 = (0..3).map(|i| a.coef(i)).sum();
+    assert_eq!(sum, 2);
+
+    let a = Poly::default();
+    assert_eq!(a.coef(0), 0);
+
+    // members with the same power are summed up
+    let x = X::<TestType>::default();
+    let a = x.pow(2) * 2 + 1 + x.pow(2) * 5;
+    assert_eq!(a.coef(2), 7);
+    assert_eq!(a.coef(0), 1);
+}
+
+#[test]
+fn test_div_rem() {
+    // x^3 + 2x + 5 = (x - 1)(x^2 + x + 3) + 8
+    let a = Poly::from_coefs(vec![5, 2, 0, 1]);
+    let b = Poly::from_coefs(vec![-1, 1]);
+    let (q, r) = a.div_rem(&b);
+    same_coef(q, vec![3, 1, 1]);
+    same_coef(r, vec![8]);
+
+    let a = Poly::from_coefs(vec![1, 2]);
+    let b = Poly::from_coefs(vec![0, 0, 1]);
+    let (q, r) = a.div_rem(&b);
+    same_coef(q, vec![]);
+    same_coef(r, vec![1, 2]);
+
+    // (2x + 2)(x + 3) = 2x^2 + 8x + 6
+    let a = Poly::from_coefs(vec![6, 8, 2]);
+    same_coef(a.clone() / Poly::from_coefs(vec![3, 1]), vec![2, 2]);
+    same_coef(a % Poly::from_coefs(vec![3, 1]), vec![]);
+}
+
+#[test]
+fn test_div_by_zero() {
+    let a = Poly::from_coefs(vec![1, 2]);
+    assert_eq!(
+        a.checked_div_rem(&Poly::from_coefs(vec![0, 0])).unwrap_err(),
+        polylib::PolyError::DivisionByZero
+    );
+    let result = std::panic::catch_unwind(|| a.div_rem(&Poly::default()));
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_capacity() {
+    let x = X::<i32>::default();
+    let mut a = Poly::default();
+    a.reserve(16);
+    let capacity = a.capacity();
+    assert!(capacity >= 16);
+    for i in 0..16 {
+        a = a + x.pow(i % 4);
+    }
+    assert_eq!(a.capacity(), capacity);
+
+    let a = a.compact();
+    assert_eq!(a.len(), 4);
+    assert!(a.capacity() < capacity);
+    same_coef(a, vec![4, 4, 4, 4]);
+
+    let mut a = Poly::from_coefs(vec![1, 2, 3, 4, 5, 6]);
+    a.reserve(100);
+    a.shrink_to_fit();
+    assert_eq!(a.capacity(), 6);
+}
+
+#[test]
+fn test_degree() {
+    assert_eq!(Poly::from_coefs(vec![1, 0, 3]).degree(), Some(2));
+    assert_eq!(Poly::from_coefs(vec![5]).degree(), Some(0));
+    assert_eq!(Poly::from_coefs(vec![0, 0]).degree(), None);
+
+    let x = X::<TestType>::default();
+    assert_eq!((x.pow(4) + 1 - x.pow(4)).degree(), Some(0));
+    assert_eq!((x.pow(2) - x.pow(2)).degree(), None);
+}
+
+#[test]
+fn test_ordering() {
+    use std::collections::BTreeMap;
+
+    let x = X::<TestType>::default();
+    // unreduced forms are equal to reduced
+    assert_eq!(x.pow(2) + x.pow(1) - x.pow(2), Poly::from_coefs(vec![0, 1]));
+    assert_eq!(Poly::from_coefs(vec![]), Poly::from_coefs(vec![0, 0, 0]));
+    assert_ne!(Poly::from_coefs(vec![1, 2]), Poly::from_coefs(vec![1, 2, 3]));
+
+    // degree goes first
+    assert!(Poly::from_coefs(vec![100, -5]) < Poly::from_coefs(vec![0, 0, -1]));
+    assert!(Poly::from_coefs(vec![]) < Poly::from_coefs(vec![-7]));
+    // then coefs from the highest power, missing coef is zero
+    assert!(Poly::from_coefs(vec![5, 0, 1]) < Poly::from_coefs(vec![0, 1, 1]));
+    assert!(Poly::from_coefs(vec![0, -1, 1]) < Poly::from_coefs(vec![5, 0, 1]));
+    assert!(Poly::from_coefs(vec![1, 2, 3]) > Poly::from_coefs(vec![0, 2, 3]));
+
+    let mut v = vec![
+        x.pow(3) - x.pow(3),
+        x.pow(2) * 2,
+        x.pow(1) + 1,
+        x.pow(2) + x.pow(2),
+        x.pow(1) + 1 - 2,
+        x.pow(0) * 4,
+    ];
+    v.sort();
+    v.dedup();
+    let coefs: Vec<Vec<TestType>> = v
+        .iter()
+        .map(|p| (0..3).map(|i| p.clone().reduce().coef(i)).collect())
+        .collect();
+    assert_eq!(
+        coefs,
+        vec![vec![0, 0, 0], vec![4, 0, 0], vec![-1, 1, 0], vec![1, 1, 0], vec![0, 0, 2]]
+    );
+
+    // lazily created zero inside of polynomial does not change ordering
+    #[allow(clippy::mutable_key_type)]
+    let mut map = BTreeMap::new();
+    map.insert(x.pow(1) * 2, "a");
+    map.insert(x.pow(1) + x.pow(1), "b");
+    assert_eq!(map.len(), 1);
+    assert_eq!(map[&Poly::from_coefs(vec![0, 2])], "b");
+
+    // floats are partially ordered
+    let nan = Polynomial::<f64>::from_coefs(vec![f64::NAN, 1.0]);
+    let one = Polynomial::<f64>::from_coefs(vec![1.0, 1.0]);
+    assert_eq!(nan.partial_cmp(&one), None);
+    assert!(Polynomial::<f64>::from_coefs(vec![0.5, 1.0]) < one);
+}
+
+#[test]
+fn test_reduce_in_place() {
+    let x = X::<TestType>::default();
+    // zeros at the beginning, in the middle and at the end
+    let mut p = x.pow(5) + x.pow(0) - x.pow(0) + x.pow(2) * 3 - x.pow(7) + x.pow(2);
+    p = p + x.pow(7) + x.pow(3) - x.pow(3) + x.pow(5);
+    let capacity = p.capacity();
+    p.reduce_in_place();
+    assert_eq!(p.capacity(), capacity);
+    assert_eq!(p.len(), 2);
+    same_coef(p, vec![0, 0, 4, 0, 0, 2]);
+
+    // already sorted members are merged too
+    let mut p = Poly::from_coefs(vec![1, 2, 3]) + x.pow(2) * -3 + x.pow(3);
+    p.reduce_in_place();
+    same_coef(p, vec![1, 2, 0, 1]);
+
+    let mut p = x.pow(1) - x.pow(1);
+    p.reduce_in_place();
+    assert!(p.is_empty());
+
+    // many summands of few powers
+    let mut p = Poly::default();
+    for i in 0..1000 {
+        p = p + x.pow(i % 7) * (i as TestType);
+    }
+    let mut expected = vec![0; 7];
+    for i in 0..1000 {
+        expected[i % 7] += i as TestType;
+    }
+    p.reduce_in_place();
+    same_coef(p, expected);
+}
+
+#[test]
+fn test_auto_reduce() {
+    let x = X::<TestType>::default();
+    let p = (x.pow(1) + 1).with_auto_reduce(4);
+    assert_eq!(p.auto_reduce_threshold(), Some(4));
+    assert_eq!(p.clone().auto_reduce_threshold(), Some(4));
+    assert_eq!(p.clone().without_auto_reduce().auto_reduce_threshold(), None);
+    assert_eq!(Poly::from_coefs(vec![1, 2]).auto_reduce_threshold(), None);
+
+    // policy is taken from any operand
+    let mut q = Poly::default();
+    for i in 0..50 {
+        q = x.pow(i % 3) + q;
+        assert_eq!(q.len(), i as usize + 1);
+    }
+    let sum = q.clone() + p.clone();
+    assert!(sum.len() <= 4);
+    same_coef(sum, vec![18, 18, 16]);
+    let sum = -(p.clone() * 2) - 1 + q.clone();
+    assert!(sum.len() <= 4);
+    assert_eq!(sum.auto_reduce_threshold(), Some(4));
+    same_coef(sum, vec![14, 15, 16]);
+
+    // product is reduced while it is computed
+    let mut product = q.clone();
+    product *= p.clone();
+    assert!(product.len() <= 4);
+    assert_eq!(product.auto_reduce_threshold(), Some(4));
+    same_coef(product, vec![17, 34, 33, 16]);
+    // `*` may change type of coefs, so it drops policy
+    assert_eq!((q.clone() * p.clone()).auto_reduce_threshold(), None);
+    let product = q.clone().mul_keeping_policy(p.clone());
+    assert!(product.len() <= 4);
+    same_coef(product, vec![17, 34, 33, 16]);
+    let product: Poly = (0..20).map(|_| p.clone()).product();
+    assert_eq!(product.auto_reduce_threshold(), Some(4));
+    assert_eq!(product.substitude(1), 1 << 20);
+    let sum: Poly = (0..20).map(|i| x.pow(i % 2) + 0).chain([p.clone()]).sum();
+    assert!(sum.len() <= 4);
+    same_coef(sum, vec![11, 11]);
+
+    // already long polynomial is reduced at once
+    let q = q.with_auto_reduce(10);
+    assert_eq!(q.len(), 3);
+    // zero threshold reduces after every operation
+    let r = (x.pow(1) - x.pow(1)).with_auto_reduce(0);
+    assert!(r.is_empty());
+    assert_eq!((r.clone() + x.pow(2) + x.pow(2)).len(), 1);
+    let mut r = r;
+    r *= x.pow(2);
+    assert_eq!(r.len(), 0);
+}
+
+#[test]
+fn test_auto_reduce_long_product() {
+    // 640000 distinct powers, nothing can be reduced: reducing after every push
+    // past the threshold takes cubic time, reducing whenever length doubles
+    // takes n log n, so this test finishes in time
+    let n = 800;
+    let a = Poly::from_coefs(vec![1; n]).with_auto_reduce(4);
+    let b = Poly::from_terms((0..n as u32).map(|i| (i * n as u32, 1)));
+    let mut product = a;
+    product *= b;
+    assert_eq!(product.auto_reduce_threshold(), Some(4));
+    assert_eq!(product.len(), n * n);
+    assert_eq!(product.degree(), Some((n * n - 1) as u32));
+    assert_eq!(product.substitude(1), (n * n) as TestType);
+}
+
+#[test]
+fn test_compile() {
+    let x = X::<TestType>::default();
+    let p = x.pow(5) * 3 - x.pow(2) + x.pow(5) + 7 - x.pow(9) + x.pow(9);
+    let f = p.compile::<TestType, TestType>();
+    for point in -5..5 {
+        assert_eq!(f(point), p.substitude(point));
+    }
+    // coef of x^0 is missing
+    let p = x.pow(3) * 2 + x.pow(1);
+    let f = p.compile::<TestType, TestType>();
+    assert_eq!(f(2), 18);
+    assert_eq!(f(-1), -3);
+
+    let f = (x.pow(1) - x.pow(1)).compile::<TestType, TestType>();
+    assert_eq!(f(3), 0);
+    // closure does not borrow polynomial
+    let f = Poly::new_const(4).compile::<TestType, TestType>();
+    assert_eq!(f(100), 4);
+}
+
+#[test]
+fn test_max_power() {
+    let x = X::<TestType>::default();
+    let p = (x ^ 3_000_000_000) * (x ^ 1_294_967_295);
+    assert_eq!(p.degree(), Some(u32::MAX));
+}
+
+#[test]
+#[should_panic(expected = "power of x^3000000000 * x^2000000000 overflows u32")]
+fn test_power_overflow() {
+    let x = X::<TestType>::default();
+    let _ = (x ^ 3_000_000_000) * (x ^ 2_000_000_000);
+}
+
+#[test]
+#[should_panic(expected = "overflows u32")]
+fn test_pow_overflow() {
+    let x = X::<TestType>::default();
+    let _ = (x ^ (1 << 31)).pow(2);
+}
+
+#[test]
+fn test_sorted_members() {
+    let x = X::<TestType>::default();
+    // members are pushed in increasing order of powers
+    let p = Poly::from_coefs(vec![1, 0, 2, 0, 0, 3]);
+    assert_eq!(p.degree(), Some(5));
+    assert_eq!(p.get(2), Some(&2));
+    assert!(p.get(3).is_none());
+    let q = p.clone() + x.pow(7) * 4;
+    assert_eq!(q.degree(), Some(7));
+    assert_eq!(q.get(7), Some(&4));
+
+    // order is broken, then restored by reduce
+    let q = q + x.pow(2) * -2;
+    assert_eq!(q.to_string(), "1 + 2x^2 + -2x^2 + 3x^5 + 4x^7");
+    assert_eq!(q.get(2), Some(&2));
+    assert_eq!(q.degree(), Some(7));
+    let q = q.reduce();
+    assert!(q.get(2).is_none());
+    assert_eq!(q.get(5), Some(&3));
+
+    // leading coef may vanish without reduction
+    let p = x.pow(3) - x.pow(3);
+    assert_eq!(p.degree(), None);
+    let p = Polynomial::<Zn<4>>::from_coefs(vec![Zn::new(2), Zn::new(1), Zn::new(2)]);
+    let p = p * Zn::new(2);
+    assert_eq!(p.degree(), Some(1));
+
+    // quotient is built from the highest power
+    let (q, r) = Poly::from_coefs(vec![5, 2, 0, 1]).div_rem(&Poly::from_coefs(vec![-1, 1]));
+    assert_eq!(q.degree(), Some(2));
+    assert_eq!(q.get(0), Some(&3));
+    assert_eq!(r.get(0), Some(&8));
+}
+
+#[test]
+fn test_try_div_exact() {
+    let x = X::<TestType>::default();
+    // cyclotomic polynomial: Φ6 = (x^6 - 1) / ((x - 1)(x + 1)(x^2 + x + 1))
+    let divisor = (x.pow(1) - 1) * (x.pow(1) + 1) * (x.pow(2) + x.pow(1) + 1);
+    let phi = (x.pow(6) - 1).try_div_exact(&divisor).expect("division is exact");
+    same_coef(phi.clone(), vec![1, -1, 1]);
+    assert_eq!(phi.degree(), Some(2));
+
+    // unreduced operands
+    let a = x.pow(3) * 4 + x.pow(1) - x.pow(3) * 2 + 1 - 1;
+    let b = x.pow(2) * 2 + 1;
+    same_coef(a.try_div_exact(&b).expect("2x^3 + x = x * (2x^2 + 1)"), vec![0, 1]);
+
+    // nonzero remainder
+    let a = Poly::from_coefs(vec![1, 0, 1]);
+    assert!(a.try_div_exact(&Poly::from_coefs(vec![1, 1])).is_none());
+    assert!(Poly::from_coefs(vec![1]).try_div_exact(&Poly::from_coefs(vec![0, 1])).is_none());
+    // quotient coef is not an integer
+    assert!(Poly::from_coefs(vec![3, 3]).try_div_exact(&Poly::from_coefs(vec![2])).is_none());
+    same_coef(
+        Poly::from_coefs(vec![4, 6]).try_div_exact(&Poly::from_coefs(vec![2])).unwrap(),
+        vec![2, 3],
+    );
+
+    assert!(a.try_div_exact(&Poly::default()).is_none());
+    assert!(Poly::default().try_div_exact(&a).unwrap().is_empty());
+}
+
+#[test]
+fn test_dense_slice() {
+    let data = [0, 3, 0, 0, -1, 0];
+    let p = Poly::from_dense_slice(&data);
+    assert_eq!(p.len(), 2);
+    same_coef(p.clone(), vec![0, 3, 0, 0, -1]);
+    assert_eq!(p.as_dense_slice_with(6), data.to_vec());
+    assert_eq!(p.as_dense_slice_with(5), vec![0, 3, 0, 0, -1]);
+    assert_eq!(Poly::from_dense_slice(&p.as_dense_slice_with(10)), p);
+
+    // members are summed up, cancelled high power fits
+    let x = X::<TestType>::default();
+    let p = x.pow(1) * 2 + x.pow(9) + 4 - x.pow(9) + x.pow(1);
+    assert_eq!(p.as_dense_slice_with(3), vec![4, 3, 0]);
+
+    assert!(Poly::from_dense_slice(&[]).is_empty());
+    assert!(Poly::from_dense_slice(&[0, 0]).is_empty());
+    assert_eq!(Poly::default().as_dense_slice_with(0), Vec::<TestType>::new());
+    assert_eq!(Poly::default().as_dense_slice_with(2), vec![0, 0]);
+}
+
+#[test]
+#[should_panic(expected = "polynomial of degree 2 doesn't fit into 2 coefs")]
+fn test_dense_slice_too_short() {
+    let _ = Poly::from_coefs(vec![1, 0, 1]).as_dense_slice_with(2);
+}
+
+#[test]
+fn test_eval_range() {
+    let x = X::<TestType>::default();
+    let p = x.pow(4) * 2 - x.pow(3) * 7 + x.pow(1) * 5 - 3 + x.pow(3);
+    let values: Vec<TestType> = p.eval_range(-10..=10).collect();
+    let expected: Vec<TestType> = (-10..=10).map(|point| p.substitude(point)).collect();
+    assert_eq!(values, expected);
+
+    // range shorter than degree
+    assert_eq!(p.eval_range(2..=2).collect::<Vec<_>>(), vec![p.substitude(2)]);
+    assert_eq!(p.eval_range(std::ops::RangeInclusive::new(3, 2)).count(), 0);
+
+    assert_eq!(Poly::new_const(7).eval_range(0..=3).collect::<Vec<_>>(), vec![7; 4]);
+    assert_eq!(Poly::default().eval_range(0..=2).collect::<Vec<_>>(), vec![0; 3]);
+    let line = Poly::from_coefs(vec![1, -2]);
+    assert_eq!(line.eval_range(0..=3).collect::<Vec<_>>(), vec![1, -1, -3, -5]);
+
+    // f64 points step by one
+    let p = Polynomial::<f64>::from_coefs(vec![0.5, 0.0, 0.25]);
+    let values: Vec<f64> = p.eval_range(0.5..=3.0).collect();
+    assert_eq!(values, vec![0.5625, 1.0625, 2.0625]);
+
+    // range may end at the maximal value of type
+    let line = Polynomial::<u8>::from_coefs(vec![0, 1]);
+    assert_eq!(line.eval_range(253..=255).collect::<Vec<_>>(), vec![253, 254, 255]);
+    assert_eq!(Poly::new_const(1).eval_range(TestType::MAX..=TestType::MAX).count(), 1);
+}
+
+#[test]
+fn test_fold_terms() {
+    let x = X::<TestType>::default();
+    let p = x.pow(3) * 2 + x.pow(1) * 5 - 4 + x.pow(3) * 3;
+    assert_eq!(p.fold_terms(0, |acc, _, coef| acc + coef), p.substitude(1));
+    assert_eq!(p.fold_terms(0, |acc, power, _| acc.max(power)), 3);
+    assert_eq!(p.clone().reduce().fold_terms(0, |acc, power, _| acc + power % 2), 2);
+    assert_eq!(Poly::default().fold_terms(7, |_, _, _| 0), 7);
+
+    let mut terms = Vec::new();
+    p.clone().reduce().visit_terms(|power, coef| terms.push((power, *coef)));
+    assert_eq!(terms, vec![(0, -4), (1, 5), (3, 5)]);
+    let mut count = 0;
+    p.visit_terms(|_, _| count += 1);
+    assert_eq!(count, p.len());
+}
+
+#[test]
+fn test_retain_terms() {
+    let x = X::<TestType>::default();
+    let mut p = Poly::from_coefs(vec![1, -2, 3, -4, 5, -6]);
+    p.retain_terms(|power, _| power % 2 == 0);
+    same_coef(p.clone(), vec![1, 0, 3, 0, 5]);
+    p.retain_terms(|_, coef| *coef > 2);
+    same_coef(p.clone(), vec![0, 0, 3, 0, 5]);
+    assert_eq!(p.get(4), Some(&5));
+    p.retain_terms(|_, _| false);
+    assert!(p.is_empty());
+
+    // members are not summed up
+    let mut p = x.pow(2) * 3 + x.pow(1) - x.pow(2) * 3;
+    p.retain_terms(|power, _| power == 2);
+    assert_eq!(p.len(), 2);
+    assert_eq!(p.reduce(), Poly::default());
+}
+
+#[test]
+fn test_dump() {
+    let x = X::<TestType>::default();
+    let p = x.pow(3) * 2 + x.pow(1) + 5 + x.pow(3) - x.pow(1);
+    let dump = p.dump();
+    assert_eq!(dump.len, 5);
+    assert_eq!(dump.max_power, Some(3));
+    assert_eq!(dump.duplicate_powers, 2);
+    assert!(!dump.sorted);
+    assert_eq!(dump.auto_reduce_threshold, None);
+    assert!(dump.capacity >= 5);
+    assert!(dump.memory >= 5 * std::mem::size_of::<TestType>());
+
+    let reduced = p.clone().reduce().with_auto_reduce(16).dump();
+    assert_eq!((reduced.len, reduced.duplicate_powers), (2, 0));
+    assert!(reduced.sorted);
+    assert_eq!(reduced.auto_reduce_threshold, Some(16));
+    assert_eq!(
+        reduced.to_string(),
+        format!("2 members (capacity {}), max power 3, 0 duplicate powers, sorted, \
+            auto-reduce over 16, {} bytes", reduced.capacity, reduced.memory)
+    );
+    let empty = Poly::default().dump();
+    assert_eq!((empty.len, empty.max_power, empty.duplicate_powers), (0, None, 0));
+
+    // alternate debug shows terms by power, others keep order
+    let debug = format!("{:#?}", p);
+    let pos = |s: &str| debug.find(s).unwrap();
+    assert!(pos("0,\n            5,") < pos("1,\n            1,"));
+    assert!(pos("1,\n            -1,") < pos("3,\n            2,"));
+    assert!(pos("3,\n            2,") < pos("3,\n            1,"));
+    assert!(debug.contains("duplicate_powers: 2"));
+    assert!(format!("{:?}", p).starts_with("Polynomial { members: "));
+}
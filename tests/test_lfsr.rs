@@ -0,0 +1,61 @@
+use polylib::applications::lfsr::{berlekamp_massey, Lfsr};
+use polylib::custom_types::Gf2Poly;
+
+#[test]
+fn test_next_bit() {
+    // x^3 + x^2 + 1: s(n) = s(n-2) + s(n-3)
+    let mut lfsr = Lfsr::new(&Gf2Poly::from_powers(&[3, 2, 0]), 0b001);
+    let bits: Vec<u8> = (0..10).map(|_| lfsr.next_bit() as u8).collect();
+    assert_eq!(bits, vec![1, 0, 0, 1, 0, 1, 1, 1, 0, 0]);
+    assert_eq!(lfsr.degree(), 3);
+    assert_eq!(lfsr.feedback(), Gf2Poly::from_powers(&[3, 2, 0]));
+
+    // 64-bit register: x^64 + x^63 + x^61 + x^60 + 1
+    let mut lfsr = Lfsr::new(&Gf2Poly::from_powers(&[64, 63, 61, 60, 0]), u64::MAX);
+    let first: Vec<bool> = lfsr.by_ref().take(64).collect();
+    assert!(first.iter().all(|&b| b));
+    assert_eq!(lfsr.period(), None);
+}
+
+#[test]
+fn test_period() {
+    // primitive polynomials give maximal period 2^L - 1
+    for powers in [&[2, 1, 0][..], &[5, 2, 0], &[8, 4, 3, 2, 0], &[16, 14, 13, 11, 0]] {
+        let lfsr = Lfsr::new(&Gf2Poly::from_powers(powers), 1);
+        assert_eq!(lfsr.period(), Some((1 << powers[0]) - 1));
+    }
+    // x^4 + x^3 + x^2 + x + 1 divides x^5 + 1
+    let lfsr = Lfsr::new(&Gf2Poly::from_powers(&[4, 3, 2, 1, 0]), 0b0110);
+    assert_eq!(lfsr.period(), Some(5));
+    // zero state stays zero
+    assert_eq!(Lfsr::new(&Gf2Poly::from_powers(&[5, 2, 0]), 0).period(), Some(1));
+}
+
+#[test]
+#[should_panic]
+fn test_no_constant_term() {
+    Lfsr::new(&Gf2Poly::from_powers(&[3, 1]), 1);
+}
+
+#[test]
+fn test_berlekamp_massey() {
+    let feedback = Gf2Poly::from_powers(&[16, 14, 13, 11, 0]);
+    let bits: Vec<bool> = Lfsr::new(&feedback, 0xACE1).take(32).collect();
+    assert_eq!(berlekamp_massey(&bits), (feedback.clone(), 16));
+    // longer sequence gives the same register
+    let bits: Vec<bool> = Lfsr::new(&feedback, 0xACE1).take(100).collect();
+    assert_eq!(berlekamp_massey(&bits), (feedback, 16));
+
+    // found register generates the sequence
+    let bits: Vec<bool> = [1, 1, 0, 1, 0, 0, 0, 1, 1, 0].iter().map(|&b| b == 1).collect();
+    let (poly, len) = berlekamp_massey(&bits);
+    for n in len..bits.len() {
+        let next = (1..=len).fold(false, |acc, i| acc ^ (poly.get(i) && bits[n - i]));
+        assert_eq!(next, bits[n]);
+    }
+
+    // single one after zeros needs register as long as sequence
+    let bits = [false, false, false, true];
+    assert_eq!(berlekamp_massey(&bits), (Gf2Poly::from_powers(&[4, 0]), 4));
+    assert_eq!(berlekamp_massey(&[]), (Gf2Poly::from_u64(1), 0));
+}
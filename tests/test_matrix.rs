@@ -1,9 +1,10 @@
-use polylib::custom_types::Matrix;
-use polylib::polynom::Polynomial;
+use polylib::custom_types::{DynMatrix, Matrix, Zn, Zp};
+use polylib::polynom::{Polynomial, PreparedPoint};
 use polylib::polynom::X;
+use polylib::random::Xorshift64;
 
 mod common;
-use common::substitude_check;
+use common::{same_coef, substitude_check};
 
 type Poly = Polynomial<i32, X<i32>>;
 
@@ -65,3 +66,157 @@ fn test_nilpotent_matrix() {
 
     substitude_check(poly, input, expect);
 }
+
+#[test]
+fn test_characteristic_matrix() {
+    type Type = Matrix<3, 3, i32>;
+    let a = Type::from_data(vec![2, -1, 0, 4, 3, 1, -2, 5, 1]);
+    let m = a.characteristic_matrix();
+
+    let det = m.determinant();
+    let expect = a.characteristic_polynomial().reduce();
+    for i in 0..=3 {
+        assert_eq!(det.clone().reduce().coef(i), expect.coef(i));
+    }
+    assert_eq!(m.substitude(2), Type::eye(2) - a);
+    assert_eq!(
+        m.degree_matrix(),
+        Matrix::from_rows([
+            [Some(1), Some(0), None],
+            [Some(0), Some(1), Some(0)],
+            [Some(0), Some(0), Some(1)],
+        ])
+    );
+}
+
+#[test]
+fn test_smith_normal_form() {
+    type F = Zp<101>;
+    let f = |rows: [[i32; 3]; 3]| Matrix::from_rows(rows.map(|row| row.map(F::from)));
+
+    // diag(1, 1, 2): invariant factors 1, x - 1, (x - 1)(x - 2)
+    let s = f([[1, 0, 0], [0, 1, 0], [0, 0, 2]]).characteristic_matrix().smith_normal_form();
+    same_coef(s[(0, 0)].clone(), vec![F::from(1)]);
+    same_coef(s[(1, 1)].clone(), vec![F::from(-1), F::from(1)]);
+    same_coef(s[(2, 2)].clone(), vec![F::from(2), F::from(-3), F::from(1)]);
+    assert_eq!(
+        s.degree_matrix(),
+        Matrix::from_rows([[Some(0), None, None], [None, Some(1), None], [None, None, Some(2)]])
+    );
+
+    // companion matrix of x^3 - 2x - 5 is cyclic: invariant factors 1, 1, x^3 - 2x - 5
+    let a = f([[0, 0, 5], [1, 0, 2], [0, 1, 0]]);
+    let s = a.characteristic_matrix().smith_normal_form();
+    same_coef(s[(0, 0)].clone(), vec![F::from(1)]);
+    same_coef(s[(1, 1)].clone(), vec![F::from(1)]);
+    same_coef(s[(2, 2)].clone(), vec![F::from(-5), F::from(-2), F::from(0), F::from(1)]);
+
+    // rank 1 matrix of polynomials
+    type P = Polynomial<F>;
+    let x = P::from_coefs(vec![F::from(0), F::from(1)]);
+    let sq = x.clone() * x.clone();
+    let m = Matrix::<2, 2, P>::from_data(vec![x.clone(), sq.clone(), x, sq]);
+    let s = m.smith_normal_form();
+    same_coef(s[(0, 0)].clone(), vec![F::from(0), F::from(1)]);
+    assert_eq!(s.degree_matrix(), Matrix::from_rows([[Some(1), None], [None, None]]));
+}
+
+#[test]
+fn test_determinant_by_interpolation() {
+    type F = Zp<101>;
+    type P = Polynomial<F>;
+    let poly = |coefs: &[i32]| P::from_coefs(coefs.iter().map(|&c| F::from(c)).collect());
+
+    let m = Matrix::<3, 3, P>::from_rows([
+        [poly(&[1, 2]), poly(&[0, 0, 3]), poly(&[5])],
+        [poly(&[]), poly(&[-1, 1]), poly(&[2, 0, 0, 1])],
+        [poly(&[4, 1, 1]), poly(&[7]), poly(&[0, -3])],
+    ]);
+    let det = m.determinant_by_interpolation();
+    let expect = m.determinant().reduce();
+    assert_eq!(det.degree(), expect.degree());
+    for i in 0..=6 {
+        assert_eq!(det.coef(i), expect.coef(i));
+    }
+
+    let rows = [[2, -1, 0], [4, 3, 1], [-2, 5, 1]];
+    let a = Matrix::<3, 3, F>::from_rows(rows.map(|row| row.map(F::from)));
+    let det = a.characteristic_matrix().determinant_by_interpolation();
+    let expect = a.characteristic_polynomial();
+    for i in 0..=3 {
+        assert_eq!(det.coef(i), expect.coef(i));
+    }
+
+    // zero column
+    let m = Matrix::<2, 2, P>::from_rows([[poly(&[1, 1]), poly(&[])], [poly(&[2]), poly(&[])]]);
+    assert!(m.determinant_by_interpolation().is_empty());
+}
+
+#[test]
+#[should_panic(expected = "field has too few elements")]
+fn test_determinant_by_interpolation_small_field() {
+    type F = Zp<2>;
+    let a = Matrix::<3, 3, F>::eye(F::new(1));
+    a.characteristic_matrix().determinant_by_interpolation();
+}
+
+#[test]
+fn test_toeplitz_hankel() {
+    type P = Polynomial<i64>;
+    let p = P::from_coefs(vec![3, 0, -2, 7]);
+    let q = P::from_coefs(vec![-1, 4, 0, 5, 2]);
+    let m = DynMatrix::toeplitz(&p, 5);
+    assert_eq!(m.shape(), (8, 5));
+    let column = DynMatrix::from_data(5, 1, (0..5).map(|i| q.coef(i)).collect());
+    let product = (p.clone() * q).reduce();
+    let expect: Vec<i64> = (0..8).map(|i| product.coef(i)).collect();
+    assert_eq!((m * column).get_data(), &expect);
+
+    let zero = DynMatrix::toeplitz(&(p.clone() - p.clone()), 2);
+    assert_eq!(zero, DynMatrix::full(2, 2, 0));
+
+    let h = DynMatrix::hankel(&p, 3);
+    assert_eq!(h.clone().transpose(), h);
+    assert_eq!(h.get_data(), &vec![3, 0, -2, 0, -2, 7, -2, 7, 0]);
+    assert_eq!(DynMatrix::hankel(&p, 0).shape(), (0, 0));
+}
+
+#[test]
+fn test_compile() {
+    type Type = Matrix<2, 2, i32>;
+    let p = Poly::from_coefs(vec![1, 0, 0, 2, 0, -1]);
+    let f = p.compile::<Type, Type>();
+    let points = [
+        Type::from_data(vec![1, 1, 0, 1]),
+        Type::from_data(vec![0, 1, -1, 0]),
+        Type::from_data(vec![2, 0, 3, -1]),
+    ];
+    for m in points {
+        let expected: Type = p.substitude(m.clone());
+        assert_eq!(f(m), expected);
+    }
+}
+
+#[test]
+fn test_prepared_point() {
+    type Inner = Zn<101>;
+    type Type = Matrix<3, 3, Inner>;
+    let m = Type::from_data((1..=9).map(|i| Inner::new(i * i)).collect());
+    let mut point = PreparedPoint::new(m.clone());
+    let mut rng = Xorshift64::new(5);
+    for round in 0..20u32 {
+        let x = X::<Inner>::default();
+        let poly = x.pow(round * 37 % 500) * Inner::new(round + 1)
+            + x.pow(1000 + round)
+            + x.pow(round % 3)
+            + Polynomial::<Inner>::random(4, &mut rng);
+        let expected: Type = poly.substitude(m.clone());
+        assert_eq!(poly.substitude_prepared::<Type, Type>(&mut point), expected);
+    }
+    assert_eq!(point.point(), &m);
+    // cached powers are reused
+    let cached = point.cached();
+    let poly = X::<Inner>::default().pow(1005) + Inner::new(1);
+    let _: Type = poly.substitude_prepared(&mut point);
+    assert_eq!(point.cached(), cached);
+}
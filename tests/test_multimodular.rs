@@ -0,0 +1,65 @@
+use polylib::polynom::{multimodular, Polynomial, X};
+use polylib::random::Xorshift64;
+
+// schoolbook product in i128
+fn naive(a: &[i64], b: &[i64]) -> Vec<i128> {
+    let mut ans = vec![0i128; a.len() + b.len() - 1];
+    for (i, x) in a.iter().enumerate() {
+        for (j, y) in b.iter().enumerate() {
+            ans[i + j] += *x as i128 * *y as i128;
+        }
+    }
+    ans
+}
+
+fn check(a: &[i64], b: &[i64]) {
+    let product = multimodular::mul(
+        &Polynomial::from_coefs(a.to_vec()),
+        &Polynomial::from_coefs(b.to_vec()),
+    );
+    let expected = naive(a, b);
+    assert_eq!(product, Polynomial::from_coefs(expected.clone()));
+    assert_eq!(product.len(), expected.iter().filter(|c| **c != 0).count());
+}
+
+#[test]
+fn test_multimodular_mul() {
+    // small coefs, one prime
+    check(&[1, 2, 3], &[-1, 0, 4, 5]);
+    check(&[-3], &[7]);
+    check(&[0, 0, 1], &[1, 1]);
+
+    let mut rng = Xorshift64::new(17);
+    // coefs bound for 1, 2, 3 and 4 primes
+    for bits in [10, 25, 40, 52] {
+        for len in [1, 7, 64, 300] {
+            let mut random = |len: usize| -> Vec<i64> {
+                (0..len)
+                    .map(|_| (rng.next_u64() >> (64 - bits)) as i64 - (1 << (bits - 1)))
+                    .collect()
+            };
+            let a = random(len);
+            let b = random(len / 2 + 1);
+            check(&a, &b);
+        }
+    }
+
+    // extreme coefs within bound
+    let max = (1i64 << 56) - 1;
+    check(&[max, -max, max], &[-max, -max, max, max]);
+
+    // unreduced polynomials and zero product
+    let x = X::<i64>::default();
+    let a = x.pow(3) * 2 + x.pow(1) - x.pow(3) + 4;
+    let b = x.pow(2) - x.pow(2) + x.pow(1) * 3;
+    let expected = Polynomial::<i128>::from_coefs(vec![0, 12, 3, 0, 3]);
+    assert_eq!(multimodular::mul(&a, &b), expected);
+    assert!(multimodular::mul(&a, &(x.pow(5) - x.pow(5))).is_empty());
+}
+
+#[test]
+#[should_panic(expected = "coefs of product don't fit into 2^114")]
+fn test_multimodular_overflow() {
+    let a = Polynomial::<i64>::from_coefs(vec![i64::MIN, 1]);
+    let _ = multimodular::mul(&a, &a);
+}
@@ -0,0 +1,116 @@
+use polylib::custom_types::Zn;
+use polylib::polynom::Polynomial;
+use polylib::polynom::{X, Y};
+use polylib::{One, Zero};
+
+mod common;
+use common::same_coef;
+
+type Inner = Polynomial<i32, Y<i32>>;
+type Poly = Polynomial<Inner, X<Inner>>;
+
+// value at (x, y): x is replaced with constant inner polynomial
+fn value(p: &Poly, x: i32, y: i32) -> i32 {
+    let inner: Inner = p.substitude(Inner::new_const(x));
+    inner.substitude(y)
+}
+
+#[test]
+fn test_derivative() {
+    let x = X::<i32>::default();
+    let p = x.pow(4) - x.pow(2) * 3 + x.pow(1) * 5 - 2;
+    same_coef(p.derivative(), vec![5, -6, 0, 4]);
+    // unreduced members
+    let p = x.pow(2) + x.pow(2) - x.pow(1);
+    same_coef(p.derivative(), vec![-1, 4]);
+    assert!(Polynomial::<i32>::new_const(3).derivative().is_empty());
+
+    // 2 * 2x^2 is zero modulo 4
+    let p = Polynomial::<Zn<4>>::from_coefs(vec![Zn::new(1), Zn::new(3), Zn::new(2)]);
+    let d = p.derivative();
+    assert_eq!(d.len(), 1);
+    assert_eq!(d.coef(0), Zn::new(3));
+}
+
+#[test]
+fn test_partial_derivatives() {
+    let x = X::<Inner>::default();
+    let y = Y::<i32>::default();
+    // x^2 y^3 + 2xy - y + 4
+    let p = x.pow(2) * (y.pow(3) + 0) + x.pow(1) * (y.pow(1) * 2) + Inner::new_const(4)
+        - y.pow(1);
+
+    // 2x y^3 + 2y
+    let dx = p.d_dx();
+    for (a, b) in [(0, 0), (1, 2), (-2, 3), (3, -1)] {
+        assert_eq!(value(&dx, a, b), 2 * a * b.pow(3) + 2 * b);
+    }
+    // 3x^2 y^2 + 2x - 1
+    let dy = p.d_dy();
+    for (a, b) in [(0, 0), (1, 2), (-2, 3), (3, -1)] {
+        assert_eq!(value(&dy, a, b), 3 * a * a * b * b + 2 * a - 1);
+    }
+    // mixed derivatives are equal: 6x y^2 + 2
+    let dxy = p.d_dx().d_dy();
+    let dyx = p.d_dy().d_dx();
+    for (a, b) in [(0, 0), (1, 2), (-2, 3), (3, -1)] {
+        assert_eq!(value(&dxy, a, b), 6 * a * b * b + 2);
+        assert_eq!(value(&dyx, a, b), 6 * a * b * b + 2);
+    }
+
+    // members, that don't depend on variable, vanish
+    assert!(Poly::new_const(y.pow(2) + 1).d_dx().is_empty());
+    assert!((x.pow(3) * Inner::new_const(5)).d_dy().is_empty());
+}
+
+#[test]
+fn test_partial_substitude() {
+    let x = X::<Inner>::default();
+    let y = Y::<i32>::default();
+    // x^2 y^3 + 2xy - y + 4
+    let p = x.pow(2) * (y.pow(3) + 0) + x.pow(1) * (y.pow(1) * 2) + Inner::new_const(4)
+        - y.pow(1);
+    for a in -3..4 {
+        let q = p.partial_substitude(a);
+        for b in -3..4 {
+            assert_eq!(q.substitude::<i32, i32>(b), value(&p, a, b));
+        }
+    }
+    same_coef(p.partial_substitude(2), vec![4, 3, 0, 4]);
+    same_coef(p.partial_substitude(0), vec![4, -1]);
+
+    // derivative by y commutes with substitution of x
+    same_coef(p.d_dy().partial_substitude(-1), vec![-3, 0, 3]);
+    same_coef(p.partial_substitude(-1).derivative(), vec![-3, 0, 3]);
+
+    assert!(Poly::default().partial_substitude(5).is_empty());
+    // auto-reduce policy of inner polynomials is kept
+    let p = Poly::from_terms(vec![(1, (y.pow(1) + 1).with_auto_reduce(2)), (2, y.pow(1) + 1)]);
+    let q = p.partial_substitude(1);
+    assert_eq!(q.auto_reduce_threshold(), Some(2));
+    assert!(q.len() <= 2);
+    same_coef(q, vec![2, 2]);
+}
+
+#[test]
+fn test_display() {
+    let x = X::<Inner>::default();
+    let inner = |coefs: Vec<i32>| Inner::from_coefs(coefs);
+    // (2 + y) + x + 3y x^2
+    let p = Poly::new_const(inner(vec![2, 1])) + x.pow(1) + x.pow(2) * inner(vec![0, 3]);
+    assert_eq!(p.to_string(), "2 + y + x + 3yx^2");
+    let p = x.pow(1) * inner(vec![1, 1]);
+    assert_eq!(p.to_string(), "(1 + y)x");
+    assert_eq!(Poly::default().to_string(), "0");
+    assert_eq!(Poly::new_const(inner(vec![])).to_string(), "0");
+
+    // unsummed members can't be checked, so they are written sorted, but not summed
+    let y = Y::<i32>::default();
+    let one = y.pow(1) + 1 - y.pow(1);
+    assert_eq!(one.try_is_one(), None);
+    assert_eq!(one.clone().reduce().try_is_one(), Some(true));
+    assert_eq!((x.pow(1) * one.clone()).to_string(), "(1 + y + -1y)x");
+    assert_eq!((one.clone() - one).try_is_zero(), None);
+    assert_eq!(inner(vec![0, 0, 5]).try_is_zero(), Some(false));
+    assert_eq!(inner(vec![]).try_is_zero(), Some(true));
+}
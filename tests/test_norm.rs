@@ -0,0 +1,72 @@
+use polylib::custom_types::{BigInt, Fraction};
+use polylib::polynom::{Polynomial, X};
+
+type Poly = Polynomial<i64, X<i64>>;
+
+#[test]
+fn test_norms() {
+    let p = Poly::from_coefs(vec![3, 0, -4, 1, -12]);
+    assert_eq!(p.l1_norm(), 20);
+    assert_eq!(p.l2_norm_sqr(), 9 + 16 + 1 + 144);
+    assert_eq!(p.max_norm(), 12);
+    assert_eq!(p.height(), 12);
+
+    // norms are taken of reduced polynomial
+    let x = X::<i64>::default();
+    let p = x.pow(2) * 5 - x.pow(1) * 3 - x.pow(2) * 7 + x.pow(1) * 3 - 1i64;
+    assert_eq!(p.l1_norm(), 3);
+    assert_eq!(p.l2_norm_sqr(), 5);
+    assert_eq!(p.max_norm(), 2);
+
+    let zero = Poly::default();
+    assert_eq!(zero.l1_norm(), 0);
+    assert_eq!(zero.l2_norm_sqr(), 0);
+    assert_eq!(zero.max_norm(), 0);
+
+    let p = Polynomial::<f64>::from_coefs(vec![-0.5, 0.25, -1.25]);
+    assert_eq!(p.l1_norm(), 2.0);
+    assert_eq!(p.l2_norm_sqr(), 0.25 + 0.0625 + 1.5625);
+    assert_eq!(p.height(), 1.25);
+
+    let q = |num, den| Fraction::<i64>::new(num, den);
+    let p = Polynomial::<Fraction<i64>>::from_coefs(vec![q(-1, 2), q(1, 3)]);
+    assert_eq!(p.l1_norm(), q(5, 6));
+    assert_eq!(p.l2_norm_sqr(), q(13, 36));
+    assert_eq!(p.max_norm(), q(1, 2));
+
+    let big = BigInt::from(1i64 << 40);
+    let p = Polynomial::<BigInt>::from_coefs(vec![-big.clone(), big.clone()]);
+    assert_eq!(p.l2_norm_sqr(), BigInt::from(1i128 << 81));
+    assert_eq!(p.max_norm(), big);
+}
+
+#[test]
+fn test_factor_coefficient_bound() {
+    // 3 + 4x: ||f||_2 = 5
+    assert_eq!(Poly::from_coefs(vec![3, 4]).factor_coefficient_bound(), BigInt::from(10));
+    // ||f||_2 = sqrt(3) is rounded up
+    assert_eq!(Poly::from_coefs(vec![1, 1, 1]).factor_coefficient_bound(), BigInt::from(8));
+    assert_eq!(Poly::new_const(-7).factor_coefficient_bound(), BigInt::from(7));
+    assert_eq!(Poly::default().factor_coefficient_bound(), BigInt::from(0));
+
+    // coefs of factors are within bound
+    let x = X::<i64>::default();
+    let factors = [
+        x.pow(2) * 3 - x.pow(1) * 17 + 40,
+        x.pow(3) - x.pow(1) * 25 - 11i64,
+        x.pow(1) * 2 + 9,
+    ];
+    let p: Poly = factors.iter().cloned().product();
+    let bound = p.factor_coefficient_bound();
+    for factor in &factors {
+        assert!(BigInt::from(factor.max_norm()) <= bound);
+    }
+    assert!(BigInt::from(p.max_norm()) <= bound);
+
+    // no overflow for huge coefs and degree
+    let p = Poly::from_coefs(vec![i64::MAX; 100]);
+    let norm = BigInt::from(i64::MAX as i128 * 10);
+    assert_eq!(p.factor_coefficient_bound(), BigInt::from(2).pow(99) * norm);
+    let p = Polynomial::<BigInt>::from_coefs(vec![BigInt::from(5), BigInt::from(12)]);
+    assert_eq!(p.factor_coefficient_bound(), BigInt::from(26));
+}
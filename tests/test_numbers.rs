@@ -0,0 +1,87 @@
+use polylib::custom_types::{Fraction, Zn, Zp};
+use polylib::polynom::{numbers, Polynomial};
+
+type Q = Fraction<i64>;
+
+#[test]
+fn test_stirling() {
+    // row 5 of both triangles
+    let first: Vec<i64> = (0..=5).map(|k| numbers::stirling1(5, k)).collect();
+    assert_eq!(first, vec![0, 24, -50, 35, -10, 1]);
+    let second: Vec<i64> = (0..=5).map(|k| numbers::stirling2(5, k)).collect();
+    assert_eq!(second, vec![0, 1, 15, 25, 10, 1]);
+
+    assert_eq!(numbers::stirling1::<i64>(0, 0), 1);
+    assert_eq!(numbers::stirling2::<i64>(0, 0), 1);
+    assert_eq!(numbers::stirling2::<i64>(3, 7), 0);
+
+    // inverse matrices: sum of s(n, j) S(j, k) is 1 iff n = k
+    for n in 0..6 {
+        for k in 0..6 {
+            let sum: i64 = (0..6)
+                .map(|j| numbers::stirling1::<i64>(n, j) * numbers::stirling2::<i64>(j, k))
+                .sum();
+            assert_eq!(sum, (n == k) as i64);
+        }
+    }
+}
+
+#[test]
+fn test_bell() {
+    assert_eq!(numbers::bell::<i64>(10), 115975);
+    // sum of row of the second kind
+    let row = numbers::stirling2_row::<i64>(8);
+    let sum: i64 = (0..=8).map(|k| row.coef(k)).sum();
+    assert_eq!(sum, numbers::bell::<i64>(8));
+    // modulo prime
+    assert_eq!(numbers::bell::<Zp<7>>(10), Zp::from(115975 % 7));
+}
+
+#[test]
+fn test_bernoulli() {
+    assert_eq!(numbers::bernoulli::<Q>(0), Q::from(1));
+    assert_eq!(numbers::bernoulli::<Q>(1), Q::new(-1, 2));
+    assert_eq!(numbers::bernoulli::<Q>(8), Q::new(-1, 30));
+    assert_eq!(numbers::bernoulli::<Q>(10), Q::new(5, 66));
+    assert_eq!(numbers::bernoulli::<Q>(12), Q::new(-691, 2730));
+    for n in [3, 5, 7, 9, 11] {
+        assert_eq!(numbers::bernoulli::<Q>(n), Q::from(0));
+    }
+}
+
+#[test]
+fn test_binomial() {
+    let row: Vec<i64> = (0..=6).map(|k| numbers::binomial(6, k)).collect();
+    assert_eq!(row, vec![1, 6, 15, 20, 15, 6, 1]);
+    assert_eq!(numbers::binomial::<i64>(0, 0), 1);
+    assert_eq!(numbers::binomial::<i64>(60, 30), 118264581564861424);
+    // composite modulus
+    assert_eq!(numbers::binomial::<Zn<10>>(8, 4), Zn::new(0)); // 70
+    assert_eq!(numbers::binomial::<Zn<10>>(9, 4), Zn::new(6)); // 126
+
+    // inverse-based computation agrees with Pascal's triangle
+    for n in 0..30 {
+        for k in 0..=n + 1 {
+            assert_eq!(Zp::<7>::binomial(n as u64, k as u64), numbers::binomial(n, k));
+            assert_eq!(Zp::<31>::binomial(n as u64, k as u64), numbers::binomial(n, k));
+        }
+    }
+    // C(p^2, p) = p (mod p^2) and 0 (mod p)
+    assert_eq!(Zp::<1000003>::binomial(1000003 * 1000003, 1000003), Zp::new(0));
+}
+
+#[test]
+fn test_falling_factorial() {
+    assert_eq!(numbers::falling_factorial(7i64, 0), 1);
+    assert_eq!(numbers::falling_factorial(7i64, 7), 5040);
+    assert_eq!(numbers::falling_factorial(-2i64, 3), -24);
+    // x(x - 1)...(x - k + 1) / k! = C(x, k)
+    for k in 0..6 {
+        let value = numbers::falling_factorial(Zp::<101>::new(20), k);
+        let factorial = numbers::falling_factorial(Zp::<101>::new(k), k);
+        assert_eq!(value / factorial, Zp::binomial(20, k as u64));
+    }
+    // polynomial argument gives the first kind row
+    let x = Polynomial::<i64>::from_coefs(vec![0, 1]);
+    assert_eq!(numbers::falling_factorial(x, 4), numbers::stirling1_row(4));
+}
@@ -0,0 +1,54 @@
+use polylib::custom_types::{Fraction, Zp};
+use polylib::polynom::ops::{Differentiate, Integrate, Operator, Scale, Shift, Truncate};
+use polylib::polynom::Polynomial;
+
+type Q = Fraction<i64>;
+
+fn poly(coefs: &[i64]) -> Polynomial<i64> {
+    Polynomial::from_coefs(coefs.to_vec())
+}
+
+#[test]
+fn test_operators() {
+    let p = poly(&[1, -2, 0, 4]); // 4x^3 - 2x + 1
+    assert_eq!(Differentiate.apply(&p), poly(&[-2, 0, 12]));
+    assert_eq!(Shift(2).apply(&p), poly(&[29, 46, 24, 4]));
+    assert_eq!(Scale(-1).apply(&p), poly(&[1, 2, 0, -4]));
+    assert_eq!(Truncate(0).apply(&p), poly(&[1]));
+    assert_eq!(Truncate(10).apply(&p), p);
+
+    // shifted values are values at shifted points
+    for x in -3..=3i64 {
+        let value: i64 = Shift(5).apply(&p).substitude(x);
+        assert_eq!(value, p.substitude(x + 5));
+    }
+
+    let empty = poly(&[]);
+    assert_eq!(Shift(3).apply(&empty), empty);
+    assert_eq!(Scale(0).apply(&p), poly(&[1]));
+
+    let q = Polynomial::<Q>::from_coefs(vec![Q::from(3), Q::from(0), Q::from(2)]);
+    let expected = vec![Q::from(0), Q::from(3), Q::from(0), Q::new(2, 3)];
+    assert_eq!(Integrate.apply(&q), Polynomial::from_coefs(expected));
+}
+
+#[test]
+fn test_pipeline() {
+    // shift, differentiate twice, truncate
+    let pipeline = Shift(1).then(Differentiate).then(Differentiate).then(Truncate(1));
+    let p = poly(&[0, 0, 0, 0, 1]); // x^4
+    // 12(x + 1)^2 = 12 + 24x + 12x^2
+    assert_eq!(pipeline.apply(&p), poly(&[12, 24]));
+    // pipeline is stored and reused
+    assert_eq!(pipeline.apply(&poly(&[7, 7])), poly(&[]));
+
+    // scale and shift do not commute: p(2x + 2) and p(2x + 1)
+    let p = poly(&[0, 1, 1]);
+    assert_eq!(Scale(2).then(Shift(1)).apply(&p), poly(&[6, 10, 4]));
+    assert_eq!(Shift(1).then(Scale(2)).apply(&p), poly(&[2, 6, 4]));
+
+    // integral of derivative loses constant term
+    let ops = Differentiate.then(Integrate);
+    let p = Polynomial::<Zp<5>>::from_coefs(vec![1.into(), 2.into(), 3.into()]);
+    assert_eq!(ops.apply(&p), Polynomial::from_coefs(vec![0.into(), 2.into(), 3.into()]));
+}
@@ -0,0 +1,65 @@
+use polylib::custom_types::{Matrix, Zn};
+use polylib::polynom::{Polynomial, X};
+
+mod common;
+use common::same_coef;
+
+type Poly = Polynomial<i32, X<i32>>;
+
+#[test]
+fn test_par_substitude() {
+    let poly = Polynomial::<i64>::from_coefs((1..=50).collect());
+    for x in -2..=2 {
+        assert_eq!(poly.par_substitude::<i64, i64>(x), poly.substitude::<i64, i64>(x));
+    }
+    assert_eq!(Poly::default().par_substitude::<i32, i32>(5), 0);
+
+    type Inner = Zn<9_999>;
+    type Type = Matrix<3, 3, Inner>;
+    let x = X::<i32>::default();
+    let poly = (x ^ 1_000_000) * 23 + (x ^ 1337) * 7 + (x ^ 228);
+    let input = Type::from_data((1..=9).map(Inner::new).collect());
+    assert_eq!(poly.par_substitude(input.clone()), poly.substitude(input));
+}
+
+#[test]
+fn test_par_mul() {
+    let a = Poly::from_coefs((0..40).collect());
+    let b = Poly::from_coefs((0..30).map(|i| i - 15).collect());
+    let expected: Vec<i32> = {
+        let prod = (a.clone() * b.clone()).reduce();
+        (0..69).map(|i| prod.coef(i)).collect()
+    };
+    same_coef(a.par_mul(&b), expected);
+
+    same_coef(a.par_mul(&Poly::default()), vec![]);
+    same_coef(Poly::default().par_mul(&a), vec![]);
+}
+
+#[test]
+#[should_panic(expected = "overflows u32")]
+fn test_par_mul_power_overflow() {
+    let x = X::<i32>::default();
+    let _ = (x ^ u32::MAX).par_mul(&(x ^ 1));
+}
+
+#[test]
+fn test_par_eval_at_points() {
+    type Inner = Zn<9_999>;
+    type Type = Matrix<3, 3, Inner>;
+    let x = X::<i32>::default();
+    // duplicate powers are summed up before evaluation
+    let poly = (x ^ 1337) * 7 + (x ^ 228) + (x ^ 1337) * 16 + 5;
+    let points: Vec<Type> = (0..50)
+        .map(|i| Type::from_data((i..i + 9).map(Inner::new).collect()))
+        .collect();
+    let values: Vec<Type> = poly.par_eval_at_points(&points);
+    assert_eq!(values.len(), points.len());
+    for (value, point) in values.into_iter().zip(points) {
+        assert_eq!(value, poly.substitude(point));
+    }
+
+    let empty: Vec<i32> = poly.par_eval_at_points::<i32, i32>(&[]);
+    assert!(empty.is_empty());
+    assert_eq!(Poly::default().par_eval_at_points::<i32, i32>(&[1, 2]), vec![0, 0]);
+}
@@ -0,0 +1,57 @@
+use polylib::applications::PolyHash;
+use polylib::custom_types::Zn;
+use polylib::polynom::Polynomial;
+
+type F = Zn<1_000_000_007>;
+
+#[test]
+fn test_hash_is_polynomial_value() {
+    let data = [3u32, 1, 4, 1, 5, 9, 2, 6];
+    let base = F::new(10);
+    let hash = PolyHash::new(&data, base);
+    assert_eq!(hash.len(), 8);
+    assert_eq!(hash.hash(0..8), F::new(31415926));
+    assert_eq!(hash.hash(2..5), F::new(415));
+    assert_eq!(hash.prefix(3), F::new(314));
+    assert_eq!(hash.power(5), F::new(100000));
+
+    // first symbol is coef of the highest power
+    let coefs: Vec<F> = data.iter().rev().map(|&s| F::new(s)).collect();
+    let poly = Polynomial::<F>::from_coefs(coefs);
+    let base = F::new(123456);
+    assert_eq!(PolyHash::new(&data, base).hash(0..8), poly.substitude(base));
+}
+
+#[test]
+fn test_substrings() {
+    let text: Vec<char> = "mississippi".chars().collect();
+    let hash = PolyHash::new(&text, F::new(911382323));
+    assert!(hash.substring_eq(1..5, 4..8)); // "issi"
+    assert!(hash.substring_eq(2..4, 5..7)); // "ss"
+    assert!(!hash.substring_eq(0..3, 3..6));
+    assert!(!hash.substring_eq(1..4, 1..5));
+    assert!(hash.substring_eq(3..3, 7..7));
+    assert_eq!(hash.common_prefix(1, 4), 4);
+    assert_eq!(hash.common_prefix(0, 1), 0);
+    assert_eq!(hash.common_prefix(2, 2), 9);
+
+    // all pairs of substrings agree with direct comparison
+    for len in 1..=text.len() {
+        for a in 0..=text.len() - len {
+            for b in 0..=text.len() - len {
+                let same = text[a..a + len] == text[b..b + len];
+                assert_eq!(hash.substring_eq(a..a + len, b..b + len), same);
+            }
+        }
+    }
+
+    let empty = PolyHash::<7>::new(&[] as &[u8], Zn::new(3));
+    assert!(empty.is_empty());
+    assert_eq!(empty.hash(0..0), Zn::new(0));
+}
+
+#[test]
+#[should_panic]
+fn test_out_of_range() {
+    PolyHash::new(b"abc", F::new(31)).hash(1..4);
+}
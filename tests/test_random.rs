@@ -0,0 +1,43 @@
+use polylib::custom_types::{Matrix, Zn};
+use polylib::polynom::Polynomial;
+use polylib::random::{Random, Xorshift64};
+
+type TestType = Zn<97>;
+type Poly = Polynomial<TestType>;
+
+#[test]
+fn test_random_poly() {
+    let mut rng = Xorshift64::new(2024);
+    for degree in 0..10 {
+        let p = Poly::random(degree, &mut rng);
+        assert!(p.get(degree).is_some());
+        assert!(p.get(degree + 1).is_none());
+    }
+}
+
+#[test]
+fn test_reproducible() {
+    let mut a = Xorshift64::new(11);
+    let mut b = Xorshift64::new(11);
+    let pa = Poly::random(5, &mut a);
+    let pb = Poly::random(5, &mut b);
+    assert_eq!(format!("{}", pa), format!("{}", pb));
+
+    let ma = Matrix::<2, 3, TestType>::random(&mut a);
+    let mb = Matrix::<2, 3, TestType>::random(&mut b);
+    assert_eq!(ma, mb);
+}
+
+#[test]
+fn test_mul_property() {
+    // (a * b)(x) == a(x) * b(x) for random inputs
+    let mut rng = Xorshift64::new(3);
+    for _ in 0..20 {
+        let a = Poly::random(4, &mut rng);
+        let b = Poly::random(3, &mut rng);
+        let x = TestType::random(&mut rng);
+        let lhs: TestType = (a.clone() * b.clone()).substitude(x);
+        let rhs: TestType = a.substitude::<TestType, TestType>(x) * b.substitude(x);
+        assert_eq!(lhs, rhs);
+    }
+}
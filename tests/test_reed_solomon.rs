@@ -0,0 +1,65 @@
+use polylib::applications::ReedSolomon;
+use polylib::custom_types::{Gf256, Zp};
+use polylib::random::Xorshift64;
+use polylib::PolyError;
+
+fn gf(values: &[u64]) -> Vec<Gf256> {
+    values.iter().map(|&v| Gf256::new(v)).collect()
+}
+
+#[test]
+fn test_encode() {
+    let rs = ReedSolomon::new(6, Gf256::new(3));
+    let message = gf(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+    let codeword = rs.encode(&message);
+    assert_eq!(codeword.len(), 16);
+    assert_eq!(&codeword[6..], &message[..]);
+    assert!(rs.syndromes(&codeword).iter().all(|s| *s == Gf256::new(0)));
+    assert_eq!(rs.generator().degree(), Some(6));
+    assert_eq!(rs.decode(&codeword), Ok(message));
+}
+
+#[test]
+fn test_correct_errors() {
+    let mut rng = Xorshift64::new(7);
+    let rs = ReedSolomon::new(8, Gf256::new(3));
+    for len in [1, 10, 100, 247] {
+        let message: Vec<Gf256> = (0..len).map(|_| Gf256::new(rng.next_u64() & 0xFF)).collect();
+        let codeword = rs.encode(&message);
+        for errors in 0..=4 {
+            let mut received = codeword.clone();
+            for _ in 0..errors {
+                let pos = (rng.next_u64() % received.len() as u64) as usize;
+                received[pos] += Gf256::new(1 + rng.next_u64() % 255);
+            }
+            assert_eq!(rs.decode(&received), Ok(message.clone()), "len {} errors {}", len, errors);
+        }
+    }
+}
+
+#[test]
+fn test_too_many_errors() {
+    let rs = ReedSolomon::new(4, Gf256::new(3));
+    let message = gf(&[10, 20, 30, 40, 50, 60, 70, 80]);
+    let mut received = rs.encode(&message);
+    received[0] += Gf256::new(1);
+    received[5] += Gf256::new(2);
+    received[9] += Gf256::new(3);
+    match rs.decode(&received) {
+        Err(err) => assert_eq!(err, PolyError::TooManyErrors),
+        // miscorrection to other codeword is possible, but not to original message
+        Ok(decoded) => assert_ne!(decoded, message),
+    }
+}
+
+#[test]
+fn test_prime_field() {
+    // PDF417 uses GF(929) with generator 3
+    type F = Zp<929>;
+    let rs = ReedSolomon::new(4, F::new(3));
+    let message: Vec<F> = [453, 178, 121, 239].iter().map(|&v| F::new(v)).collect();
+    let mut received = rs.encode(&message);
+    received[2] = F::new(0);
+    received[7] += F::new(100);
+    assert_eq!(rs.decode(&received), Ok(message));
+}
@@ -0,0 +1,135 @@
+use polylib::custom_types::{Fraction, Zp};
+use polylib::polynom::{Polynomial, X};
+
+type Poly = Polynomial<i64, X<i64>>;
+type Frac = Fraction<i64>;
+
+// (x - r1)(x - r2)...
+fn with_roots(roots: &[i64]) -> Poly {
+    let x = X::<i64>::default();
+    roots.iter().map(|r| x.pow(1) - *r).product()
+}
+
+#[test]
+fn test_power_sums() {
+    let roots = [2, -1, 3, 3, 0, -4];
+    let p = with_roots(&roots);
+    let sums = p.power_sums(10);
+    for (k, sum) in sums.iter().enumerate() {
+        let expected: i64 = roots.iter().map(|r| r.pow(k as u32)).sum();
+        assert_eq!(*sum, expected, "sum of {}-th powers", k);
+    }
+
+    // x^2 + 1 has roots i and -i: i^k + (-i)^k
+    let p = Poly::from_coefs(vec![1, 0, 1]);
+    assert_eq!(p.power_sums(6), vec![2, 0, -2, 0, 2, 0, -2]);
+
+    // constant has no roots
+    assert_eq!(Poly::new_const(5).power_sums(3), vec![0, 0, 0, 0]);
+    assert_eq!(p.power_sums(0), vec![2]);
+
+    // unreduced polynomial: 2x^2 - 3x + 1 = (2x - 1)(x - 1)
+    let x = X::<Frac>::default();
+    let p = x.pow(2) * Frac::from(3) - x.pow(1) * Frac::from(3) - x.pow(2) + Frac::from(1);
+    assert_eq!(
+        p.power_sums(3),
+        vec![Frac::from(2), Frac::new(3, 2), Frac::new(5, 4), Frac::new(9, 8)]
+    );
+}
+
+#[test]
+fn test_elementary_symmetric() {
+    let p = with_roots(&[2, -1, 3, 5]);
+    let e: Vec<i64> = (0..6).map(|k| p.elementary_symmetric(k)).collect();
+    assert_eq!(e, vec![1, 9, 21, -1, -30, 0]);
+
+    // 3x^2 + 2x - 1 = (3x - 1)(x + 1) over Z7: roots are 1/3 = 5 and -1 = 6
+    let p = Polynomial::<Zp<7>>::from_coefs(vec![Zp::new(6), Zp::new(2), Zp::new(3)]);
+    assert_eq!(p.elementary_symmetric(1), Zp::new(4));
+    assert_eq!(p.elementary_symmetric(2), Zp::new(2));
+    assert_eq!(p.power_sums(2)[2], Zp::new(5 * 5 + 6 * 6));
+}
+
+#[test]
+#[should_panic(expected = "power_sums of zero polynomial")]
+fn test_power_sums_of_zero() {
+    let _ = Poly::from_coefs(vec![0, 0]).power_sums(2);
+}
+
+#[test]
+fn test_roots_scaled() {
+    let roots = [2, -1, 3, 0];
+    let p = with_roots(&roots) * 5;
+    let scaled: Vec<i64> = roots.iter().map(|r| r * -3).collect();
+    assert_eq!(p.poly_with_roots_scaled(-3), with_roots(&scaled) * 5);
+    assert_eq!(p.poly_with_roots_scaled(1), p);
+    // all roots become zero
+    assert_eq!(p.poly_with_roots_scaled(0), Poly::from_coefs(vec![0, 0, 0, 0, 5]));
+
+    let x = X::<i64>::default();
+    let unreduced = x.pow(2) + x.pow(2) - x.pow(0) - x.pow(2);
+    assert_eq!(unreduced.poly_with_roots_scaled(4), Poly::from_coefs(vec![-16, 0, 1]));
+    assert!(Poly::default().poly_with_roots_scaled(2).is_empty());
+    assert_eq!(Poly::new_const(3).poly_with_roots_scaled(2), Poly::new_const(3));
+}
+
+#[test]
+fn test_roots_shifted() {
+    let roots = [2, -1, 3, 3, 0];
+    let p = with_roots(&roots) * -2;
+    for a in [-4, -1, 0, 1, 7] {
+        let shifted: Vec<i64> = roots.iter().map(|r| r + a).collect();
+        assert_eq!(p.poly_with_roots_shifted(a), with_roots(&shifted) * -2);
+    }
+    // shift back
+    let q = p.poly_with_roots_shifted(5).poly_with_roots_shifted(-5);
+    assert_eq!(q, p);
+    assert!(Poly::default().poly_with_roots_shifted(2).is_empty());
+    assert_eq!(Poly::new_const(3).poly_with_roots_shifted(2), Poly::new_const(3));
+}
+
+#[test]
+fn test_reciprocal_roots() {
+    let q = |num, den| Frac::new(num, den);
+    let x = X::<Frac>::default();
+    // (x - 2)(x + 1/3)(x - 4)
+    let p: Polynomial<Frac> = [q(2, 1), q(-1, 3), q(4, 1)]
+        .iter()
+        .map(|r| x.pow(1) - *r)
+        .product();
+    let r = p.poly_with_reciprocal_roots();
+    assert_eq!(r.degree(), Some(3));
+    for root in [q(1, 2), q(-3, 1), q(1, 4)] {
+        assert_eq!(r.substitude::<Frac, Frac>(root), q(0, 1));
+    }
+    assert_eq!(r.poly_with_reciprocal_roots(), p);
+
+    // zero roots are dropped
+    let p = with_roots(&[0, 0, 1, -1]);
+    assert_eq!(p.poly_with_reciprocal_roots(), Poly::from_coefs(vec![1, 0, -1]));
+    assert!(Poly::default().poly_with_reciprocal_roots().is_empty());
+}
+
+#[test]
+fn test_graeffe_iterate() {
+    let roots = [1, -2, 3, 0, -1];
+    let p = with_roots(&roots);
+    for k in 0..4 {
+        let powered: Vec<i64> = roots.iter().map(|r| r.pow(1 << k)).collect();
+        assert_eq!(p.graeffe_iterate(k), with_roots(&powered), "{} iterations", k);
+    }
+
+    // leading coef is squared: 3x^2 + 1 has roots ±i/√3, both squares are -1/3
+    let p = Poly::from_coefs(vec![1, 0, 3]);
+    assert_eq!(p.graeffe_iterate(1), Poly::from_coefs(vec![1, 6, 9]));
+    assert_eq!(p.graeffe_iterate(2), Poly::from_coefs(vec![1, -18, 81]));
+
+    // magnitudes of roots: (x - 2)(x - 3)(x - 5), coefs ratios tend to 5^8, 3^8, 2^8
+    let p = Polynomial::<f64>::from_coefs(vec![-30.0, 31.0, -10.0, 1.0]).graeffe_iterate(3);
+    let r: Vec<f64> =
+        (0..3).map(|i| (p.coef(i).abs() / p.coef(i + 1).abs()).powf(1.0 / 8.0)).collect();
+    assert!((r[0] - 2.0).abs() < 0.1 && (r[1] - 3.0).abs() < 0.1 && (r[2] - 5.0).abs() < 0.1);
+
+    assert!(Poly::default().graeffe_iterate(3).is_empty());
+    assert_eq!(Poly::new_const(-2).graeffe_iterate(2), Poly::new_const(16));
+}
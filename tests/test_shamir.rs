@@ -0,0 +1,51 @@
+use polylib::applications::shamir::{reconstruct, split, Share};
+use polylib::custom_types::Zp;
+use polylib::random::Xorshift64;
+use polylib::PolyError;
+
+type F = Zp<65521>;
+
+#[test]
+fn test_any_k_shares() {
+    let mut rng = Xorshift64::new(1);
+    let secret = F::new(31337);
+    let shares = split(secret, 3, 6, || rng.next_u64());
+    assert_eq!(shares.len(), 6);
+    assert!(shares.iter().enumerate().all(|(i, s)| s.x == F::new(i as u32 + 1)));
+
+    for a in 0..6 {
+        for b in a + 1..6 {
+            for c in b + 1..6 {
+                assert_eq!(reconstruct(&[shares[a], shares[b], shares[c]]), Ok(secret));
+            }
+        }
+    }
+    // more shares than needed work too
+    assert_eq!(reconstruct(&shares), Ok(secret));
+}
+
+#[test]
+fn test_threshold() {
+    let mut rng = Xorshift64::new(2);
+    let secret = F::new(7);
+    let shares = split(secret, 4, 4, || rng.next_u64());
+    assert_eq!(reconstruct(&shares), Ok(secret));
+    assert_ne!(reconstruct(&shares[..3]), Ok(secret));
+
+    // threshold 1 gives secret to everybody
+    let shares = split(secret, 1, 3, || rng.next_u64());
+    assert!(shares.iter().all(|s| s.y == secret));
+}
+
+#[test]
+fn test_duplicate_points() {
+    let share = Share { x: F::new(1), y: F::new(5) };
+    assert_eq!(reconstruct(&[share, share]), Err(PolyError::DivisionByZero));
+}
+
+#[test]
+#[should_panic]
+fn test_too_many_shares() {
+    let mut rng = Xorshift64::new(3);
+    let _ = split(Zp::<5>::new(1), 2, 5, || rng.next_u64());
+}
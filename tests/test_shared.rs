@@ -0,0 +1,56 @@
+use polylib::custom_types::Matrix;
+use polylib::polynom::{Polynomial, SharedPolynomial, X};
+
+mod common;
+use common::same_coef;
+
+type Poly = Polynomial<i32, X<i32>>;
+
+#[test]
+fn test_share() {
+    let shared = Poly::from_coefs(vec![1, 2, 3]).share();
+    let other = shared.clone();
+    assert!(shared.ptr_eq(&other));
+    assert_eq!(shared.share_count(), 2);
+    assert_eq!(other.len(), 3);
+    assert_eq!(other.coef(2), 3);
+    assert_eq!(format!("{}", other), "1 + 2x + 3x^2");
+}
+
+#[test]
+fn test_copy_on_write() {
+    let a: SharedPolynomial<i32> = Poly::from_coefs(vec![1, 2]).into();
+    let mut b = a.clone();
+
+    b.make_mut().reserve(1);
+    assert!(!a.ptr_eq(&b));
+    assert_eq!(a.share_count(), 1);
+
+    // not shared anymore, so no copy
+    let before = &*b as *const Poly;
+    b.make_mut().reserve(1);
+    assert_eq!(before, &*b as *const Poly);
+
+    same_coef(a.unshare() + b.unshare(), vec![2, 4]);
+}
+
+#[test]
+fn test_threads() {
+    type Type = Matrix<2, 2, i32>;
+    let x = X::<i32>::default();
+    let shared = ((x ^ 10) + (x ^ 3) + 1).share();
+
+    let results: Vec<Type> = std::thread::scope(|s| {
+        let handles: Vec<_> = (0..4)
+            .map(|i| {
+                let poly = shared.clone();
+                s.spawn(move || poly.substitude(Type::eye(i)))
+            })
+            .collect();
+        handles.into_iter().map(|h| h.join().unwrap()).collect()
+    });
+    assert_eq!(results[0], Type::eye(1));
+    assert_eq!(results[1], Type::eye(3));
+    assert_eq!(results[2], Type::eye(1024 + 8 + 1));
+    assert_eq!(shared.share_count(), 1);
+}
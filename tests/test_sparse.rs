@@ -0,0 +1,97 @@
+use polylib::custom_types::{Matrix, Zn};
+use polylib::polynom::{Polynomial, SparsePolynomial, X};
+use polylib::{One, PolyError, Zero};
+
+mod common;
+use common::same_coef;
+
+type Sparse = SparsePolynomial<i64>;
+
+#[test]
+fn test_terms() {
+    let mut p = Sparse::new();
+    assert!(p.is_empty());
+    assert_eq!(p.degree(), None);
+    p.add_term(u64::MAX, 1);
+    p.add_term(5, 2);
+    p.add_term(5, -2);
+    p.add_term(0, 0);
+    assert_eq!(p.len(), 1);
+    assert_eq!(p.degree(), Some(u64::MAX));
+    assert_eq!(p.get(5), None);
+    assert_eq!(p.get(u64::MAX), Some(&1));
+
+    let p = Sparse::from_terms(vec![(7, 1), (3, 2), (1 << 50, 4), (3, 1)]);
+    let terms: Vec<(u64, i64)> = p.terms().map(|(power, &coef)| (power, coef)).collect();
+    assert_eq!(terms, vec![(3, 3), (7, 1), (1 << 50, 4)]);
+    assert!(Sparse::one().is_one());
+    assert!(Sparse::zero().is_zero());
+}
+
+#[test]
+fn test_arithmetic() {
+    let a = Sparse::from_terms(vec![(1 << 40, 1), (0, 1)]);
+    let b = Sparse::from_terms(vec![(1 << 40, 1), (0, -1)]);
+    assert_eq!(a.clone() + b.clone(), Sparse::from_terms(vec![(1 << 40, 2)]));
+    assert_eq!(a.clone() - b.clone(), Sparse::from_terms(vec![(0, 2)]));
+    assert_eq!(-a.clone(), Sparse::from_terms(vec![(1 << 40, -1), (0, -1)]));
+    assert_eq!(a.clone() * 3, Sparse::from_terms(vec![(1 << 40, 3), (0, 3)]));
+    // (x^n + 1)(x^n - 1) = x^2n - 1
+    assert_eq!(a * b, Sparse::from_terms(vec![(1 << 41, 1), (0, -1)]));
+
+    // zero divisors of Zn<4> drop members
+    let p = SparsePolynomial::<Zn<4>>::from_terms(vec![(1, Zn::new(2)), (2, Zn::new(1))]);
+    assert_eq!(p.clone() * Zn::new(2), SparsePolynomial::from_terms(vec![(2, Zn::new(2))]));
+    // (2x + x^2)^2 = 4x^2 + 4x^3 + x^4 = x^4
+    assert_eq!((p.clone() * p).len(), 1);
+}
+
+#[test]
+#[should_panic]
+fn test_power_overflow() {
+    let p = Sparse::from_terms(vec![(u64::MAX / 2 + 1, 1)]);
+    let _ = p.clone() * p;
+}
+
+#[test]
+fn test_substitude() {
+    let p = Sparse::from_terms(vec![(0, 1), (2, 3), (3, -1)]);
+    assert_eq!(p.substitude::<i64, i64>(2), 5);
+    assert_eq!(Sparse::zero().substitude::<i64, i64>(2), 0);
+
+    // same as `Polynomial` at matrix point
+    type Inner = Zn<9_999>;
+    type Type = Matrix<3, 3, Inner>;
+    let x = X::<i32>::default();
+    let poly = (x ^ 2_000_000_000) * 23 + (x ^ 1_321_654) * 5 + (x ^ 1337) * 7 + (x ^ 228);
+    let sparse = SparsePolynomial::from(poly.clone());
+    let point = Type::from_data((1..=9).map(Inner::new).collect());
+    let expected: Type = poly.substitude(point.clone());
+    assert_eq!(sparse.substitude::<Type, Type>(point), expected);
+}
+
+#[test]
+fn test_conversion() {
+    let x = X::<i64>::default();
+    let poly = x.pow(3) * 2 + x.pow(1) - x.pow(3) * 2 + 4;
+    let sparse = SparsePolynomial::from(poly);
+    assert_eq!(sparse, Sparse::from_terms(vec![(1, 1), (0, 4)]));
+    same_coef(sparse.to_polynomial().expect("powers are small"), vec![4, 1]);
+
+    let huge = Sparse::from_terms(vec![(1 << 32, 1)]);
+    assert_eq!(
+        huge.to_polynomial().unwrap_err(),
+        PolyError::ExponentOverflow(1 << 32)
+    );
+    let p: Polynomial<i64> = Sparse::from_terms(vec![(u32::MAX as u64, 1)])
+        .to_polynomial()
+        .expect("power fits");
+    assert_eq!(p.degree(), Some(u32::MAX));
+}
+
+#[test]
+fn test_display() {
+    let p = Sparse::from_terms(vec![(1 << 40, 1), (1, 2), (0, 1)]);
+    assert_eq!(format!("{}", p), "1 + 2x + x^1099511627776");
+    assert_eq!(format!("{}", Sparse::zero()), "0");
+}
@@ -0,0 +1,54 @@
+use polylib::custom_types::DynMatrix;
+use polylib::polynom::{subresultant, Polynomial};
+
+type Poly = Polynomial<i64>;
+
+#[test]
+fn test_subresultants() {
+    // Knuth's example, coprime polynomials
+    let p = Poly::from_coefs(vec![-5, 2, 8, -3, -3, 0, 1, 0, 1]);
+    let q = Poly::from_coefs(vec![21, -9, -4, 0, 5, 0, 3]);
+    let prs = subresultant::subresultants(&q, &p);
+    assert_eq!(prs.len(), 6);
+    assert_eq!(prs[0], p);
+    assert_eq!(prs[1], q);
+    assert_eq!(prs[2], Poly::from_coefs(vec![9, 0, -3, 0, 15]));
+    assert_eq!(prs[3], Poly::from_coefs(vec![-245, 125, 65]));
+    assert_eq!(prs[4], Poly::from_coefs(vec![-12300, 9326]));
+    assert_eq!(prs[5], Poly::from_coefs(vec![260708]));
+
+    // gcd (x - 1)^2 (x + 2) up to constant
+    let g = Poly::from_coefs(vec![2, -3, 0, 1]);
+    let p = g.clone() * Poly::from_coefs(vec![5, 0, 1]);
+    let q = g.clone() * Poly::from_coefs(vec![-7, 2]);
+    let last = subresultant::subresultants(&p, &q).pop().unwrap();
+    assert_eq!(last.degree(), Some(3));
+    let lead = last.coef(3);
+    assert_eq!(last, g * lead);
+
+    let zero = p.clone() - p.clone();
+    assert!(subresultant::subresultants(&zero, &zero).is_empty());
+    assert_eq!(subresultant::subresultants(&zero, &q), vec![q]);
+}
+
+#[test]
+fn test_bezout_matrix() {
+    let p = Poly::from_coefs(vec![2, -3, 1]); // (x - 1)(x - 2)
+    let q = Poly::from_coefs(vec![-3, -2, 1]); // (x - 3)(x + 1)
+    let b = subresultant::bezout_matrix(&p, &q);
+    assert_eq!(b.shape(), (2, 2));
+    assert_eq!(b.clone().transpose(), b);
+    // resultant q(1) * q(2) = 12
+    assert_eq!(b.determinant().abs(), 12);
+    let last = subresultant::subresultants(&p, &q).pop().unwrap();
+    assert_eq!(last.coef(0).abs(), 12);
+
+    let p = Poly::from_coefs(vec![1, 0, 3, -1, 2]);
+    let q = Poly::from_coefs(vec![4, 1, 0, -2]);
+    let b = subresultant::bezout_matrix(&p, &q);
+    assert_eq!(b.clone().transpose(), b);
+    assert_eq!(subresultant::bezout_matrix(&q, &p), -b);
+
+    let zero = p.clone() - p.clone();
+    assert_eq!(subresultant::bezout_matrix(&zero, &zero), DynMatrix::full(0, 0, 0));
+}
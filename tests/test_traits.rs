@@ -0,0 +1,187 @@
+use polylib::polynom::{
+    ChebyshevSeries, DensePolynomial, FixedPolynomial, Polynomial, SparsePolynomial,
+};
+use polylib::{Evaluate, One, Zero};
+
+mod common;
+use common::substitude_check;
+
+// `From<u8>` is deliberately not `value -> value` here,
+// so `Zero`/`One` must be implemented by hand.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+struct Parity(bool);
+
+impl From<u8> for Parity {
+    fn from(value: u8) -> Self {
+        Parity(value % 2 == 1)
+    }
+}
+
+impl Zero for Parity {
+    fn zero() -> Self {
+        Parity(false)
+    }
+
+    fn is_zero(&self) -> bool {
+        !self.0
+    }
+}
+
+impl One for Parity {
+    fn one() -> Self {
+        Parity(true)
+    }
+
+    fn is_one(&self) -> bool {
+        self.0
+    }
+}
+
+impl std::ops::Add for Parity {
+    type Output = Parity;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Parity(self.0 != rhs.0)
+    }
+}
+
+impl std::ops::Mul for Parity {
+    type Output = Parity;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Parity(self.0 && rhs.0)
+    }
+}
+
+impl std::fmt::Display for Parity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0 as u8)
+    }
+}
+
+#[test]
+fn test_primitives() {
+    assert!(0i8.is_zero());
+    assert!(1u128.is_one());
+    assert_eq!(usize::zero(), 0);
+    assert_eq!(f64::one(), 1.0);
+    assert!(!2i64.is_one());
+    assert!(!(-0.5f32).is_zero());
+}
+
+#[test]
+fn test_custom_type() {
+    assert_eq!(Parity::from(3), Parity::one());
+    assert_eq!(Parity::from(4), Parity::zero());
+
+    // x^2 + x + 1 over GF(2) has no roots
+    let poly = Polynomial::<Parity>::from_coefs(vec![Parity(true); 3]);
+    substitude_check(
+        poly,
+        vec![Parity(false), Parity(true)],
+        vec![Parity(true), Parity(true)],
+    );
+}
+
+// generic over anything, that can be evaluated
+fn values<F: Evaluate<i64, i64>>(f: &F, points: &[i64]) -> Vec<i64> {
+    points.iter().map(|&x| f.evaluate(x)).collect()
+}
+
+#[test]
+fn test_evaluate() {
+    let p = Polynomial::<i64>::from_coefs(vec![1, -2, 0, 3]);
+    let points = [-2, 0, 1, 5];
+    let expect = values(&|x: i64| 3 * x * x * x - 2 * x + 1, &points);
+    assert_eq!(values(&p, &points), expect);
+    assert_eq!(values(&p.compile::<i64, i64>(), &points), expect);
+    assert_eq!(values(&DensePolynomial::from(p.clone()), &points), expect);
+    assert_eq!(values(&SparsePolynomial::from(p.clone()), &points), expect);
+    assert_eq!(values(&FixedPolynomial::<i64, 4>::new([1, -2, 0, 3]), &points), expect);
+    assert_eq!(values(&p.clone().share(), &points), expect);
+
+    let q = Polynomial::<f64>::from_coefs(vec![0.5, 0.0, -1.0]);
+    let series = ChebyshevSeries::from_polynomial(&q, -1.0, 3.0);
+    for x in [-1.0, 0.25, 2.0] {
+        let value: f64 = q.evaluate(x);
+        assert!((Evaluate::evaluate(&series, x) - value).abs() < 1e-12);
+    }
+}
+
+// coef without `Zero` and `PartialEq`: function of parameter t
+#[derive(Clone)]
+struct Func(std::rc::Rc<dyn Fn(f64) -> f64>);
+
+impl Func {
+    fn new<F: Fn(f64) -> f64 + 'static>(f: F) -> Func {
+        Func(std::rc::Rc::new(f))
+    }
+}
+
+impl std::ops::Add for Func {
+    type Output = Func;
+
+    fn add(self, rhs: Func) -> Func {
+        Func::new(move |t| (self.0)(t) + (rhs.0)(t))
+    }
+}
+
+impl std::ops::Mul for Func {
+    type Output = Func;
+
+    fn mul(self, rhs: Func) -> Func {
+        Func::new(move |t| (self.0)(t) * (rhs.0)(t))
+    }
+}
+
+#[test]
+fn test_coefs_without_zero() {
+    // t + t^2 x + 0 x^2 + 3x^3
+    let p = Polynomial::<Func>::from_coefs_with_zeros(vec![
+        Func::new(|t| t),
+        Func::new(|t| t * t),
+        Func::new(|_| 0.0),
+    ]) + Polynomial::from_terms(vec![(3, Func::new(|_| 3.0))]);
+    assert_eq!(p.len(), 4);
+    assert_eq!(p.get(1).map(|f| (f.0)(3.0)), Some(9.0));
+
+    let square = p.clone() * p;
+    let value = |t: f64, x: f64| {
+        square.fold_terms(0.0, |acc, power, coef| acc + (coef.0)(t) * x.powi(power as i32))
+    };
+    let expect = |t: f64, x: f64| (t + t * t * x + 3.0 * x.powi(3)).powi(2);
+    for (t, x) in [(0.0, 1.0), (1.5, -2.0), (-3.0, 0.5)] {
+        assert!((value(t, x) - expect(t, x)).abs() < 1e-9);
+    }
+}
+
+// generic code, that promotes scalars into polynomials
+fn affine<T, P>(scale: T, shift: T) -> P
+where
+    P: From<(u32, T)> + From<T> + std::ops::Add<Output = P>,
+{
+    P::from((1, scale)) + P::from(shift)
+}
+
+#[test]
+fn test_from_conversions() {
+    let p: Polynomial<i32> = affine(2, 5);
+    assert_eq!(p, Polynomial::from_coefs(vec![5, 2]));
+
+    assert_eq!(Polynomial::<i32>::from(7), Polynomial::new_const(7));
+    assert_eq!(Polynomial::<i32>::from((0, 7)), Polynomial::new_const(7));
+    let q: Polynomial<i32> = vec![1, 0, 3].into();
+    assert_eq!(q, Polynomial::from_coefs(vec![1, 0, 3]));
+    assert_eq!(q.len(), 2);
+
+    // coefs without `Zero` are promoted too
+    let f: Polynomial<Func> = (2, Func::new(|t| t + 1.0)).into();
+    assert_eq!(f.get(2).map(|g| (g.0)(1.0)), Some(2.0));
+    let c: Polynomial<Func> = Func::new(|_| 4.0).into();
+    assert_eq!(c.get(0).map(|g| (g.0)(0.0)), Some(4.0));
+
+    // nested polynomial gets inner one as constant
+    let inner = Polynomial::<i32>::from_coefs(vec![1, 1]);
+    let nested: Polynomial<Polynomial<i32>> = inner.clone().into();
+    assert_eq!(nested.get(0), Some(&inner));
+}
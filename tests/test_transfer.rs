@@ -0,0 +1,81 @@
+use polylib::applications::TransferFunction;
+use polylib::custom_types::Fraction;
+use polylib::polynom::Polynomial;
+use polylib::PolyError;
+
+type Q = Fraction<i64>;
+
+fn q_poly(coefs: &[i64]) -> Polynomial<Q> {
+    Polynomial::from_coefs(coefs.iter().map(|&c| Q::from(c)).collect())
+}
+
+fn close(a: &[(f64, f64)], b: &[(f64, f64)]) -> bool {
+    a.len() == b.len()
+        && a.iter().zip(b).all(|(x, y)| (x.0 - y.0).abs() < 1e-9 && (x.1 - y.1).abs() < 1e-9)
+}
+
+#[test]
+fn test_impulse_response() {
+    // Fibonacci: H = z^-1 / (1 - z^-1 - z^-2)
+    let h = TransferFunction::new(q_poly(&[0, 1]), q_poly(&[1, -1, -1]));
+    let expect: Vec<Q> = [0, 1, 1, 2, 3, 5, 8, 13].iter().map(|&c| Q::from(c)).collect();
+    assert_eq!(h.impulse_response(8), expect);
+    assert!(h.impulse_response(0).is_empty());
+    assert_eq!(h.numerator(), q_poly(&[0, 1]));
+    assert_eq!(h.denominator(), q_poly(&[1, -1, -1]));
+
+    // a0 scales output
+    let h = TransferFunction::new(q_poly(&[1]), q_poly(&[2]));
+    assert_eq!(h.impulse_response(2), vec![Q::new(1, 2), Q::from(0)]);
+
+    let input: Vec<Q> = [3, -1, 4].iter().map(|&c| Q::from(c)).collect();
+    let h = TransferFunction::new(q_poly(&[1, 1]), q_poly(&[1]));
+    assert_eq!(h.filter(&input), vec![Q::from(3), Q::from(2), Q::from(3)]);
+
+    let err = TransferFunction::try_new(q_poly(&[1]), q_poly(&[0, 1])).unwrap_err();
+    assert_eq!(err, PolyError::DivisionByZero);
+    assert!(TransferFunction::try_new(q_poly(&[1]), q_poly(&[])).is_err());
+}
+
+#[test]
+fn test_composition() {
+    let h1 = TransferFunction::new(q_poly(&[1]), q_poly(&[1, -1])); // 1 / (1 - z^-1)
+    let h2 = TransferFunction::new(q_poly(&[1, 2]), q_poly(&[2, 1]));
+    let input: Vec<Q> = [1, 0, -2, 5, 3].iter().map(|&c| Q::from(c)).collect();
+
+    // series connection filters twice
+    let series = h1.cascade(&h2);
+    assert_eq!(series.filter(&input), h2.filter(&h1.filter(&input)));
+
+    // parallel connection sums outputs
+    let both = h1.parallel(&h2);
+    let sum: Vec<Q> = h1
+        .filter(&input)
+        .into_iter()
+        .zip(h2.filter(&input))
+        .map(|(a, b)| a + b)
+        .collect();
+    assert_eq!(both.filter(&input), sum);
+}
+
+#[test]
+fn test_poles_zeros() {
+    let poly = |coefs: Vec<f64>| Polynomial::<f64>::from_coefs(coefs);
+    // resonator with poles 0.9 e^(+-i pi/3): 1 - 0.9 z^-1 + 0.81 z^-2
+    let h = TransferFunction::new(poly(vec![1.0, 0.0, -1.0]), poly(vec![1.0, -0.9, 0.81]));
+    let (re, im) = (0.45, 0.9 * 3f64.sqrt() / 2.0);
+    assert!(close(&h.poles(), &[(re, -im), (re, im)]));
+    assert!(close(&h.zeros(), &[(-1.0, 0.0), (1.0, 0.0)]));
+    assert!(h.is_stable());
+
+    // FIR filter has poles at 0 only
+    let fir = TransferFunction::new(poly(vec![1.0, -3.0, 2.0]), poly(vec![1.0]));
+    assert!(close(&fir.poles(), &[(0.0, 0.0), (0.0, 0.0)]));
+    assert!(close(&fir.zeros(), &[(1.0, 0.0), (2.0, 0.0)]));
+
+    // pole on unit circle
+    let integrator = TransferFunction::new(poly(vec![1.0]), poly(vec![1.0, -1.0]));
+    assert!(!integrator.is_stable());
+    let both = integrator.cascade(&h);
+    assert_eq!(both.poles().len(), 3);
+}
@@ -0,0 +1,85 @@
+use polylib::custom_types::Tropical;
+use polylib::polynom::Polynomial;
+use polylib::polynom::X;
+
+mod common;
+use common::same_coef;
+use common::substitude_check;
+
+type TestType = Tropical<i64>;
+type Poly = Polynomial<TestType, X<TestType>>;
+
+fn t(value: i64) -> TestType {
+    TestType::new(value)
+}
+
+#[test]
+fn test_build() {
+    let x = X::<TestType>::default();
+    let poly = Poly::default() + t(3) + x.pow(1) * t(1) + x.pow(2) * t(4);
+    same_coef(poly, vec![t(3), t(1), t(4)]);
+}
+
+#[test]
+fn test_add_reduce() {
+    // (min) of coefs with the same power
+    let a = Poly::from_coefs(vec![t(3), t(1), t(4)]);
+    let b = Poly::from_coefs(vec![t(2), t(7), TestType::infinity(), t(0)]);
+    same_coef(a + b, vec![t(2), t(1), t(4), t(0)]);
+}
+
+#[test]
+fn test_mul() {
+    // (x + 1)(x + 2) = x^2 + min(1, 2)x + 3 in tropical notation
+    let a = Poly::from_coefs(vec![t(1), t(0)]);
+    let b = Poly::from_coefs(vec![t(2), t(0)]);
+    same_coef(a.clone() * b, vec![t(3), t(1), t(0)]);
+    same_coef(a.pow(3), vec![t(3), t(2), t(1), t(0)]);
+}
+
+#[test]
+fn test_substitude() {
+    // p(x) = min(3, 1 + x, 4 + 2x)
+    let poly = Poly::from_coefs(vec![t(3), t(1), t(4)]);
+    substitude_check(
+        poly,
+        vec![t(-5), t(0), t(2), t(10)],
+        vec![t(-6), t(1), t(3), t(3)],
+    );
+}
+
+#[test]
+fn test_display() {
+    let poly = Poly::from_coefs(vec![t(3), t(1), t(4)]);
+    assert_eq!(format!("{}", poly), "3 + 1x + 4x^2");
+
+    let poly = Poly::from_coefs(vec![t(0), t(0)]);
+    assert_eq!(format!("{}", poly), "0 + x");
+
+    assert_eq!(format!("{}", Poly::default()), "inf");
+}
+
+#[test]
+fn test_shortest_paths() {
+    use polylib::custom_types::Matrix;
+    use polylib::polynom::Polynomial;
+    use polylib::{One, Zero};
+
+    // weighted graph 0 -> 1 (4), 0 -> 2 (1), 2 -> 1 (2), 1 -> 3 (5)
+    type Type = Matrix<4, 4, TestType>;
+    let inf = TestType::zero();
+    let graph = Type::from_data(vec![
+        inf, t(4), t(1), inf,
+        inf, inf, inf, t(5),
+        inf, t(2), inf, inf,
+        inf, inf, inf, inf,
+    ]);
+    // 1 + A + A^2 + A^3 gives shortest paths with at most 3 edges
+    let poly = Polynomial::<Type>::from_coefs(vec![Type::one(); 4]);
+    let dist: Type = poly.substitude(graph);
+    assert_eq!(dist[(0, 1)], t(3));
+    assert_eq!(dist[(0, 3)], t(8));
+    assert_eq!(dist[(2, 3)], t(7));
+    assert_eq!(dist[(1, 1)], t(0));
+    assert!(dist[(3, 0)].is_infinity());
+}
@@ -0,0 +1,41 @@
+use polylib::custom_types::{Saturating, Wrapping};
+use polylib::polynom::Polynomial;
+
+mod common;
+use common::same_coef;
+use common::substitude_check;
+
+#[test]
+fn test_wrapping() {
+    type TestType = Wrapping<u8>;
+    // x^2 + 1 overflows u8 at 16, 16^2 + 1 = 257 = 1 (mod 256)
+    let poly = Polynomial::<TestType>::from_coefs(vec![Wrapping(1), Wrapping(0), Wrapping(1)]);
+    substitude_check(
+        poly.clone(),
+        vec![Wrapping(3), Wrapping(16), Wrapping(255)],
+        vec![Wrapping(10), Wrapping(1), Wrapping(2)],
+    );
+    // (x^2 + 1)^2 = x^4 + 2x^2 + 1
+    same_coef(
+        poly.pow(2),
+        vec![Wrapping(1), Wrapping(0), Wrapping(2), Wrapping(0), Wrapping(1)],
+    );
+}
+
+#[test]
+fn test_saturating() {
+    type TestType = Saturating<i8>;
+    // 2x^3 - 5, members are summed from the lowest power,
+    // so 2 * 10^3 saturates to 127 and then -5 + 127 = 122
+    let poly = Polynomial::<TestType>::from_coefs(vec![
+        Saturating(-5),
+        Saturating(0),
+        Saturating(0),
+        Saturating(2),
+    ]);
+    substitude_check(
+        poly,
+        vec![Saturating(1), Saturating(10), Saturating(-10)],
+        vec![Saturating(-3), Saturating(122), Saturating(-128)],
+    );
+}
@@ -1,6 +1,7 @@
 use polylib::custom_types::Zn;
 use polylib::polynom::Polynomial;
 use polylib::polynom::X;
+use polylib::random::Xorshift64;
 
 mod common;
 use common::same_coef;
@@ -25,3 +26,156 @@ fn test_zn() {
         vec![TestType::new(1), TestType::new(0), TestType::new(2)],
     );
 }
+
+#[test]
+fn test_from_signed_coefs() {
+    // x^2 - 3x - 1 over Z3 is x^2 + 2
+    let coefs: Vec<TestType> = [-1i32, -3, 1].into_iter().map(TestType::from).collect();
+    let poly = Poly::from_coefs(coefs);
+    same_coef(
+        poly,
+        vec![TestType::new(2), TestType::new(0), TestType::new(1)],
+    );
+}
+
+// every residue, where polynomial is zero
+fn brute_roots<const N: u32>(poly: &Polynomial<Zn<N>>) -> Vec<Zn<N>> {
+    (0..N)
+        .map(Zn::new)
+        .filter(|&r| poly.substitude::<Zn<N>, Zn<N>>(r) == Zn::new(0))
+        .collect()
+}
+
+#[test]
+fn test_roots_mod_n() {
+    // (x - 2)(x - 5)^2(x^2 + 1) over Z13, x^2 + 1 = (x - 5)(x - 8)
+    type F = Zn<13>;
+    let linear = |r: i64| Polynomial::<F>::from_coefs(vec![F::from_i64(-r), F::new(1)]);
+    let poly = linear(2) * linear(5) * linear(5) * linear(5) * linear(8);
+    let roots: Vec<u32> = poly.roots_mod_n().iter().map(Zn::value).collect();
+    assert_eq!(roots, vec![2, 5, 8]);
+
+    // x^2 + x over Z2 vanishes everywhere, but is not zero
+    let poly = Polynomial::<Zn<2>>::from_coefs(vec![Zn::new(0), Zn::new(1), Zn::new(1)]);
+    assert_eq!(poly.roots_mod_n(), vec![Zn::new(0), Zn::new(1)]);
+
+    // x^3 - x over Z12 and Z(2^31 - 1)
+    let coefs = [0, -1, 0, 1];
+    let roots: Vec<u32> = Polynomial::<Zn<12>>::from_coefs(coefs.map(Zn::from_i64).to_vec())
+        .roots_mod_n()
+        .iter()
+        .map(Zn::value)
+        .collect();
+    assert_eq!(roots, vec![0, 1, 3, 4, 5, 7, 8, 9, 11]);
+    const P: u32 = 2_147_483_647;
+    let roots: Vec<u32> = Polynomial::<Zn<P>>::from_coefs(coefs.map(Zn::from_i64).to_vec())
+        .roots_mod_n()
+        .iter()
+        .map(Zn::value)
+        .collect();
+    assert_eq!(roots, vec![0, 1, P - 1]);
+
+    assert_eq!(Polynomial::<Zn<5>>::from_coefs(vec![]).roots_mod_n().len(), 5);
+    assert!(Polynomial::<Zn<5>>::new_const(Zn::new(3)).roots_mod_n().is_empty());
+}
+
+#[test]
+fn test_roots_mod_n_random() {
+    let mut rng = Xorshift64::new(7);
+    for degree in 0..12 {
+        let poly = Polynomial::<Zn<101>>::random(degree, &mut rng);
+        assert_eq!(poly.roots_mod_n(), brute_roots(&poly));
+        let poly = Polynomial::<Zn<91>>::random(degree, &mut rng);
+        assert_eq!(poly.roots_mod_n(), brute_roots(&poly));
+    }
+    // product of many linear factors splits completely
+    let mut poly = Polynomial::<Zn<101>>::new_const(Zn::new(1));
+    for r in (3..101).step_by(7) {
+        poly *= Polynomial::from_coefs(vec![Zn::from_i64(-r), Zn::new(1)]);
+    }
+    assert_eq!(poly.roots_mod_n(), brute_roots(&poly));
+    assert_eq!(poly.roots_mod_n().len(), 14);
+}
+
+#[test]
+fn test_evaluate_at_root_powers() {
+    const P: u32 = 998_244_353;
+    type F = Zn<P>;
+    let mut rng = Xorshift64::new(11);
+    for n in [1usize, 2, 4, 16, 64] {
+        let omega = F::new(3).pow((P as u64 - 1) / n as u64);
+        // degree may exceed n
+        for degree in [0, n as u32 / 2, 2 * n as u32 + 3] {
+            let poly = Polynomial::<F>::random(degree, &mut rng);
+            let expected: Vec<F> = (0..n as u64).map(|k| poly.substitude(omega.pow(k))).collect();
+            assert_eq!(poly.evaluate_at_root_powers(omega, n), expected);
+        }
+    }
+
+    // n is not power of two, or omega is not root of unity of order n
+    let poly = Polynomial::<Zn<13>>::from_coefs(vec![Zn::new(1), Zn::new(1), Zn::new(1)]);
+    let omega = Zn::new(3); // order 3
+    let values: Vec<u32> = poly.evaluate_at_root_powers(omega, 3).iter().map(Zn::value).collect();
+    assert_eq!(values, vec![3, 0, 0]);
+    let values: Vec<u32> =
+        poly.evaluate_at_root_powers(Zn::new(2), 4).iter().map(Zn::value).collect();
+    assert_eq!(values, vec![3, 7, 21 % 13, 73 % 13]);
+    assert!(poly.evaluate_at_root_powers(omega, 0).is_empty());
+
+    // over Z(2^4 + 1) 2 is root of unity of order 8
+    let poly = Polynomial::<Zn<17>>::random(20, &mut rng);
+    let expected: Vec<Zn<17>> = (0..8).map(|k| poly.substitude(Zn::new(2).pow(k))).collect();
+    assert_eq!(poly.evaluate_at_root_powers(Zn::new(2), 8), expected);
+
+    // omega^4 = 1, but omega is not primitive root of order 4
+    let poly = Polynomial::<Zn<17>>::from_coefs(vec![Zn::new(1), Zn::new(2), Zn::new(3)]);
+    let values: Vec<u32> =
+        poly.evaluate_at_root_powers(Zn::new(1), 4).iter().map(Zn::value).collect();
+    assert_eq!(values, vec![6, 6, 6, 6]);
+    let values: Vec<u32> =
+        poly.evaluate_at_root_powers(Zn::new(16), 4).iter().map(Zn::value).collect();
+    assert_eq!(values, vec![6, 2, 6, 2]);
+}
+
+#[test]
+fn test_mod_n() {
+    use polylib::polynom::X;
+
+    let x = X::<i64>::default();
+    // unreduced members stay separate, zero residues are dropped
+    let p = x.pow(3) * 7 - x.pow(1) * 4 + x.pow(3) * -2 + 21 + i64::MIN;
+    let q = p.mod_n::<7>();
+    assert_eq!(q.len(), 3);
+    let expected = Polynomial::<Zn<7>>::from_coefs(vec![
+        Zn::from_i64(i64::MIN),
+        Zn::from_i64(-4),
+        Zn::new(0),
+        Zn::from_i64(-2),
+    ]);
+    assert_eq!(q, expected);
+
+    // reduction is homomorphism
+    let a = Polynomial::<i64>::from_coefs(vec![3, -8, 0, 12, 5]);
+    let b = Polynomial::<i64>::from_coefs(vec![-1, 4, 9]);
+    let product = (a.clone() * b.clone()).mod_n::<13>();
+    assert_eq!(product, a.mod_n::<13>() * b.mod_n::<13>());
+    for point in 0..13 {
+        let value: i64 = a.substitude(point as i64);
+        let reduced: Zn<13> = a.mod_n::<13>().substitude(Zn::new(point));
+        assert_eq!(reduced, Zn::from_i64(value));
+    }
+
+    let q = a.mod_dyn(13);
+    assert_eq!(q.len(), 4);
+    for power in 0..5 {
+        assert_eq!(q.coef(power).value(), a.mod_n::<13>().coef(power).value());
+    }
+    assert!(Polynomial::<i64>::from_coefs(vec![4, 8]).mod_n::<4>().is_empty());
+    assert!(Polynomial::<i64>::from_coefs(vec![4, 8]).mod_dyn(2).is_empty());
+}
+
+#[test]
+#[should_panic(expected = "modulus 0")]
+fn test_mod_dyn_zero() {
+    let _ = Polynomial::<i64>::default().mod_dyn(0);
+}
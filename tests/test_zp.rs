@@ -0,0 +1,30 @@
+use polylib::custom_types::Zp;
+use polylib::polynom::Polynomial;
+use polylib::CoefField;
+
+mod common;
+use common::same_coef;
+
+type TestType = Zp<5>;
+type Poly = Polynomial<TestType>;
+
+fn poly(coefs: &[i32]) -> Poly {
+    Poly::from_coefs(coefs.iter().map(|c| TestType::from(*c)).collect())
+}
+
+fn is_field<T: CoefField>() {}
+
+#[test]
+fn test_field() {
+    is_field::<TestType>();
+}
+
+#[test]
+fn test_div_rem() {
+    // x^3 + 2x + 1 = (2x + 1) * (3x^2 + x + 3) + 3
+    let a = poly(&[1, 2, 0, 1]);
+    let b = poly(&[1, 2]);
+    let (q, r) = a.div_rem(&b);
+    same_coef(q.reduce(), vec![3.into(), 1.into(), 3.into()]);
+    same_coef(r.reduce(), vec![3.into()]);
+}